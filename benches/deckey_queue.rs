@@ -5,6 +5,37 @@ use criterion::{
 use orx_priority_queue::*;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// A minimal port of the Fx hasher used by rustc; fast for integer nodes.
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct FxHasher {
+    hash: u64,
+}
+#[cfg(feature = "std")]
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.write_u64(*byte as u64);
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ i).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+#[cfg(feature = "std")]
+type FxBuildHasher = BuildHasherDefault<FxHasher>;
 
 struct TestData {
     push: Vec<(usize, u64)>,
@@ -111,6 +142,40 @@ fn run_on_dary_heap_with_map<const D: usize>(
         },
     );
 }
+#[cfg(feature = "std")]
+fn run_on_dary_heap_with_fx_map<const D: usize>(
+    group: &mut BenchmarkGroup<WallTime>,
+    n: usize,
+    data: &TestData,
+) {
+    group.bench_with_input(
+        BenchmarkId::new(format!("DaryHeapWithMap<_, _, {}, FxBuildHasher>", D), n),
+        &n,
+        |b, _| {
+            b.iter(|| {
+                let hasher = FxBuildHasher::default();
+                let pq = DaryHeapWithMap::<_, _, D, _>::with_capacity_and_hasher(n, hasher);
+                run_on_deckey_queue(black_box(pq), black_box(data))
+            })
+        },
+    );
+}
+fn run_on_lazy_deckey_heap<const D: usize>(
+    group: &mut BenchmarkGroup<WallTime>,
+    n: usize,
+    data: &TestData,
+) {
+    group.bench_with_input(
+        BenchmarkId::new(format!("LazyDecKeyHeap<_, _, {}>", D), n),
+        &n,
+        |b, _| {
+            b.iter(|| {
+                let pq = LazyDecKeyHeap::<_, _, D>::with_capacity(n);
+                run_on_deckey_queue(black_box(pq), black_box(data))
+            })
+        },
+    );
+}
 
 fn bench_deckey_queue(c: &mut Criterion) {
     let treatments = vec![1_000, 10_000, 100_000];
@@ -128,6 +193,17 @@ fn bench_deckey_queue(c: &mut Criterion) {
         run_on_dary_heap_with_map::<4>(&mut group, *n, &data);
         run_on_dary_heap_with_map::<8>(&mut group, *n, &data);
 
+        #[cfg(feature = "std")]
+        {
+            run_on_dary_heap_with_fx_map::<2>(&mut group, *n, &data);
+            run_on_dary_heap_with_fx_map::<4>(&mut group, *n, &data);
+            run_on_dary_heap_with_fx_map::<8>(&mut group, *n, &data);
+        }
+
+        run_on_lazy_deckey_heap::<2>(&mut group, *n, &data);
+        run_on_lazy_deckey_heap::<4>(&mut group, *n, &data);
+        run_on_lazy_deckey_heap::<8>(&mut group, *n, &data);
+
         #[cfg(feature = "impl_priority_queue")]
         {
             group.bench_with_input(
@@ -0,0 +1,111 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+struct TestData {
+    push: Vec<(usize, u64)>,
+    deckey: Vec<(usize, u64)>,
+}
+impl TestData {
+    fn new(seed: u64, n_push: usize, n_deckey: usize) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let mut push = Vec::new();
+        for node in 0..n_push {
+            push.push((node, rng.gen_range(u64::MAX / 2..u64::MAX)));
+        }
+
+        // decreased keys are less than all pushed keys
+        let mut deckey = Vec::new();
+        for _ in 0..n_deckey {
+            let node = rng.gen_range(0..n_push);
+            deckey.push((node, rng.gen_range(0..u64::MAX / 2)));
+        }
+        deckey.sort_by_key(|x| x.0);
+        deckey.dedup_by_key(|x| x.0);
+
+        Self { push, deckey }
+    }
+}
+
+// data
+fn run_decrease_key_loop<P>(mut pq: P, data: &TestData) -> (usize, u64)
+where
+    P: PriorityQueueDecKey<usize, u64>,
+{
+    for (node, key) in &data.push {
+        pq.push(*node, *key);
+    }
+
+    for (node, key) in &data.deckey {
+        pq.decrease_key(node, *key);
+    }
+
+    pq.pop().unwrap_or_default()
+}
+
+fn run_decrease_key_all<P>(mut pq: P, data: &TestData) -> (usize, u64)
+where
+    P: PriorityQueueDecKey<usize, u64>,
+{
+    for (node, key) in &data.push {
+        pq.push(*node, *key);
+    }
+
+    pq.decrease_key_all(data.deckey.iter().copied());
+
+    pq.pop().unwrap_or_default()
+}
+
+fn run_on_dary_heap_of_indices<const D: usize>(
+    group: &mut BenchmarkGroup<WallTime>,
+    n: usize,
+    data: &TestData,
+) {
+    group.bench_with_input(
+        BenchmarkId::new(format!("DaryHeapOfIndices<_, _, {}>::decrease_key", D), n),
+        &n,
+        |b, _| {
+            b.iter(|| {
+                let pq = DaryHeapOfIndices::<_, _, D>::with_index_bound(n);
+                run_decrease_key_loop(black_box(pq), black_box(data))
+            })
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new(
+            format!("DaryHeapOfIndices<_, _, {}>::decrease_key_all", D),
+            n,
+        ),
+        &n,
+        |b, _| {
+            b.iter(|| {
+                let pq = DaryHeapOfIndices::<_, _, D>::with_index_bound(n);
+                run_decrease_key_all(black_box(pq), black_box(data))
+            })
+        },
+    );
+}
+
+fn bench_decrease_key_all(c: &mut Criterion) {
+    let treatments = vec![1_000, 10_000, 100_000];
+
+    let mut group = c.benchmark_group("decrease_key_all");
+
+    for n in &treatments {
+        let data = TestData::new(8498723, *n, *n);
+
+        run_on_dary_heap_of_indices::<2>(&mut group, *n, &data);
+        run_on_dary_heap_of_indices::<4>(&mut group, *n, &data);
+        run_on_dary_heap_of_indices::<8>(&mut group, *n, &data);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_decrease_key_all);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+fn test_data(seed: u64, len: usize) -> Vec<(usize, u64)> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..len).map(|node| (node, rng.gen())).collect()
+}
+
+fn run_on_dary_heaps<const D: usize>(
+    group: &mut BenchmarkGroup<WallTime>,
+    n: usize,
+    data: &[(usize, u64)],
+) {
+    group.bench_with_input(
+        BenchmarkId::new(format!("DaryHeap::<_, _, {}>::from", D), n),
+        &n,
+        |b, _| b.iter(|| DaryHeap::<_, _, D>::from(black_box(data).to_vec())),
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new(format!("DaryHeap::<_, _, {}>::par_from_vec", D), n),
+        &n,
+        |b, _| b.iter(|| DaryHeap::<_, _, D>::par_from_vec(black_box(data).to_vec())),
+    );
+}
+
+fn bench_par_from_vec(c: &mut Criterion) {
+    let treatments = vec![100_000, 1_000_000, 10_000_000];
+
+    let mut group = c.benchmark_group("par_from_vec");
+    group.sample_size(10);
+
+    for n in &treatments {
+        let data = test_data(5412, *n);
+
+        run_on_dary_heaps::<2>(&mut group, *n, &data);
+        run_on_dary_heaps::<4>(&mut group, *n, &data);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_par_from_vec);
+criterion_main!(benches);
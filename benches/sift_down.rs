@@ -0,0 +1,56 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+fn test_data(seed: u64, len: usize) -> Vec<(usize, u64)> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..len).map(|node| (node, rng.gen())).collect()
+}
+
+fn run_on_dary_heap<const D: usize>(mut pq: DaryHeap<usize, u64, D>) -> (usize, u64) {
+    let mut sum_keys = 0;
+    let mut sum_nodes = 0;
+
+    while let Some((node, key)) = pq.pop() {
+        sum_nodes += node;
+        sum_keys += key;
+    }
+
+    (sum_nodes, sum_keys)
+}
+
+fn run_on_dary_heaps<const D: usize>(
+    group: &mut BenchmarkGroup<WallTime>,
+    n: usize,
+    data: &[(usize, u64)],
+) {
+    let pq = DaryHeap::<_, _, D>::from(data.to_vec());
+    group.bench_with_input(
+        BenchmarkId::new(format!("DaryHeap<_, _, {}>", D), n),
+        &n,
+        |b, _| b.iter(|| run_on_dary_heap(black_box(pq.clone()))),
+    );
+}
+
+fn bench_sift_down(c: &mut Criterion) {
+    let treatments = vec![10_000, 100_000, 1_000_000];
+
+    let mut group = c.benchmark_group("sift_down");
+
+    for n in &treatments {
+        let data = test_data(7321, *n);
+
+        run_on_dary_heaps::<4>(&mut group, *n, &data);
+        run_on_dary_heaps::<8>(&mut group, *n, &data);
+        run_on_dary_heaps::<16>(&mut group, *n, &data);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sift_down);
+criterion_main!(benches);
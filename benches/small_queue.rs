@@ -0,0 +1,90 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+const NUM_QUEUES: usize = 1000;
+
+struct TestData {
+    elements: Vec<Vec<(usize, u64)>>,
+}
+impl TestData {
+    fn new(seed: u64, len: usize) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let elements = (0..NUM_QUEUES)
+            .map(|_| (0..len).map(|node| (node, rng.gen())).collect())
+            .collect();
+        Self { elements }
+    }
+}
+
+// creates many short lived queues; as in a broadphase where each cell has a tiny queue
+fn run_on_new_queues<P>(new_queue: impl Fn() -> P, data: &TestData) -> (usize, u64)
+where
+    P: PriorityQueue<usize, u64>,
+{
+    let mut sum_keys = 0;
+    let mut sum_nodes = 0;
+
+    for elements in &data.elements {
+        let mut pq = new_queue();
+        for (node, key) in elements {
+            pq.push(*node, *key);
+        }
+        while let Some((node, key)) = pq.pop() {
+            sum_nodes += node;
+            sum_keys += key;
+        }
+    }
+
+    (sum_nodes, sum_keys)
+}
+
+fn run_on_dary_heaps<const D: usize>(
+    group: &mut BenchmarkGroup<WallTime>,
+    n: usize,
+    data: &TestData,
+) {
+    group.bench_with_input(
+        BenchmarkId::new(format!("DaryHeap<_, _, {}>", D), n),
+        &n,
+        |b, _| b.iter(|| run_on_new_queues(DaryHeap::<_, _, D>::default, black_box(data))),
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new(format!("SmallDaryHeap<_, _, {}, 32>", D), n),
+        &n,
+        |b, _| b.iter(|| run_on_new_queues(SmallDaryHeap::<_, _, D, 32>::default, black_box(data))),
+    );
+}
+
+fn bench_small_queue(c: &mut Criterion) {
+    let treatments = vec![4, 8, 16, 24, 64];
+
+    let mut group = c.benchmark_group("small_queue");
+
+    for n in &treatments {
+        let data = TestData::new(8498723, *n);
+
+        group.bench_with_input(
+            BenchmarkId::new("std::collections::BinaryHeap", n),
+            n,
+            |b, _| {
+                b.iter(|| {
+                    run_on_new_queues(std::collections::BinaryHeap::default, black_box(&data))
+                })
+            },
+        );
+
+        run_on_dary_heaps::<2>(&mut group, *n, &data);
+        run_on_dary_heaps::<4>(&mut group, *n, &data);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_small_queue);
+criterion_main!(benches);
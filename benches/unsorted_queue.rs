@@ -0,0 +1,109 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+struct TestData {
+    first_push: Vec<(usize, u64)>,
+    push_then_pop: Vec<(usize, u64)>,
+}
+impl TestData {
+    fn new(seed: u64, len: usize) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let first_push = (0..len).map(|node| (node, rng.gen())).collect();
+        let push_then_pop = (len..(len + 100 * len))
+            .map(|node| (node, rng.gen()))
+            .collect();
+
+        Self {
+            first_push,
+            push_then_pop,
+        }
+    }
+}
+
+// data
+fn run_on_small_queue<P>(mut pq: P, data: &TestData) -> (usize, u64)
+where
+    P: PriorityQueue<usize, u64>,
+{
+    let mut sum_keys = 0;
+    let mut sum_nodes = 0;
+
+    for (node, key) in &data.first_push {
+        pq.push(*node, *key);
+    }
+
+    // length of the queue stays the same
+    for (node, key) in &data.push_then_pop {
+        if let Some((node, key)) = pq.pop() {
+            sum_nodes += node;
+            sum_keys += key;
+        }
+        pq.push(*node, *key);
+    }
+
+    while let Some((node, key)) = pq.pop() {
+        sum_nodes += node;
+        sum_keys += key;
+    }
+
+    (sum_nodes, sum_keys)
+}
+
+fn run_on_dary_heap<const D: usize>(
+    group: &mut BenchmarkGroup<WallTime>,
+    n: usize,
+    data: &TestData,
+) {
+    group.bench_with_input(
+        BenchmarkId::new(format!("DaryHeap<_, _, {}>", D), n),
+        &n,
+        |b, _| {
+            b.iter(|| {
+                let pq = DaryHeap::<_, _, D>::default();
+                run_on_small_queue(black_box(pq), black_box(data))
+            })
+        },
+    );
+}
+
+fn bench_unsorted_queue(c: &mut Criterion) {
+    let treatments = vec![4, 8, 16, 32, 64, 128];
+
+    let mut group = c.benchmark_group("unsorted_queue");
+
+    for n in &treatments {
+        let data = TestData::new(8498723, *n);
+
+        group.bench_with_input(BenchmarkId::new("UnsortedQueue", n), n, |b, _| {
+            b.iter(|| {
+                let pq = UnsortedQueue::default();
+                run_on_small_queue(black_box(pq), black_box(&data))
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("std::collections::BinaryHeap", n),
+            n,
+            |b, _| {
+                b.iter(|| {
+                    let pq = std::collections::BinaryHeap::default();
+                    run_on_small_queue(black_box(pq), black_box(&data))
+                })
+            },
+        );
+
+        run_on_dary_heap::<2>(&mut group, *n, &data);
+        run_on_dary_heap::<4>(&mut group, *n, &data);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_unsorted_queue);
+criterion_main!(benches);
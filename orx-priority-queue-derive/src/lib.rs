@@ -0,0 +1,152 @@
+//! # orx-priority-queue-derive
+//!
+//! Derive macro for the [`HasIndex`](https://docs.rs/orx-priority-queue/latest/orx_priority_queue/trait.HasIndex.html)
+//! trait of [orx-priority-queue](https://crates.io/crates/orx-priority-queue).
+//!
+//! This crate is not intended to be used directly;
+//! the macro is re-exported by `orx-priority-queue` with the `derive` feature.
+
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Member};
+
+const ATTRIBUTE: &str = "has_index";
+
+/// Derives `HasIndex` for a struct by forwarding to the `index` of one of its fields.
+///
+/// The field providing the index is determined as follows:
+/// * if a field is annotated with `#[has_index]`, it is the annotated field;
+/// * otherwise, the struct must have exactly one field, such as the newtype `struct CityId(u32)`.
+///
+/// The type of the field must itself implement `HasIndex`, as all unsigned integers do.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, HasIndex)]
+/// struct CityId(u32);
+///
+/// #[derive(Clone, Debug, PartialEq, HasIndex)]
+/// struct City {
+///     #[has_index]
+///     id: u32,
+///     name: String,
+/// }
+///
+/// assert_eq!(3, CityId(3).index());
+///
+/// let city = City { id: 2, name: "Istanbul".to_string() };
+/// assert_eq!(2, city.index());
+///
+/// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+/// queue.push(CityId(2), 42);
+/// queue.push(CityId(0), 7);
+/// assert_eq!(Some((CityId(0), 7)), queue.pop());
+/// ```
+///
+/// A struct with multiple fields requires exactly one of them to be annotated.
+///
+/// ```compile_fail
+/// use orx_priority_queue::*;
+///
+/// #[derive(Clone, HasIndex)]
+/// struct City {
+///     id: u32,
+///     name: String,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use orx_priority_queue::*;
+///
+/// #[derive(Clone, HasIndex)]
+/// struct City {
+///     #[has_index]
+///     id: u32,
+///     #[has_index]
+///     zip: u32,
+/// }
+/// ```
+///
+/// A struct without fields cannot provide an index.
+///
+/// ```compile_fail
+/// use orx_priority_queue::*;
+///
+/// #[derive(Clone, HasIndex)]
+/// struct Empty;
+/// ```
+///
+/// Enums and unions are not supported.
+///
+/// ```compile_fail
+/// use orx_priority_queue::*;
+///
+/// #[derive(Clone, HasIndex)]
+/// enum City {
+///     Istanbul(u32),
+/// }
+/// ```
+#[proc_macro_derive(HasIndex, attributes(has_index))]
+pub fn derive_has_index(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match has_index_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn has_index_impl(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let member = index_member(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::orx_priority_queue::HasIndex for #name #ty_generics #where_clause {
+            #[inline(always)]
+            fn index(&self) -> usize {
+                ::orx_priority_queue::HasIndex::index(&self.#member)
+            }
+        }
+    })
+}
+
+fn index_member(input: &DeriveInput) -> Result<Member, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "HasIndex can only be derived for structs",
+            ))
+        }
+    };
+
+    let annotated: Vec<_> = fields
+        .iter()
+        .zip(fields.members())
+        .filter(|(field, _)| field.attrs.iter().any(|a| a.path().is_ident(ATTRIBUTE)))
+        .collect();
+
+    match (annotated.as_slice(), fields.len()) {
+        ([(_, member)], _) => Ok(member.clone()),
+        ([], 1) => Ok(fields.members().next().expect("single field exists")),
+        ([], 0) => Err(Error::new(
+            Span::call_site(),
+            "HasIndex cannot be derived for a struct without fields",
+        )),
+        ([], _) => Err(Error::new(
+            Span::call_site(),
+            "HasIndex requires the field providing the index to be annotated with #[has_index] when the struct has multiple fields",
+        )),
+        ([_, (field, _), ..], _) => Err(Error::new_spanned(
+            field,
+            "HasIndex requires exactly one field to be annotated with #[has_index]",
+        )),
+    }
+}
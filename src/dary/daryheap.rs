@@ -1,10 +1,24 @@
-use super::heap::Heap;
-use crate::{positions::none::HeapPositionsNone, PriorityQueue};
+use super::{
+    drain::Drain, drain_sorted::DrainSorted, heap::Heap, into_iter_sorted::IntoIterSorted,
+    iter_mut::IterMut, iter_sorted::IterSorted, peek_mut::PeekMut,
+};
+use crate::{positions::none::HeapPositionsNone, HeapError, PriorityQueue};
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::{
+    cmp::Ordering,
+    iter::{Rev, Take},
+    slice::Iter,
+};
 
 /// Type alias for `DaryHeap<N, K, 2>`; see [`DaryHeap`] for details.
 pub type BinaryHeap<N, K> = DaryHeap<N, K, 2>;
+/// Type alias for `DaryHeap<N, K, 3>`; see [`DaryHeap`] for details.
+pub type TernaryHeap<N, K> = DaryHeap<N, K, 3>;
 /// Type alias for `DaryHeap<N, K, 4>`; see [`DaryHeap`] for details.
 pub type QuaternaryHeap<N, K> = DaryHeap<N, K, 4>;
+/// Type alias for `DaryHeap<N, K, 8>`; see [`DaryHeap`] for details.
+pub type OctaryHeap<N, K> = DaryHeap<N, K, 8>;
 
 /// A d-ary heap which implements `PriorityQueue`, but not `PriorityQueueDecKey`.
 ///
@@ -55,7 +69,26 @@ pub type QuaternaryHeap<N, K> = DaryHeap<N, K, 4>;
 /// test_priority_queue(QuaternaryHeap::default());
 /// test_priority_queue(QuaternaryHeap::with_capacity(16));
 /// ```
-#[derive(Clone, Debug)]
+///
+/// ## Valid values of D
+///
+/// The heap requires `D >= 2`; creating a heap with `D = 0` or `D = 1` fails to compile.
+/// This holds for all d-ary heap variants.
+///
+/// ```compile_fail
+/// use orx_priority_queue::*;
+///
+/// let mut queue = DaryHeap::<usize, f64, 1>::default();
+/// queue.push(0, 42.0);
+/// ```
+///
+/// ```compile_fail
+/// use orx_priority_queue::*;
+///
+/// let mut queue = DaryHeapWithMap::<usize, f64, 0>::default();
+/// queue.push(0, 42.0);
+/// ```
+#[derive(Debug)]
 pub struct DaryHeap<N, K, const D: usize = 2>
 where
     N: Clone,
@@ -64,6 +97,37 @@ where
     heap: Heap<N, K, HeapPositionsNone, D>,
 }
 
+impl<N, K, const D: usize> Clone for DaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+        }
+    }
+
+    /// Clones the elements of `source` into this heap reusing its already allocated storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let source = QuaternaryHeap::from([('a', 3), ('b', 1), ('c', 2)]);
+    ///
+    /// let mut queue = QuaternaryHeap::with_capacity(16);
+    /// queue.clone_from(&source);
+    ///
+    /// assert!(queue == source);
+    /// assert!(queue.capacity() >= 16);
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        self.heap.clone_from(&source.heap);
+    }
+}
+
 impl<N, K, const D: usize> Default for DaryHeap<N, K, D>
 where
     N: Clone,
@@ -148,60 +212,1344 @@ where
     pub fn as_slice(&self) -> &[(N, K)] {
         self.heap.as_slice()
     }
-}
 
-impl<N, K, const D: usize> PriorityQueue<N, K> for DaryHeap<N, K, D>
-where
-    N: Clone,
-    K: PartialOrd + Clone,
-{
-    type NodeKey<'a> = &'a (N, K) where Self: 'a, N: 'a, K: 'a;
-    type Iter<'a> = core::slice::Iter<'a, (N, K)> where Self: 'a, N: 'a, K: 'a;
+    /// Returns an iterator visiting the nodes and keys currently in the queue in the reverse order of `as_slice`.
+    ///
+    /// Note that the order is still arbitrary, this is only the reverse traversal of the internal tree;
+    /// in particular, the elements are not yielded in descending order of their keys.
+    /// The iterator is double-ended; `iter_rev().rev()` is equivalent to `iter()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let reversed: Vec<_> = queue.iter_rev().collect();
+    /// let mut forward: Vec<_> = queue.iter().collect();
+    /// forward.reverse();
+    /// assert_eq!(forward, reversed);
+    /// ```
+    pub fn iter_rev(&self) -> Rev<Iter<'_, (N, K)>> {
+        self.as_slice().iter().rev()
+    }
 
-    #[inline(always)]
-    fn len(&self) -> usize {
-        self.heap.len()
+    /// Returns the number of levels of the tree of the heap, which is 0 for an empty heap and 1 for a heap
+    /// with a single element; each level holds `D` times the elements of its parent level.
+    ///
+    /// This is useful for comparing the heights of the heaps with different arities on a particular workload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// assert_eq!(0, queue.depth());
+    ///
+    /// queue.push(0usize, 42);
+    /// assert_eq!(1, queue.depth());
+    ///
+    /// // levels of sizes 1 and 4
+    /// for node in 1..5 {
+    ///     queue.push(node, 42);
+    /// }
+    /// assert_eq!(2, queue.depth());
+    ///
+    /// queue.push(5, 42);
+    /// assert_eq!(3, queue.depth());
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.heap.depth()
     }
 
-    #[inline(always)]
-    fn capacity(&self) -> usize {
-        self.heap.capacity()
+    /// Returns whether or not the `node` is in the queue by a linear scan over its elements.
+    ///
+    /// Since `DaryHeap` does not track positions of its nodes, this method requires *O(n)* time;
+    /// it is a convenience for small queues or for debugging.
+    /// Queues implementing [`PriorityQueueDecKey`] such as `DaryHeapOfIndices` or `DaryHeapWithMap`
+    /// provide the `contains` check in constant time.
+    ///
+    /// [`PriorityQueueDecKey`]: crate::PriorityQueueDecKey
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    ///
+    /// assert!(queue.contains(&"x"));
+    /// assert!(!queue.contains(&"z"));
+    ///
+    /// queue.pop();
+    /// assert!(!queue.contains(&"y"));
+    /// ```
+    pub fn contains(&self, node: &N) -> bool
+    where
+        N: PartialEq,
+    {
+        self.as_slice().iter().any(|x| &x.0 == node)
     }
 
-    fn peek(&self) -> Option<&(N, K)> {
-        self.heap.peek()
+    /// Validates the heap; i.e., checks whether or not the key of every element is greater than or equal to
+    /// the key of its parent on the tree.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    ///
+    /// Since the heap maintains its invariants on its own, this method is mainly useful for debugging and testing.
+    /// It requires *O(n)* time where n is the number of elements on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
     }
 
-    fn clear(&mut self) {
-        self.heap.clear()
+    /// Reserves capacity for at least `additional` more elements to be pushed to the heap.
+    /// The heap may reserve more space to speculatively avoid frequent reallocations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::<char, i32>::new();
+    /// queue.reserve(100);
+    /// assert!(queue.capacity() >= 100);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.heap.reserve(additional)
     }
 
-    #[inline(always)]
-    fn pop(&mut self) -> Option<(N, K)> {
-        self.heap.pop()
+    /// Reserves the minimum capacity for at least `additional` more elements to be pushed to the heap.
+    /// Unlike `reserve`, this will not deliberately over-allocate to speculatively avoid frequent allocations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::<char, i32>::new();
+    /// queue.reserve_exact(100);
+    /// assert!(queue.capacity() >= 100);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.heap.reserve_exact(additional)
     }
 
-    #[inline(always)]
-    fn pop_node(&mut self) -> Option<N> {
-        self.heap.pop_node()
+    /// Shrinks the capacity of the heap as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::with_capacity(1000);
+    /// for node in 0..1000 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// while queue.len() > 10 {
+    ///     queue.pop();
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.shrink_to_fit();
+    /// assert!(queue.capacity() < 1000);
+    /// assert!(queue.capacity() >= 10);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.heap.shrink_to_fit()
     }
 
-    #[inline(always)]
-    fn pop_key(&mut self) -> Option<K> {
-        self.heap.pop_key()
+    /// Clears the heap and releases its memory by shrinking the capacity as much as possible.
+    ///
+    /// Unlike `clear`, which retains the capacity for reuse, this method is useful when the memory held
+    /// by a queue which has once been large is to be released without creating a new queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::with_capacity(1000);
+    /// for node in 0..1000 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.clear_and_shrink();
+    /// assert!(queue.is_empty());
+    /// assert_eq!(0, queue.capacity());
+    /// ```
+    pub fn clear_and_shrink(&mut self) {
+        self.heap.clear_and_shrink()
     }
 
-    #[inline(always)]
-    fn push(&mut self, node: N, key: K) {
-        self.heap.push(node, key)
+    /// Clears the heap and, if its capacity exceeds `max_capacity`, shrinks the capacity down to `max_capacity`;
+    /// the capacity is retained otherwise.
+    ///
+    /// This method lies between `clear`, which retains the capacity, and `clear_and_shrink`, which releases it:
+    /// reusing the queue in a long-running loop avoids reallocations in normal operation,
+    /// while the memory held after an occasional burst is bounded by `max_capacity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::with_capacity(1000);
+    /// for node in 0..1000 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.clear_to_capacity(100);
+    /// assert!(queue.is_empty());
+    /// assert!(queue.capacity() >= 100 && queue.capacity() < 1000);
+    ///
+    /// for node in 0..10 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// queue.clear_to_capacity(100);
+    /// assert!(queue.is_empty());
+    /// assert!(queue.capacity() >= 100 && queue.capacity() < 1000);
+    /// ```
+    pub fn clear_to_capacity(&mut self, max_capacity: usize) {
+        self.heap.clear_to_capacity(max_capacity)
     }
 
-    #[inline(always)]
-    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
-        self.heap.push_then_pop(node, key)
+    /// Returns a guard providing mutable access to the key of the foremost element of the heap;
+    /// returns None if the heap is empty.
+    ///
+    /// If the key is mutated, the heap is restored once the guard is dropped;
+    /// see [`PeekMut`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// if let Some(mut top) = queue.peek_mut() {
+    ///     assert_eq!(&"y", top.node());
+    ///     *top.key_mut() += 43;
+    /// }
+    ///
+    /// assert_eq!(Some(&("x", 42)), queue.peek());
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, N, K>> {
+        self.heap.peek_mut()
     }
 
-    fn iter(&self) -> Self::Iter<'_> {
-        self.as_slice().iter()
+    /// Returns a guard providing mutable access to the keys of all elements of the heap;
+    /// the guard is iterated by a mutable reference yielding `(&N, &mut K)` pairs in arbitrary order.
+    ///
+    /// The heap is restored by a single bottom-up heapify once the guard is dropped;
+    /// see [`IterMut`] for details.
+    /// This is much cheaper than draining and rebuilding the heap to apply an update to all keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// for (node, key) in &mut queue.iter_mut() {
+    ///     match node {
+    ///         1 => *key += 100,
+    ///         _ => *key *= 2,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(&(0, 84)), queue.peek());
+    /// assert_eq!(Some((0, 84)), queue.pop());
+    /// assert_eq!(Some((1, 107)), queue.pop());
+    /// assert_eq!(Some((2, 198)), queue.pop());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, N, K> {
+        self.heap.iter_mut()
+    }
+
+    /// Restores the heap property by a single bottom-up heapify over all elements.
+    ///
+    /// This is the canonical way to fix the heap after its elements are mutated in a way that could have invalidated
+    /// its invariants; for instance, after mutating the keys through the guards returned by [`iter_mut`] or [`peek_mut`].
+    /// These guards already repair the heap on drop; hence, `repair` is mainly an escape hatch rather than a routine step.
+    ///
+    /// It requires *O(n)* time where n is the number of elements on the heap,
+    /// and leaves an already valid heap valid.
+    ///
+    /// [`iter_mut`]: Self::iter_mut
+    /// [`peek_mut`]: Self::peek_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// queue.repair();
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// assert_eq!(Some((1, 7)), queue.pop());
+    /// ```
+    pub fn repair(&mut self) {
+        self.heap.repair()
+    }
+
+    /// Removes the foremost element of the queue and replaces it with the given (`node`, `key`) pair;
+    /// returns the removed element.
+    /// If the queue is empty, the pair is pushed and None is returned.
+    ///
+    /// This is equivalent to, but faster than, a `pop` followed by a `push`,
+    /// since it requires a single heapify-down rather than two heapify operations.
+    /// Unlike `push_then_pop`, the new element always enters the queue, even when its key is less than that of the root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// assert_eq!(None, queue.replace_root("x", 42));
+    ///
+    /// queue.push("y", 7);
+    /// assert_eq!(Some(("y", 7)), queue.replace_root("z", 99));
+    ///
+    /// assert_eq!(Some(("x", 42)), queue.pop());
+    /// assert_eq!(Some(("z", 99)), queue.pop());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn replace_root(&mut self, node: N, key: K) -> Option<(N, K)> {
+        self.heap.replace_root(node, key)
+    }
+
+    /// Returns an iterator visiting all (node, key) pairs on the heap in ascending order of keys,
+    /// without mutating or consuming the heap.
+    ///
+    /// The order is the same as the order in which the elements would be popped,
+    /// while elements with equal keys are yielded in an arbitrary order.
+    ///
+    /// Yielding the first `k` elements requires *O(k log k)* time and *O(k)* additional space;
+    /// see [`IterSorted`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 7);
+    ///
+    /// let keys: Vec<_> = queue.iter_sorted().map(|x| x.1).collect();
+    /// assert_eq!(vec![7, 7, 42, 99], keys);
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(4, queue.len());
+    /// ```
+    pub fn iter_sorted(&self) -> IterSorted<'_, N, K, D> {
+        self.heap.iter_sorted()
+    }
+
+    /// Returns an iterator over the `k` foremost (node, key) pairs of the heap in ascending order of keys,
+    /// without mutating or consuming the heap.
+    ///
+    /// The iterator yields all elements of the heap if the heap has fewer than `k` elements.
+    ///
+    /// The iterator is a [`IterSorted`] limited to `k` elements;
+    /// hence, it requires *O(k log k)* time and *O(k)* additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 21);
+    ///
+    /// let top2: Vec<_> = queue.peek_k(2).collect();
+    /// assert_eq!(vec![&("y", 7), &("w", 21)], top2);
+    ///
+    /// assert_eq!(4, queue.peek_k(10).count());
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(4, queue.len());
+    /// ```
+    pub fn peek_k(&self, k: usize) -> Take<IterSorted<'_, N, K, D>> {
+        self.iter_sorted().take(k)
+    }
+
+    /// Returns the (node, key) pair which would be the `n`-th element to be popped, counting from zero,
+    /// without mutating the heap; returns None if the heap has `n` or fewer elements.
+    ///
+    /// `peek_nth(0)` is equivalent to `peek`.
+    /// It requires *O(n log n)* time and *O(n)* additional space, where n is the argument rather than the length of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Some(&("y", 7)), queue.peek_nth(0));
+    /// assert_eq!(Some(&("x", 42)), queue.peek_nth(1));
+    /// assert_eq!(Some(&("z", 99)), queue.peek_nth(2));
+    /// assert_eq!(None, queue.peek_nth(3));
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&(N, K)> {
+        self.iter_sorted().nth(n)
+    }
+
+    /// Returns the (node, key) pair which would be the second element to be popped without mutating the heap;
+    /// returns None if the heap has fewer than two elements.
+    ///
+    /// Since the second smallest element of a valid heap is one of the children of the root,
+    /// only the up to `D` children of the root are examined; hence, it requires *O(D)* time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// assert_eq!(None, queue.peek_second());
+    ///
+    /// queue.push("x", 42);
+    /// assert_eq!(None, queue.peek_second());
+    ///
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 13);
+    ///
+    /// assert_eq!(Some(&("y", 7)), queue.peek());
+    /// assert_eq!(Some(&("w", 13)), queue.peek_second());
+    /// ```
+    pub fn peek_second(&self) -> Option<&(N, K)> {
+        self.heap.peek_second()
+    }
+
+    /// Returns a vector of clones of the (node, key) pairs of the heap sorted in ascending order of keys,
+    /// without mutating the heap.
+    ///
+    /// Unlike [`as_slice`], the elements are sorted; and unlike [`into_iter_sorted`], the heap is not consumed.
+    /// It requires *O(n log n)* time and clones every element of the heap.
+    /// Elements with equal keys are in an arbitrary order.
+    ///
+    /// [`as_slice`]: Self::as_slice
+    /// [`into_iter_sorted`]: Self::into_iter_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(vec![("y", 7), ("x", 42), ("z", 99)], queue.to_sorted_vec());
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(3, queue.len());
+    /// ```
+    pub fn to_sorted_vec(&self) -> Vec<(N, K)> {
+        self.iter_sorted().cloned().collect()
+    }
+
+    /// Consumes the heap and returns an iterator yielding its (node, key) pairs in ascending order of keys.
+    ///
+    /// The iterator is lazy; each call to `next` pops the element with the lowest key.
+    /// Elements with equal keys are yielded in an arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let sorted: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(vec![("y", 7), ("x", 42), ("z", 99)], sorted);
+    /// ```
+    pub fn into_iter_sorted(self) -> IntoIterSorted<N, K, D> {
+        IntoIterSorted::new(self.heap.into_heap_without_positions())
+    }
+
+    /// Consumes the heap and returns its `k` foremost (node, key) pairs in ascending order of keys;
+    /// the remaining elements are dropped.
+    ///
+    /// All elements of the heap are returned in sorted order if the heap has fewer than `k` elements.
+    ///
+    /// Elements are popped one by one and popping stops after the `k`-th element;
+    /// hence, the method requires *O(k log n)* time rather than sorting all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 21);
+    ///
+    /// let top2 = queue.k_smallest(2);
+    /// assert_eq!(vec![("y", 7), ("w", 21)], top2);
+    /// ```
+    pub fn k_smallest(self, k: usize) -> Vec<(N, K)> {
+        let mut smallest = Vec::with_capacity(k.min(self.len()));
+        smallest.extend(self.into_iter_sorted().take(k));
+        smallest
+    }
+
+    /// Consumes the heap and returns all of its (node, key) pairs in ascending order of keys.
+    ///
+    /// Elements are popped one by one; hence, the method requires *O(n log n)* time.
+    /// Elements with equal keys are returned in an arbitrary order;
+    /// [`into_sorted_vec_by`] can be used to break the ties deterministically.
+    ///
+    /// [`into_sorted_vec_by`]: Self::into_sorted_vec_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(vec![("y", 7), ("x", 42), ("z", 99)], queue.into_sorted_vec());
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<(N, K)> {
+        let mut sorted = Vec::with_capacity(self.len());
+        sorted.extend(self.into_iter_sorted());
+        sorted
+    }
+
+    /// Consumes the heap and returns all of its (node, key) pairs sorted by the comparator `cmp`,
+    /// which is called with pairs of (node, key) pairs.
+    ///
+    /// The elements are taken out of the heap without popping and sorted once with `cmp`, in the manner of
+    /// [`slice::sort_unstable_by`].
+    /// The comparator is expected to be consistent with the order of the keys, such as comparing the keys and
+    /// breaking the ties by the nodes, for the result to be the fully sorted sequence of the heap;
+    /// otherwise, the result is simply the elements of the heap sorted by `cmp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("z", 7);
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    ///
+    /// // keys collide; ties are broken by the nodes
+    /// let sorted = queue.into_sorted_vec_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+    /// assert_eq!(vec![("y", 7), ("z", 7), ("x", 42)], sorted);
+    /// ```
+    pub fn into_sorted_vec_by<F>(self, cmp: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&(N, K), &(N, K)) -> Ordering,
+    {
+        let mut elements = self.heap.into_elements();
+        elements.sort_unstable_by(cmp);
+        elements
+    }
+
+    /// Consumes the heap and returns a heap of the same nodes where each key is mapped by `f`.
+    ///
+    /// The mapped heap is restored by a single bottom-up heapify; hence, `f` is not required to preserve the order of the keys
+    /// and the method requires *O(n)* time where n is the number of elements on the heap.
+    /// When `f` is known to be monotonically non-decreasing, [`map_keys_monotonic`] avoids the heapify.
+    ///
+    /// [`map_keys_monotonic`]: Self::map_keys_monotonic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    /// queue.push(2, 2);
+    ///
+    /// // reverses the order of the keys
+    /// let mut mapped = queue.map_keys(|key| 10 - key);
+    ///
+    /// assert_eq!(Ok(()), mapped.validate());
+    /// assert_eq!(Some((0, 7)), mapped.pop());
+    /// assert_eq!(Some((2, 8)), mapped.pop());
+    /// assert_eq!(Some((1, 9)), mapped.pop());
+    /// ```
+    pub fn map_keys<K2, F>(self, f: F) -> DaryHeap<N, K2, D>
+    where
+        K2: PartialOrd + Clone,
+        F: FnMut(K) -> K2,
+    {
+        DaryHeap::from_heap(self.heap.map_keys(f, false))
+    }
+
+    /// Consumes the heap and returns a heap of the same nodes where each key is mapped by the monotonic function `f`.
+    ///
+    /// Since the order of the keys is preserved, the positions of the elements are kept as they are without a heapify.
+    /// This is useful for unit conversions on the keys such as converting seconds to milliseconds.
+    ///
+    /// `f` must be monotonically non-decreasing; i.e., `a <= b` must imply `f(a) <= f(b)` for all keys on the heap.
+    /// Otherwise, the returned heap is invalid and the order of the elements popped from it is unspecified;
+    /// [`map_keys`] must be used instead if `f` does not satisfy this requirement.
+    ///
+    /// [`map_keys`]: Self::map_keys
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    /// queue.push(2, 2);
+    ///
+    /// // seconds to milliseconds
+    /// let mut mapped = queue.map_keys_monotonic(|seconds| seconds as f64 * 1000.0);
+    ///
+    /// assert_eq!(Ok(()), mapped.validate());
+    /// assert_eq!(Some((1, 1000.0)), mapped.pop());
+    /// assert_eq!(Some((2, 2000.0)), mapped.pop());
+    /// assert_eq!(Some((0, 3000.0)), mapped.pop());
+    /// ```
+    pub fn map_keys_monotonic<K2, F>(self, f: F) -> DaryHeap<N, K2, D>
+    where
+        K2: PartialOrd + Clone,
+        F: FnMut(K) -> K2,
+    {
+        DaryHeap::from_heap(self.heap.map_keys(f, true))
+    }
+
+    /// Deconstructs the heap into the vector of its tree and its positions,
+    /// which can be used to recreate the heap by [`from_parts`](Self::from_parts) without copying the elements.
+    ///
+    /// The returned tree is the vector backing the heap, exactly as it is stored:
+    /// * it is empty if no element has been pushed to the heap since it is created, and otherwise,
+    /// * its first `offset` elements are padding which keep the children of the nodes aligned,
+    ///   where `offset` is [`heap_offset::<D>()`](crate::heap_offset); i.e., `D - 1` when `D` is a power of two not greater than 64, and 0 otherwise;
+    ///   the padding elements are copies of an arbitrary element and not among the elements of the heap,
+    /// * the remaining elements are the elements of the heap, equal to [`as_slice`](Self::as_slice),
+    ///   where the children of the element at position `offset + i` are at positions `offset + D * i + 1` to `offset + D * i + D`.
+    ///
+    /// [`DaryHeap`] does not track the positions of its nodes; hence, the positions is the unit-like [`HeapPositionsNone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = QuaternaryHeap::from([('a', 3), ('b', 1), ('c', 2)]);
+    /// let slice = queue.as_slice().to_vec();
+    ///
+    /// let (tree, positions) = queue.into_parts();
+    /// assert_eq!(3 + 3, tree.len());
+    /// assert_eq!(slice.as_slice(), &tree[3..]);
+    ///
+    /// let mut queue = unsafe { QuaternaryHeap::from_parts(tree, positions) };
+    /// assert_eq!(Some(('b', 1)), queue.pop());
+    /// ```
+    pub fn into_parts(self) -> (Vec<(N, K)>, HeapPositionsNone) {
+        self.heap.into_parts()
+    }
+
+    /// Creates the heap from the vector of its tree and its positions as returned by [`into_parts`](Self::into_parts),
+    /// without copying or heapifying the elements.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the `tree` satisfies the invariants of the heap, which are the layout described in
+    /// [`into_parts`](Self::into_parts) and the heap property that the key of every element is not less than the key of its parent.
+    /// These are always satisfied by the tree returned by `into_parts` as long as no element is inserted, removed
+    /// or moved, and no key is changed in a way that breaks the heap property.
+    ///
+    /// The invariants are validated only in debug builds;
+    /// when they are violated, the heap may return elements out of order or panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = QuaternaryHeap::from([('a', 3), ('b', 1), ('c', 2)]);
+    ///
+    /// let (mut tree, positions) = queue.into_parts();
+    ///
+    /// // increasing all keys preserves the heap property
+    /// tree.iter_mut().for_each(|(_, key)| *key += 10);
+    ///
+    /// let mut queue = unsafe { QuaternaryHeap::from_parts(tree, positions) };
+    /// assert_eq!(Ok(()), queue.validate());
+    /// assert_eq!(Some(('b', 11)), queue.pop());
+    /// ```
+    pub unsafe fn from_parts(tree: Vec<(N, K)>, positions: HeapPositionsNone) -> Self {
+        Self::from_heap(Heap::from_parts(tree, positions))
+    }
+
+    pub(crate) fn from_heap(heap: Heap<N, K, HeapPositionsNone, D>) -> Self {
+        Self { heap }
+    }
+
+    /// Creates a heap of the given (node, key) pairs with a bottom-up heapify which is parallelized across the
+    /// independent subtrees of the heap; requires `--features rayon`.
+    ///
+    /// The subtrees rooted at the nodes of a level of the heap are disjoint; hence, they are heapified in parallel,
+    /// and then, the few levels above them are heapified sequentially.
+    /// The resulting heap is identical to the one created by `DaryHeap::from(vec)`, which heapifies sequentially.
+    ///
+    /// Parallelization pays off for very large vectors; small vectors are heapified sequentially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let elements: Vec<_> = (0..100_000).map(|i| (i, (i * 7919) % 100_000)).collect();
+    ///
+    /// let mut queue = QuaternaryHeap::par_from_vec(elements.clone());
+    /// assert_eq!(QuaternaryHeap::from(elements).as_slice(), queue.as_slice());
+    ///
+    /// assert_eq!(Some((0, 0)), queue.pop());
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_from_vec(mut elements: Vec<(N, K)>) -> Self
+    where
+        N: Send,
+        K: Send,
+    {
+        super::par_heapify::par_heapify::<N, K, D>(&mut elements);
+
+        if let Some(first) = elements.first() {
+            let first = first.clone();
+            let padding = (0..super::daryheap_const_helpers::offset::<D>()).map(|_| first.clone());
+            elements.splice(0..0, padding);
+        }
+
+        Self::from_heap(Heap::from_parts(elements, HeapPositionsNone))
+    }
+
+    /// Pops up to `n` elements from the queue and returns them in ascending order of keys;
+    /// stops early if the queue becomes empty.
+    ///
+    /// The elements are popped one by one and come out sorted;
+    /// hence, the method requires *O(n log m)* time where m is the length of the queue, without any additional sorting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 21);
+    ///
+    /// let batch = queue.pop_n(2);
+    /// assert_eq!(vec![("y", 7), ("w", 21)], batch);
+    ///
+    /// let batch = queue.pop_n(16);
+    /// assert_eq!(vec![("x", 42), ("z", 99)], batch);
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn pop_n(&mut self, n: usize) -> Vec<(N, K)> {
+        self.heap.pop_n(n)
+    }
+
+    /// Pops the element with the minimum key together with all elements whose keys are equal to it;
+    /// returns an empty vector if the queue is empty.
+    ///
+    /// Popping stops at the first element whose key is not equal to the minimum key.
+    /// The order of the elements within the returned group is unspecified.
+    ///
+    /// Keys are compared by `PartialEq`; hence, float keys are grouped only if they are exactly equal,
+    /// keys which differ by a rounding error fall into different groups and a NaN key is never grouped with another key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 7);
+    /// queue.push("y", 3);
+    /// queue.push("z", 3);
+    ///
+    /// let mut group = queue.pop_min_group();
+    /// group.sort();
+    /// assert_eq!(vec![("y", 3), ("z", 3)], group);
+    ///
+    /// assert_eq!(vec![("x", 7)], queue.pop_min_group());
+    /// assert!(queue.is_empty());
+    /// assert!(queue.pop_min_group().is_empty());
+    /// ```
+    pub fn pop_min_group(&mut self) -> Vec<(N, K)> {
+        self.heap.pop_min_group()
+    }
+
+    /// Repeatedly pops the foremost element while it satisfies the predicate `f`,
+    /// and returns the popped elements in ascending order of their keys.
+    ///
+    /// The predicate is evaluated on the foremost element before each pop; popping stops at the first element
+    /// for which `f(node, key)` returns false, which is left on the queue.
+    /// This is the drain pattern of schedulers and timer wheels, such as popping all tasks whose deadlines have passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("b", 12);
+    /// queue.push("a", 3);
+    /// queue.push("c", 10);
+    /// queue.push("d", 42);
+    ///
+    /// let now = 10;
+    /// let expired = queue.pop_while(|_, deadline| *deadline <= now);
+    /// assert_eq!(expired, [("a", 3), ("c", 10)]);
+    /// assert_eq!(Some(&("b", 12)), queue.peek());
+    /// ```
+    pub fn pop_while<F>(&mut self, f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.pop_while(f)
+    }
+
+    /// Returns an iterator which pops the (node, key) pairs of the heap in ascending order of keys.
+    ///
+    /// The iterator is lazy; each call to `next` pops the element with the lowest key.
+    /// Once the iterator is dropped, the heap is empty even if the iterator is not fully consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let mut drain = queue.drain_sorted();
+    /// assert_eq!(Some(("y", 7)), drain.next());
+    /// drop(drain);
+    ///
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, N, K, D> {
+        self.heap.drain_sorted()
+    }
+
+    /// Returns an iterator which removes all (node, key) pairs of the heap in an arbitrary order.
+    ///
+    /// Unlike `drain_sorted`, the elements are yielded in the order of the underlying tree without any sift cost;
+    /// hence, draining all elements requires linear time rather than the O(n log n) time of repeated `pop` calls.
+    /// This is useful when the elements are to be consumed regardless of their order, such as to reclaim owned nodes.
+    /// Once the iterator is dropped, the heap is empty even if the iterator is not fully consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let mut drained: Vec<_> = queue.drain().collect();
+    /// drained.sort();
+    /// assert_eq!(3, drained.len());
+    ///
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, N, K> {
+        self.heap.drain()
+    }
+
+    /// Pushes all (node, key) pairs of the `items` to the queue.
+    ///
+    /// Rather than heapifying each element on insertion, the elements are first appended to the end of the tree.
+    /// Then, the heap property is restored depending on the size of the batch relative to the current length of the queue:
+    /// * if the batch contains at least as many elements as the queue held before the call,
+    ///   the entire tree is rebuilt with a single bottom-up heapify in *O(n)* time;
+    /// * otherwise, each appended element is sifted up, which is equivalent to pushing the elements one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push('a', 3);
+    ///
+    /// queue.push_all([('b', 1), ('c', 4), ('d', 0)]);
+    /// assert_eq!(4, queue.len());
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [('d', 0), ('b', 1), ('a', 3), ('c', 4)]);
+    /// ```
+    pub fn push_all<I>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        self.heap.extend(items)
+    }
+
+    /// Moves all elements of `other` into this heap, leaving `other` empty.
+    ///
+    /// Elements are moved without being heapified one by one;
+    /// the heap property is then restored once on the combined tree,
+    /// which is cheaper than popping from `other` and pushing to this heap.
+    /// Allocation of `other` is retained for reuse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    ///
+    /// let mut other = QuaternaryHeap::default();
+    /// other.push('c', 2);
+    /// other.push('d', 0);
+    ///
+    /// queue.append(&mut other);
+    /// assert!(other.is_empty());
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [('d', 0), ('b', 1), ('c', 2), ('a', 3)]);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.heap.append(&mut other.heap)
+    }
+
+    /// Moves all elements of `other` into this heap, consuming `other`.
+    ///
+    /// Unlike `append`, `other` is taken by value; and hence, its allocation is not retained.
+    ///
+    /// # Complexity
+    ///
+    /// Being valid heaps, both trees are already partially ordered, which is meant to be exploited by a
+    /// specialized merge in the future.
+    /// Currently, elements of `other` are moved to the end of the tree and the heap property is restored once
+    /// as in `append`; this requires O(n + m) time when `other` is at least as large as this heap, where n and m
+    /// are the lengths of this heap and `other`, respectively, and O(m log(n + m)) time otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    ///
+    /// let mut other = QuaternaryHeap::default();
+    /// other.push('c', 2);
+    /// other.push('d', 0);
+    ///
+    /// queue.append_sorted(other);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [('d', 0), ('b', 1), ('c', 2), ('a', 3)]);
+    /// ```
+    pub fn append_sorted(&mut self, other: Self) {
+        self.heap.append_sorted(other.heap)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, removes all (node, key) pairs for which `f(&node, &key)` returns false.
+    /// The heap property is restored once after all removals, which requires linear time.
+    /// No heapify is required and the heap is not modified if all elements are retained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// (0..10).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// queue.retain(|node, _| node % 3 == 0);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (3, 30), (6, 60), (9, 90)]);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.retain(f)
+    }
+
+    /// Retains only the elements specified by the predicate, which may also mutate the keys of the elements.
+    ///
+    /// In other words, removes all (node, key) pairs for which `f(&node, &mut key)` returns false;
+    /// and the keys of the retained elements are updated as mutated by `f`.
+    /// This allows to both evict elements and adjust the keys of the survivors in a single pass,
+    /// such as decaying priorities while dropping the expired ones.
+    ///
+    /// The heap property is restored once after all removals and mutations by a bottom-up heapify;
+    /// hence, the method requires *O(n)* time.
+    /// Unlike [`retain`], the heap is heapified even if all elements are retained, since their keys might have changed.
+    ///
+    /// [`retain`]: Self::retain
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// (0..10).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// // drops the odd nodes, and reverses the order of the keys of the others
+    /// queue.retain_mut(|node, key| {
+    ///     *key = 100 - *key;
+    ///     node % 2 == 0
+    /// });
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(8, 20), (6, 40), (4, 60), (2, 80), (0, 100)]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&N, &mut K) -> bool,
+    {
+        self.heap.retain_mut(f)
+    }
+
+    /// Removes all elements for which `f(&node, &key)` returns true from the queue, and returns them.
+    ///
+    /// The order of the returned (node, key) pairs is unspecified.
+    /// Unlike [`retain`], the removed elements are returned, which allows to partition the queue into kept and evicted elements in one pass;
+    /// hence, it corresponds to the `drain_filter` (`extract_if`) operation of the standard collections.
+    ///
+    /// [`retain`]: Self::retain
+    ///
+    /// The heap property is restored once after all removals;
+    /// hence, the method requires linear time regardless of the number of removed elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// (0..10).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// let mut removed = queue.remove_if(|_, key| *key >= 50);
+    /// removed.sort();
+    /// assert_eq!(removed, [(5, 50), (6, 60), (7, 70), (8, 80), (9, 90)]);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+    /// ```
+    #[doc(alias = "drain_filter")]
+    #[doc(alias = "extract_if")]
+    pub fn remove_if<F>(&mut self, f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.remove_if(f)
+    }
+
+    /// Splits the queue at the given `threshold`: removes all elements with keys greater than or equal to the
+    /// `threshold` into a new queue which is returned, leaving the elements with smaller keys in this queue.
+    ///
+    /// The tree is scanned once to partition the elements, and the heap property of each of the two queues
+    /// is restored by a single bottom-up heapify; hence, the method requires linear time.
+    /// Elements whose keys are not comparable to the `threshold`, such as NaN keys, remain in this queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut urgent = QuaternaryHeap::default();
+    /// (0..10).for_each(|x| urgent.push(x, x * 10));
+    ///
+    /// let later = urgent.split_off_ge(50);
+    ///
+    /// let popped: Vec<_> = urgent.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+    ///
+    /// let popped: Vec<_> = later.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(5, 50), (6, 60), (7, 70), (8, 80), (9, 90)]);
+    /// ```
+    pub fn split_off_ge(&mut self, threshold: K) -> Self {
+        Self {
+            heap: self.heap.split_off_ge(&threshold, HeapPositionsNone),
+        }
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueue<N, K> for DaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a> = &'a (N, K) where Self: 'a, N: 'a, K: 'a;
+    type Iter<'a> = core::slice::Iter<'a, (N, K)> where Self: 'a, N: 'a, K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.heap.peek()
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop()
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node()
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key()
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        self.heap.push(node, key)
+    }
+
+    #[inline(always)]
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        self.heap.push_then_pop(node, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
+
+/// Two heaps are equal if they contain the same (node, key) pairs with the same multiplicities,
+/// regardless of the layouts of their trees; i.e., if they would pop identical sequences up to the order of equal keys.
+///
+/// The comparison iterates over both heaps in sorted order and requires *O(n log n)* time.
+impl<N, K, const D: usize> PartialEq for DaryHeap<N, K, D>
+where
+    N: Clone + Eq,
+    K: PartialOrd + Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        // elements with equal keys are compared as multisets of nodes
+        let mut iter = self.iter_sorted().peekable();
+        let mut other_iter = other.iter_sorted();
+        let mut group = Vec::new();
+        while let Some(first) = iter.next() {
+            group.clear();
+            group.push(&first.0);
+            while let Some(x) = iter.next_if(|x| x.1 == first.1) {
+                group.push(&x.0);
+            }
+
+            for _ in 0..group.len() {
+                let matched = other_iter
+                    .next()
+                    .filter(|x| x.1 == first.1)
+                    .and_then(|x| group.iter().position(|node| *node == &x.0));
+                match matched {
+                    Some(position) => _ = group.swap_remove(position),
+                    None => return false,
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Hashes the heap by its (node, key) pairs, allowing heaps to be used as keys of hash maps such as in memoization of search states.
+///
+/// Consistent with `PartialEq`, the hash depends only on the multiset of the (node, key) pairs and not on the layout of the tree;
+/// hence, equal heaps hash equally. Each element is hashed separately and the sorted element hashes are fed into the hasher,
+/// which requires *O(n log n)* time.
+impl<N, K, const D: usize> Hash for DaryHeap<N, K, D>
+where
+    N: Clone + Eq + Hash,
+    K: PartialOrd + Clone + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.heap.hash_elements(state)
+    }
+}
+
+impl<N, K, const D: usize> Eq for DaryHeap<N, K, D>
+where
+    N: Clone + Eq,
+    K: PartialOrd + Clone + Eq,
+{
+}
+
+impl<N, K, const D: usize> Extend<(N, K)> for DaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        self.heap.extend(iter)
+    }
+}
+
+/// Creates a heap of the given (node, key) pairs with a single bottom-up heapify in *O(n)* time.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = BinaryHeap::from(vec![(0, 5.0), (1, 2.0), (2, 3.0)]);
+///
+/// assert_eq!(Some((1, 2.0)), queue.pop());
+/// assert_eq!(Some((2, 3.0)), queue.pop());
+/// assert_eq!(Some((0, 5.0)), queue.pop());
+/// ```
+impl<N, K, const D: usize> From<Vec<(N, K)>> for DaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn from(elements: Vec<(N, K)>) -> Self {
+        let mut queue = Self::with_capacity(elements.len());
+        queue.heap.extend(elements);
+        queue
+    }
+}
+
+/// Creates a heap of the given (node, key) pairs with a single bottom-up heapify in *O(n)* time.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = QuaternaryHeap::from([('a', 5.0), ('b', 2.0), ('a', 3.0)]);
+///
+/// assert_eq!(Some(('b', 2.0)), queue.pop());
+/// assert_eq!(Some(('a', 3.0)), queue.pop());
+/// assert_eq!(Some(('a', 5.0)), queue.pop());
+/// ```
+impl<N, K, const D: usize, const M: usize> From<[(N, K); M]> for DaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn from(elements: [(N, K); M]) -> Self {
+        let mut queue = Self::with_capacity(M);
+        queue.heap.extend(elements);
+        queue
+    }
+}
+
+/// Serializes the heap as the sequence of its (node, key) pairs.
+#[cfg(feature = "serde")]
+impl<N, K, const D: usize> serde::Serialize for DaryHeap<N, K, D>
+where
+    N: Clone + serde::Serialize,
+    K: PartialOrd + Clone + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+/// Deserializes the heap from the sequence of its (node, key) pairs.
+#[cfg(feature = "serde")]
+impl<'de, N, K, const D: usize> serde::Deserialize<'de> for DaryHeap<N, K, D>
+where
+    N: Clone + serde::Deserialize<'de>,
+    K: PartialOrd + Clone + serde::Deserialize<'de>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let elements = Vec::<(N, K)>::deserialize(deserializer)?;
+        let heap = Heap::from_elements(elements, HeapPositionsNone)
+            .ok_or_else(|| serde::de::Error::custom("duplicate node"))?;
+        Ok(Self { heap })
     }
 }
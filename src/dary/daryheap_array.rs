@@ -0,0 +1,368 @@
+use super::{
+    daryheap_const_helpers::offset, heap::Heap, iter_mut::IterMut, iter_sorted::IterSorted,
+    peek_mut::PeekMut,
+};
+use crate::{positions::none::HeapPositionsNone, HeapError, PriorityQueue};
+use arrayvec::ArrayVec;
+
+type ArrayTree<N, K, const CAP: usize> = ArrayVec<(N, K), CAP>;
+
+/// A d-ary heap which implements `PriorityQueue`, but not `PriorityQueueDecKey`, storing its tree in an `ArrayVec`
+/// with a compile time capacity; requires `--features arrayvec`.
+///
+/// The heap never allocates, which makes it suitable for embedded and real-time systems forbidding heap allocations.
+/// The sift operations are identical to those of [`DaryHeap`](crate::DaryHeap).
+///
+/// Since the heap cannot grow, [`try_push`](Self::try_push) hands the rejected (node, key) pair back to the caller
+/// when the heap is full, leaving the heap unchanged.
+/// On the other hand, `push` of the [`PriorityQueue`] trait panics when the heap is full.
+///
+/// # Capacity and the offset of the tree
+///
+/// Note that `CAP` is the capacity of the tree which also holds the offset aligning the children of the nodes;
+/// the offset is `D - 1` when `D` is a power of two not greater than 64, and 0 otherwise.
+/// Therefore, the heap can hold up to `CAP - offset` elements, which is returned by [`max_len`](Self::max_len).
+/// For instance, an `ArrayDaryHeap<N, K, 4, 16>` holds up to 13 elements, while an `ArrayDaryHeap<N, K, 3, 16>` holds 16.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = ArrayDaryHeap::<char, i32, 4, 16>::new();
+/// assert_eq!(13, queue.capacity());
+///
+/// for (i, node) in ('a'..='m').enumerate() {
+///     assert!(queue.try_push(node, 100 - i as i32).is_ok());
+/// }
+///
+/// assert_eq!(Err(('x', 0)), queue.try_push('x', 0));
+///
+/// assert_eq!(Some(('m', 88)), queue.pop());
+/// assert_eq!(Ok(()), queue.try_push('x', 0));
+/// assert_eq!(Some(('x', 0)), queue.pop());
+/// ```
+#[derive(Debug)]
+pub struct ArrayDaryHeap<N, K, const D: usize, const CAP: usize>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    heap: Heap<N, K, HeapPositionsNone, D, ArrayTree<N, K, CAP>>,
+}
+
+impl<N, K, const D: usize, const CAP: usize> Clone for ArrayDaryHeap<N, K, D, CAP>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.heap.clone_from(&source.heap);
+    }
+}
+
+impl<N, K, const D: usize, const CAP: usize> Default for ArrayDaryHeap<N, K, D, CAP>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        Self {
+            heap: Heap::new(None, HeapPositionsNone),
+        }
+    }
+}
+
+impl<N, K, const D: usize, const CAP: usize> ArrayDaryHeap<N, K, D, CAP>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    /// Creates a new empty d-ary heap with the fixed capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut heap = ArrayDaryHeap::<_, _, 4, 8>::new();
+    ///
+    /// heap.push('a', 4);
+    /// heap.push('b', 42);
+    ///
+    /// assert_eq!(Some('a'), heap.pop_node());
+    /// assert_eq!(Some('b'), heap.pop_node());
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Returns the maximum number of elements that the heap can hold, which is `CAP` minus the offset of the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// assert_eq!(15, ArrayDaryHeap::<char, i32, 2, 16>::max_len());
+    /// assert_eq!(16, ArrayDaryHeap::<char, i32, 3, 16>::max_len());
+    /// assert_eq!(13, ArrayDaryHeap::<char, i32, 4, 16>::max_len());
+    /// assert_eq!(9, ArrayDaryHeap::<char, i32, 8, 16>::max_len());
+    /// ```
+    pub const fn max_len() -> usize {
+        CAP.saturating_sub(offset::<D>())
+    }
+
+    /// Returns whether or not the heap is full; i.e., whether or not its length is equal to [`max_len`](Self::max_len).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = ArrayDaryHeap::<char, i32, 2, 3>::new();
+    ///
+    /// queue.push('a', 1);
+    /// assert!(!queue.is_full());
+    ///
+    /// queue.push('b', 2);
+    /// assert!(queue.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.heap.len() >= Self::max_len()
+    }
+
+    /// Pushes the given (`node`, `key`) pair to the queue provided that the heap is not full.
+    ///
+    /// Returns the rejected (`node`, `key`) pair back unmodified otherwise, in which case the queue is not modified;
+    /// the caller can then decide whether to drop the pair, evict an element to make room for it, or keep it elsewhere.
+    /// This is the non-panicking counterpart of `push`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = ArrayDaryHeap::<char, i32, 2, 3>::new();
+    ///
+    /// assert_eq!(Ok(()), queue.try_push('a', 42));
+    /// assert_eq!(Ok(()), queue.try_push('b', 7));
+    ///
+    /// assert_eq!(Err(('c', 1)), queue.try_push('c', 1));
+    ///
+    /// assert_eq!(2, queue.len());
+    /// assert_eq!(Some(('b', 7)), queue.pop());
+    /// ```
+    pub fn try_push(&mut self, node: N, key: K) -> Result<(), (N, K)> {
+        match self.is_full() {
+            true => Err((node, key)),
+            false => {
+                self.heap.push(node, key);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the nodes and keys currently in the queue as a slice;
+    /// not necessarily sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = ArrayDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let slice = queue.as_slice();
+    ///
+    /// assert_eq!(3, slice.len());
+    /// assert!(slice.contains(&("x", 42)));
+    /// assert!(slice.contains(&("y", 7)));
+    /// assert!(slice.contains(&("z", 99)));
+    /// ```
+    pub fn as_slice(&self) -> &[(N, K)] {
+        self.heap.as_slice()
+    }
+
+    /// Validates the heap; i.e., checks whether or not the key of every element is greater than or equal to
+    /// the key of its parent on the tree.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = ArrayDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
+    }
+
+    /// Returns a mutable reference to the element with the smallest key, which restores the heap property
+    /// once it is dropped if its key has been mutated; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = ArrayDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push('a', 1);
+    /// queue.push('b', 2);
+    ///
+    /// if let Some(mut root) = queue.peek_mut() {
+    ///     *root.key_mut() = 3;
+    /// }
+    ///
+    /// assert_eq!(Some(('b', 2)), queue.pop());
+    /// assert_eq!(Some(('a', 3)), queue.pop());
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, N, K>> {
+        self.heap.peek_mut()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the heap in arbitrary order;
+    /// the heap property is restored once the iterator is dropped if the keys are mutated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = ArrayDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push('a', 1);
+    /// queue.push('b', 2);
+    ///
+    /// for (node, key) in &mut queue.iter_mut() {
+    ///     if *node == 'b' {
+    ///         *key = 0;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(('b', 0)), queue.pop());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, N, K> {
+        self.heap.iter_mut()
+    }
+
+    /// Returns an iterator visiting the elements of the heap in ascending order of keys without mutating the heap.
+    ///
+    /// Note that, unlike the heap itself, the iterator allocates to keep track of its candidates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = ArrayDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let sorted: Vec<_> = queue.iter_sorted().collect();
+    /// assert_eq!(vec![&("y", 7), &("x", 42), &("z", 99)], sorted);
+    /// ```
+    pub fn iter_sorted(&self) -> IterSorted<'_, N, K, D> {
+        self.heap.iter_sorted()
+    }
+}
+
+impl<N, K, const D: usize, const CAP: usize> PriorityQueue<N, K> for ArrayDaryHeap<N, K, D, CAP>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a>
+        = &'a (N, K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = core::slice::Iter<'a, (N, K)>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.heap.peek()
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop()
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node()
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key()
+    }
+
+    /// Pushes the given (`node`, `key`) pair to the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heap is full; see [`ArrayDaryHeap::try_push`] for the non-panicking counterpart.
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        assert!(!self.is_full(), "ArrayDaryHeap is full");
+        self.heap.push(node, key)
+    }
+
+    #[inline(always)]
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        self.heap.push_then_pop(node, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
@@ -0,0 +1,257 @@
+use super::daryheap::DaryHeap;
+use crate::{HeapError, PriorityQueue};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// A d-ary heap keeping only the `bound` elements with the smallest keys among all elements pushed to it;
+/// useful for maintaining the running k smallest elements of a stream.
+///
+/// Internally, it is a max-heap of at most `bound` elements, which is a [`DaryHeap`] of reversed keys.
+/// Once the heap is full, a pushed element replaces the element with the largest key if its key is smaller;
+/// and it is discarded otherwise.
+/// Therefore, each push requires *O(log k)* time where k is the bound.
+///
+/// Since the root of the heap is the element with the largest key, `BoundedDaryHeap` does not implement [`PriorityQueue`];
+/// the elements are obtained in ascending order of keys by [`into_sorted_vec`](Self::into_sorted_vec).
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut smallest = BoundedDaryHeap::<_, _, 4>::with_bound(3);
+///
+/// for (node, key) in [('a', 5), ('b', 2), ('c', 8), ('d', 1), ('e', 7), ('f', 3)] {
+///     smallest.push(node, key);
+/// }
+///
+/// assert_eq!(3, smallest.len());
+/// assert_eq!(Some((&'f', &3)), smallest.peek_max());
+/// assert_eq!(vec![('d', 1), ('b', 2), ('f', 3)], smallest.into_sorted_vec());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedDaryHeap<N, K, const D: usize = 2>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    heap: DaryHeap<N, Reverse<K>, D>,
+    bound: usize,
+}
+
+impl<N, K, const D: usize> BoundedDaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    /// Creates a new empty heap which keeps at most `bound` elements with the smallest keys.
+    ///
+    /// The capacity for `bound` elements is reserved up front; hence, the heap does not reallocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut smallest = BoundedDaryHeap::<char, i32>::with_bound(2);
+    ///
+    /// smallest.push('a', 3);
+    /// smallest.push('b', 1);
+    /// smallest.push('c', 2);
+    ///
+    /// assert_eq!(vec![('b', 1), ('c', 2)], smallest.into_sorted_vec());
+    /// ```
+    pub fn with_bound(bound: usize) -> Self {
+        Self {
+            heap: DaryHeap::with_capacity(bound),
+            bound,
+        }
+    }
+
+    /// Returns the maximum number of elements that the heap keeps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let smallest = BoundedDaryHeap::<char, i32>::with_bound(10);
+    /// assert_eq!(10, smallest.bound());
+    /// ```
+    pub fn bound(&self) -> usize {
+        self.bound
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Number of elements in the heap, which is never greater than the [`bound`](Self::bound).
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns whether or not the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns whether or not the heap is full; i.e., whether or not its length is equal to its [`bound`](Self::bound).
+    ///
+    /// Once the heap is full, pushed elements compete with the element with the largest key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut smallest = BoundedDaryHeap::<char, i32>::with_bound(2);
+    ///
+    /// smallest.push('a', 3);
+    /// assert!(!smallest.is_full());
+    ///
+    /// smallest.push('b', 1);
+    /// assert!(smallest.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.heap.len() >= self.bound
+    }
+
+    /// Pushes the (`node`, `key`) pair to the heap while keeping only the `bound` elements with the smallest keys:
+    /// * when the heap is not full, the pair is pushed and None is returned;
+    /// * otherwise, if `key` is smaller than the largest key on the heap, the pair replaces the element with the
+    ///   largest key, which is returned;
+    /// * otherwise, the pair is discarded and returned back.
+    ///
+    /// In other words, the returned element, if any, is the element which is left out of the smallest `bound` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut smallest = BoundedDaryHeap::<char, i32>::with_bound(2);
+    ///
+    /// assert_eq!(None, smallest.push('a', 5));
+    /// assert_eq!(None, smallest.push('b', 3));
+    ///
+    /// // replaces the largest
+    /// assert_eq!(Some(('a', 5)), smallest.push('c', 1));
+    ///
+    /// // discarded
+    /// assert_eq!(Some(('d', 4)), smallest.push('d', 4));
+    ///
+    /// assert_eq!(vec![('c', 1), ('b', 3)], smallest.into_sorted_vec());
+    /// ```
+    pub fn push(&mut self, node: N, key: K) -> Option<(N, K)> {
+        if !self.is_full() {
+            self.heap.push(node, Reverse(key));
+            return None;
+        }
+
+        match self.heap.peek() {
+            Some((_, Reverse(max))) if &key < max => self
+                .heap
+                .replace_root(node, Reverse(key))
+                .map(|(node, key)| (node, key.0)),
+            _ => Some((node, key)),
+        }
+    }
+
+    /// Returns the element with the largest key on the heap, which is the threshold a pushed key must be smaller than
+    /// to enter the heap once it is full; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut smallest = BoundedDaryHeap::<char, i32>::with_bound(3);
+    /// assert_eq!(None, smallest.peek_max());
+    ///
+    /// smallest.push('a', 3);
+    /// smallest.push('b', 7);
+    /// smallest.push('c', 1);
+    /// assert_eq!(Some((&'b', &7)), smallest.peek_max());
+    /// ```
+    pub fn peek_max(&self) -> Option<(&N, &K)> {
+        self.heap.peek().map(|(node, key)| (node, &key.0))
+    }
+
+    /// Removes and returns the element with the largest key on the heap; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut smallest = BoundedDaryHeap::<char, i32>::with_bound(3);
+    /// smallest.push('a', 3);
+    /// smallest.push('b', 7);
+    ///
+    /// assert_eq!(Some(('b', 7)), smallest.pop_max());
+    /// assert_eq!(Some(('a', 3)), smallest.pop_max());
+    /// assert_eq!(None, smallest.pop_max());
+    /// ```
+    pub fn pop_max(&mut self) -> Option<(N, K)> {
+        self.heap.pop().map(|(node, key)| (node, key.0))
+    }
+
+    /// Returns an iterator visiting the elements on the heap in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut smallest = BoundedDaryHeap::<char, i32>::with_bound(2);
+    /// smallest.push('a', 3);
+    /// smallest.push('b', 7);
+    /// smallest.push('c', 1);
+    ///
+    /// let mut nodes: Vec<_> = smallest.iter().map(|(node, _)| *node).collect();
+    /// nodes.sort();
+    /// assert_eq!(vec!['a', 'c'], nodes);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&N, &K)> {
+        self.heap
+            .as_slice()
+            .iter()
+            .map(|(node, key)| (node, &key.0))
+    }
+
+    /// Clears the heap; the bound is unchanged.
+    pub fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    /// Consumes the heap and returns its elements in ascending order of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut smallest = BoundedDaryHeap::<usize, u32, 4>::with_bound(3);
+    /// for (node, key) in [9, 4, 7, 1, 8, 2].into_iter().enumerate() {
+    ///     smallest.push(node, key);
+    /// }
+    ///
+    /// assert_eq!(vec![(3, 1), (5, 2), (1, 4)], smallest.into_sorted_vec());
+    /// ```
+    pub fn into_sorted_vec(mut self) -> Vec<(N, K)> {
+        let mut sorted: Vec<_> = core::iter::from_fn(|| self.pop_max()).collect();
+        sorted.reverse();
+        sorted
+    }
+
+    /// Validates the heap; i.e., checks whether or not the key of every element is less than or equal to
+    /// the key of its parent on the max-heap tree.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
+    }
+}
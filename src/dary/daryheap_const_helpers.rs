@@ -15,6 +15,46 @@ pub(crate) const fn offset<const D: usize>() -> usize {
     }
 }
 
+/// Returns the number of leading padding cells of the tree of a d-ary heap with arity `D`;
+/// i.e., `D - 1` when `D` is a power of two not greater than 64, and 0 otherwise.
+///
+/// The tree of a d-ary heap is stored in a vector where the children of the element at position `i` are
+/// at positions `D * i + 1` to `D * i + D`, and the parent of the element at position `c` is at `(c - 1) / D`.
+/// When `D` is a power of two, `D - 1` padding cells are prepended to the tree so that the elements of the heap
+/// start at position `D - 1` rather than 0. With this shift, the children of every element start at a
+/// multiple of `D` and are aligned, and the parent of the element at position `c` is computed by a single
+/// shift and addition, `(c >> log2(D)) + D - 2`, without the subtraction and division.
+/// The padding cells are copies of an arbitrary element and are never considered as elements of the heap.
+///
+/// This is the number of leading cells of the tree returned by `into_parts` of the d-ary heaps,
+/// and hence, the number of cells which must be prepended to the elements of a tree which is constructed
+/// externally to be passed to `from_parts`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// assert_eq!(1, heap_offset::<2>());
+/// assert_eq!(0, heap_offset::<3>());
+/// assert_eq!(3, heap_offset::<4>());
+/// assert_eq!(0, heap_offset::<5>());
+///
+/// // constructing the tree of a heap externally
+/// let elements = vec![('a', 1), ('b', 3), ('c', 2)];
+///
+/// let offset = heap_offset::<4>();
+/// let mut tree = vec![elements[0]; offset];
+/// tree.extend(elements);
+///
+/// let queue = unsafe { QuaternaryHeap::from_parts(tree, HeapPositionsNone) };
+/// assert_eq!(Ok(()), queue.validate());
+/// assert_eq!(Some(&('a', 1)), queue.peek());
+/// ```
+pub const fn heap_offset<const D: usize>() -> usize {
+    offset::<D>()
+}
+
 /// Let c = `child`.
 ///
 /// when `D` = 2^k
@@ -23,6 +63,8 @@ pub(crate) const fn offset<const D: usize>() -> usize {
 ///
 /// otherwise
 /// * parent = (c - 1) / `D`
+///
+/// Since `D` is a constant, the compiler lowers the division into a multiplication and a shift in optimized builds.
 pub(crate) const fn parent_of<const D: usize>(child: usize) -> usize {
     match D {
         2 => child >> 1,
@@ -54,3 +96,27 @@ pub(crate) const fn left_child_of<const D: usize>(parent: usize) -> usize {
         _ => D * parent + 1,
     }
 }
+
+/// Let p = `position` relative to the root at 0, ignoring the offset.
+///
+/// Returns the level of the position on the tree where the root is at level 0;
+/// in other words, the number of its ancestors.
+pub(crate) const fn level_of<const D: usize>(position: usize) -> usize {
+    let mut level = 0;
+    let mut level_end = 1;
+    let mut level_size: usize = 1;
+    while position >= level_end {
+        level_size = level_size.saturating_mul(D);
+        level_end = level_end.saturating_add(level_size);
+        level += 1;
+    }
+    level
+}
+
+/// Returns the number of levels of a tree with `len` elements; 0 if the tree is empty.
+pub(crate) const fn depth_of<const D: usize>(len: usize) -> usize {
+    match len {
+        0 => 0,
+        _ => level_of::<D>(len - 1) + 1,
+    }
+}
@@ -0,0 +1,455 @@
+use super::{entry::HeapEntry, heap::Heap};
+use crate::{
+    positions::handles::{Handle, HandleNode, HeapPositionsHandles},
+    HeapError, PriorityQueue, ResUpdateKey,
+};
+use core::iter::FusedIterator;
+
+/// Type alias for `DaryHeapOfHandles<N, K, 2>`; see [`DaryHeapOfHandles`] for details.
+pub type BinaryHeapOfHandles<N, K> = DaryHeapOfHandles<N, K, 2>;
+/// Type alias for `DaryHeapOfHandles<N, K, 4>`; see [`DaryHeapOfHandles`] for details.
+pub type QuaternaryHeapOfHandles<N, K> = DaryHeapOfHandles<N, K, 4>;
+
+/// A d-ary heap which implements `PriorityQueue` and allows to access the nodes on the queue by
+/// stable [`Handle`]s returned by its `push` method.
+///
+/// The handle of a node can later be used to access its key, to decrease or update its key, or to remove it from the queue.
+/// Handles are generational indices into a vector of slots;
+/// they are invalidated once their nodes leave the queue by `pop` or `remove`.
+///
+/// # Flexibility and Performance
+///
+/// `DaryHeapOfHandles` fits between the other two variants providing decrease-key operations:
+/// * unlike `DaryHeapWithMap`, it does not require the nodes to implement `Hash + Eq` and does not use a map;
+/// * unlike `DaryHeapOfIndices`, it does not require the nodes to implement `HasIndex` and does not have a fixed bound.
+///
+/// On the other hand, since nodes are addressed by handles rather than by themselves,
+/// it does not implement [`PriorityQueueDecKey`], and the handle returned by `push` must be kept to access the node later.
+///
+/// [`PriorityQueueDecKey`]: crate::PriorityQueueDecKey
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Task {
+///     name: String,
+/// }
+///
+/// let mut queue = QuaternaryHeapOfHandles::default();
+///
+/// let a = queue.push(Task { name: "a".to_string() }, 42);
+/// let b = queue.push(Task { name: "b".to_string() }, 17);
+/// assert_eq!(Some(&17), queue.key_of(&b));
+///
+/// queue.decrease_key(&a, 7);
+/// assert_eq!(Some(&7), queue.key_of(&a));
+///
+/// let (task, key) = queue.pop().unwrap();
+/// assert_eq!(("a", 7), (task.name.as_str(), key));
+///
+/// // handle of a popped node is invalidated
+/// assert!(!queue.contains(&a));
+/// assert!(queue.contains(&b));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DaryHeapOfHandles<N, K, const D: usize = 2>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    heap: Heap<HandleNode<N>, K, HeapPositionsHandles, D>,
+}
+
+impl<N, K, const D: usize> Default for DaryHeapOfHandles<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        Self {
+            heap: Heap::new(None, HeapPositionsHandles::default()),
+        }
+    }
+}
+
+impl<N, K, const D: usize> DaryHeapOfHandles<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    /// Creates a new empty d-ary heap of handles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut heap = BinaryHeapOfHandles::new();
+    ///
+    /// heap.push('a', 4);
+    /// heap.push('b', 42);
+    ///
+    /// assert_eq!(Some('a'), heap.pop_node());
+    /// assert_eq!(Some('b'), heap.pop_node());
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new d-ary heap of handles with the given initial `capacity` on the number of nodes to simultaneously exist on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = DaryHeapOfHandles::<_, _, 4>::with_capacity(4);
+    /// queue.push('a', 4);
+    /// assert_eq!(Some('a'), queue.pop_node());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: Heap::new(
+                Some(capacity),
+                HeapPositionsHandles::with_capacity(capacity),
+            ),
+        }
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Pushes the given (`node`, `key`) pair to the queue; and returns the handle of the node.
+    ///
+    /// The handle remains valid until the node leaves the queue by `pop` or `remove`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    ///
+    /// let a = queue.push('a', 42);
+    /// let b = queue.push('a', 7); // nodes are not required to be distinct
+    /// assert_ne!(a, b);
+    ///
+    /// assert_eq!(Some(&42), queue.key_of(&a));
+    /// assert_eq!(Some(&7), queue.key_of(&b));
+    /// ```
+    pub fn push(&mut self, node: N, key: K) -> Handle {
+        let handle = self.heap.positions_mut().allocate();
+        self.heap.push(HandleNode { handle, node }, key);
+        handle
+    }
+
+    /// Returns whether or not the node of the `handle` is in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    ///
+    /// let a = queue.push('a', 42);
+    /// assert!(queue.contains(&a));
+    ///
+    /// queue.pop();
+    /// assert!(!queue.contains(&a));
+    /// ```
+    pub fn contains(&self, handle: &Handle) -> bool {
+        self.heap.positions().position_of_handle(handle).is_some()
+    }
+
+    /// Returns a reference to the node of the `handle`; None if the node is not in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    ///
+    /// let a = queue.push('a', 42);
+    /// assert_eq!(Some(&'a'), queue.node_of(&a));
+    ///
+    /// queue.pop();
+    /// assert_eq!(None, queue.node_of(&a));
+    /// ```
+    pub fn node_of(&self, handle: &Handle) -> Option<&N> {
+        self.element_of(handle).map(|x| &x.0.node)
+    }
+
+    /// Returns a reference to the key of the node of the `handle`; None if the node is not in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    ///
+    /// let a = queue.push('a', 42);
+    /// assert_eq!(Some(&42), queue.key_of(&a));
+    ///
+    /// queue.pop();
+    /// assert_eq!(None, queue.key_of(&a));
+    /// ```
+    pub fn key_of(&self, handle: &Handle) -> Option<&K> {
+        self.element_of(handle).map(|x| &x.1)
+    }
+
+    /// Decreases key of the node of the `handle` to the given `decreased_key`; and returns the previous key of the node.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if:
+    /// * the node of the `handle` is not in the queue; or
+    /// * `decreased_key` is strictly larger than the current key of the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    ///
+    /// let a = queue.push('a', 42);
+    /// queue.push('b', 17);
+    ///
+    /// assert_eq!(42, queue.decrease_key(&a, 7));
+    /// assert_eq!(Some(('a', 7)), queue.pop());
+    /// ```
+    pub fn decrease_key(&mut self, handle: &Handle, decreased_key: K) -> K {
+        let position = self
+            .heap
+            .positions()
+            .position_of_handle(handle)
+            .expect("cannot decrease key of a node that is not on the queue");
+        self.heap.decrease_key_at(position, decreased_key)
+    }
+
+    /// Updates key of the node of the `handle` to the given `new_key`;
+    /// and returns whether the key is decreased or increased.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the node of the `handle` is not in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    ///
+    /// let a = queue.push('a', 7);
+    /// queue.push('b', 17);
+    ///
+    /// assert_eq!(ResUpdateKey::Increased, queue.update_key(&a, 42));
+    /// assert_eq!(Some(('b', 17)), queue.pop());
+    /// ```
+    pub fn update_key(&mut self, handle: &Handle, new_key: K) -> ResUpdateKey {
+        let position = self
+            .heap
+            .positions()
+            .position_of_handle(handle)
+            .expect("cannot update key of a node that is not on the queue");
+        self.heap.update_key_at(position, new_key)
+    }
+
+    /// Removes the node of the `handle` from the queue; and returns the removed (node, key) pair.
+    /// The handle is invalidated.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the node of the `handle` is not in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    ///
+    /// let a = queue.push('a', 7);
+    /// queue.push('b', 17);
+    ///
+    /// assert_eq!(('a', 7), queue.remove(&a));
+    /// assert!(!queue.contains(&a));
+    /// assert_eq!(1, queue.len());
+    /// ```
+    pub fn remove(&mut self, handle: &Handle) -> (N, K) {
+        let position = self
+            .heap
+            .positions()
+            .position_of_handle(handle)
+            .expect("cannot remove a node that is not on the queue");
+        let (node, key) = self.heap.remove_and_heapify(position);
+        (node.node, key)
+    }
+
+    /// Validates the heap; i.e., checks whether or not:
+    /// * the key of every element is greater than or equal to the key of its parent on the tree, and
+    /// * the positions of the nodes on the tree are tracked correctly by their handles.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    ///
+    /// Since the heap maintains its invariants on its own, this method is mainly useful for debugging and testing.
+    /// It requires *O(n)* time where n is the number of elements on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfHandles::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
+    }
+
+    fn element_of(&self, handle: &Handle) -> Option<&(HandleNode<N>, K)> {
+        self.heap
+            .positions()
+            .position_of_handle(handle)
+            .map(|position| self.heap.element_at(position))
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueue<N, K> for DaryHeapOfHandles<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a>
+        = (&'a N, &'a K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = IterHandles<'a, N, K>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<(&N, &K)> {
+        self.heap.peek().map(|x| (&x.0.node, &x.1))
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop().map(|(node, key)| (node.node, key))
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node().map(|node| node.node)
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key()
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        _ = DaryHeapOfHandles::push(self, node, key);
+    }
+
+    #[inline(always)]
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        match self.heap.peek() {
+            Some(root) if root.1 < key => {
+                let handle = self.heap.positions_mut().allocate();
+                let (node, key) = self.heap.push_then_pop(HandleNode { handle, node }, key);
+                (node.node, key)
+            }
+            _ => (node, key),
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        IterHandles {
+            iter: self.heap.as_slice().iter(),
+        }
+    }
+}
+
+impl<N, K, const D: usize> Extend<(N, K)> for DaryHeapOfHandles<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    /// Pushes all (node, key) pairs of the iterator to the queue; the handles of the pushed nodes are discarded.
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.heap.reserve(iter.size_hint().0);
+        for (node, key) in iter {
+            _ = DaryHeapOfHandles::push(self, node, key);
+        }
+    }
+}
+
+/// An iterator over the (node, key) pairs of a [`DaryHeapOfHandles`] in an arbitrary order.
+///
+/// Created by the `iter` method of the heap.
+pub struct IterHandles<'a, N, K> {
+    iter: core::slice::Iter<'a, (HandleNode<N>, K)>,
+}
+
+impl<'a, N, K> Iterator for IterHandles<'a, N, K> {
+    type Item = (&'a N, &'a K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|x| (&x.0.node, &x.1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<N, K> DoubleEndedIterator for IterHandles<'_, N, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|x| (&x.0.node, &x.1))
+    }
+}
+
+impl<N, K> ExactSizeIterator for IterHandles<'_, N, K> {}
+
+impl<N, K> FusedIterator for IterHandles<'_, N, K> {}
@@ -1,13 +1,33 @@
-use super::heap::Heap;
+use super::{
+    daryheap::DaryHeap, daryheap_const_helpers::level_of, daryheap_map::DaryHeapWithMap,
+    drain::Drain, drain_sorted::DrainSorted, entry::Entry, heap::Heap,
+    into_iter_sorted::IntoIterSorted, iter_mut::IterMut, iter_sorted::IterSorted,
+    peek_mut::PeekMut,
+};
 use crate::{
-    positions::has_index::HeapPositionsHasIndex, HasIndex, PriorityQueue, PriorityQueueDecKey,
+    positions::{
+        has_index::HeapPositionsHasIndex,
+        map::{HeapPositionsMap, Index},
+    },
+    HasIndex, HeapError, IndexOutOfBound, PriorityQueue, PriorityQueueDecKey, RenameError,
     ResUpdateKey,
 };
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::{
+    cmp::Ordering,
+    iter::{Rev, Take},
+    slice::Iter,
+};
 
 /// Type alias for `DaryHeapOfIndices<N, K, 2>`; see [`DaryHeapOfIndices`] for details.
 pub type BinaryHeapOfIndices<N, K> = DaryHeapOfIndices<N, K, 2>;
+/// Type alias for `DaryHeapOfIndices<N, K, 3>`; see [`DaryHeapOfIndices`] for details.
+pub type TernaryHeapOfIndices<N, K> = DaryHeapOfIndices<N, K, 3>;
 /// Type alias for `DaryHeapOfIndices<N, K, 4>`; see [`DaryHeapOfIndices`] for details.
 pub type QuaternaryHeapOfIndices<N, K> = DaryHeapOfIndices<N, K, 4>;
+/// Type alias for `DaryHeapOfIndices<N, K, 8>`; see [`DaryHeapOfIndices`] for details.
+pub type OctaryHeapOfIndices<N, K> = DaryHeapOfIndices<N, K, 8>;
 
 /// A d-ary heap which implements both `PriorityQueue` and `PriorityQueueDecKey`.
 ///
@@ -133,7 +153,7 @@ pub type QuaternaryHeapOfIndices<N, K> = DaryHeapOfIndices<N, K, 4>;
 /// test_priority_queue_deckey(QuaternaryHeapOfIndices::with_index_bound(16));
 /// test_priority_queue_deckey(QuaternaryHeapOfIndices::with_index_bound(16));
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub struct DaryHeapOfIndices<N, K, const D: usize = 2>
 where
     N: HasIndex,
@@ -142,6 +162,41 @@ where
     heap: Heap<N, K, HeapPositionsHasIndex<N>, D>,
 }
 
+impl<N, K, const D: usize> Clone for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+        }
+    }
+
+    /// Clones the elements of `source` into this heap reusing the allocations of the tree and the positions array.
+    ///
+    /// Note that this heap takes over the index bound of the `source`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut source = QuaternaryHeapOfIndices::with_index_bound(8);
+    /// source.push(3usize, 30);
+    /// source.push(5, 10);
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(16);
+    /// queue.clone_from(&source);
+    ///
+    /// assert!(queue == source);
+    /// assert_eq!(8, queue.index_bound());
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        self.heap.clone_from(&source.heap);
+    }
+}
+
 impl<N, K, const D: usize> DaryHeapOfIndices<N, K, D>
 where
     N: HasIndex,
@@ -176,8 +231,38 @@ where
     /// // pq.push(16, 7.0);
     /// ```
     pub fn with_index_bound(index_bound: usize) -> Self {
+        Self::with_index_bound_and_capacity(index_bound, 0)
+    }
+
+    /// Creates a new d-ary heap with the given `index_bound` as explained in [`with_index_bound`];
+    /// and with the given initial `capacity` on the number of nodes to simultaneously exist on the heap.
+    ///
+    /// This is useful when the number of nodes simultaneously on the heap is expected to be much smaller
+    /// than the index bound, avoiding reallocations of the tree while the heap is being filled.
+    ///
+    /// [`with_index_bound`]: Self::with_index_bound
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut pq = QuaternaryHeapOfIndices::with_index_bound_and_capacity(1000, 16);
+    ///
+    /// assert_eq!(1000, pq.index_bound());
+    /// assert!(pq.capacity() >= 16);
+    ///
+    /// pq.push(999usize, 42.0);
+    /// pq.push(7, 3.0);
+    /// assert_eq!(Some((7, 3.0)), pq.pop());
+    /// ```
+    pub fn with_index_bound_and_capacity(index_bound: usize, capacity: usize) -> Self {
+        let capacity = (capacity > 0).then_some(capacity);
         Self {
-            heap: Heap::new(None, HeapPositionsHasIndex::with_index_bound(index_bound)),
+            heap: Heap::new(
+                capacity,
+                HeapPositionsHasIndex::with_index_bound(index_bound),
+            ),
         }
     }
 
@@ -190,6 +275,90 @@ where
         self.heap.positions().index_bound()
     }
 
+    /// Grows the index bound of the heap to `new_bound`, allowing nodes with indices in [0, 1, ..., `new_bound`)
+    /// to enter the queue.
+    ///
+    /// Elements already in the queue and their positions are not affected.
+    /// The method does nothing if `new_bound` is less than or equal to the current `index_bound`;
+    /// i.e., the index bound never shrinks, see [`shrink_index_bound`] instead.
+    ///
+    /// [`shrink_index_bound`]: Self::shrink_index_bound
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(3usize, 42);
+    /// assert!(queue.try_push(7, 7).is_err());
+    ///
+    /// queue.grow_index_bound(8);
+    /// assert_eq!(8, queue.index_bound());
+    /// queue.push(7, 7);
+    ///
+    /// queue.grow_index_bound(2);
+    /// assert_eq!(8, queue.index_bound());
+    ///
+    /// assert_eq!(Some((7, 7)), queue.pop());
+    /// assert_eq!(Some((3, 42)), queue.pop());
+    /// ```
+    pub fn grow_index_bound(&mut self, new_bound: usize) {
+        self.heap.positions_mut().grow_index_bound(new_bound)
+    }
+
+    /// Shrinks the index bound of the heap to `new_bound`, releasing the memory of the positions of the nodes
+    /// with indices in [`new_bound`, ..., `index_bound`).
+    ///
+    /// This is useful for long-lived queues which are reused for progressively smaller sets of nodes.
+    ///
+    /// All nodes currently in the queue are checked before shrinking, which requires *O(n)* time;
+    /// on success, the elements in the queue and their positions are not affected.
+    /// The method does nothing if `new_bound` is greater than or equal to the current `index_bound`;
+    /// i.e., the index bound never grows, see [`grow_index_bound`] instead.
+    ///
+    /// [`grow_index_bound`]: Self::grow_index_bound
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexOutOfBound`] error and leaves the queue unchanged if the index of any node in the queue
+    /// is greater than or equal to `new_bound`;
+    /// the error reports the largest index among the nodes so that `index + 1` is the smallest feasible bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(1000);
+    /// queue.push(3usize, 42);
+    /// queue.push(7, 7);
+    ///
+    /// let error = queue.shrink_index_bound(4);
+    /// assert_eq!(Err(IndexOutOfBound { index: 7, bound: 4 }), error);
+    /// assert_eq!(1000, queue.index_bound());
+    ///
+    /// assert_eq!(Ok(()), queue.shrink_index_bound(8));
+    /// assert_eq!(8, queue.index_bound());
+    /// assert!(queue.try_push(8, 1).is_err());
+    ///
+    /// assert_eq!(Some((7, 7)), queue.pop());
+    /// assert_eq!(Some((3, 42)), queue.pop());
+    /// ```
+    pub fn shrink_index_bound(&mut self, new_bound: usize) -> Result<(), IndexOutOfBound> {
+        let max_index = self.as_slice().iter().map(|(node, _)| node.index()).max();
+        match max_index {
+            Some(index) if index >= new_bound => Err(IndexOutOfBound {
+                index,
+                bound: new_bound,
+            }),
+            _ => {
+                self.heap.positions_mut().shrink_index_bound(new_bound);
+                Ok(())
+            }
+        }
+    }
+
     /// Returns the 'd' of the d-ary heap.
     /// In other words, it represents the maximum number of children that each node on the heap can have.
     pub const fn d() -> usize {
@@ -220,100 +389,1782 @@ where
     pub fn as_slice(&self) -> &[(N, K)] {
         self.heap.as_slice()
     }
-}
-
-impl<N, K, const D: usize> PriorityQueue<N, K> for DaryHeapOfIndices<N, K, D>
-where
-    N: HasIndex,
-    K: PartialOrd + Clone,
-{
-    type NodeKey<'a>
-        = &'a (N, K)
-    where
-        Self: 'a,
-        N: 'a,
-        K: 'a;
-    type Iter<'a>
-        = core::slice::Iter<'a, (N, K)>
-    where
-        Self: 'a,
-        N: 'a,
-        K: 'a;
-
-    #[inline(always)]
-    fn len(&self) -> usize {
-        self.heap.len()
-    }
 
-    #[inline(always)]
-    fn capacity(&self) -> usize {
-        self.heap.capacity()
+    /// Returns an iterator visiting the nodes and keys currently in the queue in the reverse order of `as_slice`.
+    ///
+    /// Note that the order is still arbitrary, this is only the reverse traversal of the internal tree;
+    /// in particular, the elements are not yielded in descending order of their keys.
+    /// The iterator is double-ended; `iter_rev().rev()` is equivalent to `iter()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(3);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// let reversed: Vec<_> = queue.iter_rev().collect();
+    /// let mut forward: Vec<_> = queue.iter().collect();
+    /// forward.reverse();
+    /// assert_eq!(forward, reversed);
+    /// ```
+    pub fn iter_rev(&self) -> Rev<Iter<'_, (N, K)>> {
+        self.as_slice().iter().rev()
     }
 
-    fn peek(&self) -> Option<&(N, K)> {
-        self.heap.peek()
+    /// Returns the number of levels of the tree of the heap, which is 0 for an empty heap and 1 for a heap
+    /// with a single element; each level holds `D` times the elements of its parent level.
+    ///
+    /// This is useful for comparing the heights of the heaps with different arities on a particular workload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(16);
+    /// assert_eq!(0, queue.depth());
+    ///
+    /// queue.push(0usize, 42);
+    /// assert_eq!(1, queue.depth());
+    ///
+    /// // levels of sizes 1 and 4
+    /// for node in 1..5 {
+    ///     queue.push(node, 42);
+    /// }
+    /// assert_eq!(2, queue.depth());
+    ///
+    /// queue.push(5, 42);
+    /// assert_eq!(3, queue.depth());
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.heap.depth()
     }
 
-    fn clear(&mut self) {
-        self.heap.clear()
+    /// Validates the heap; i.e., checks whether or not:
+    /// * the key of every element is greater than or equal to the key of its parent on the tree, and
+    /// * the positions of the nodes on the tree are tracked correctly.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    ///
+    /// Since the heap maintains its invariants on its own, this method is mainly useful for debugging and testing.
+    /// It requires *O(n)* time where n is the number of elements on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
     }
 
-    #[inline(always)]
-    fn pop(&mut self) -> Option<(N, K)> {
-        self.heap.pop()
+    /// Returns the position of the `node` in the slice of elements of the heap returned by [`as_slice`];
+    /// returns None if the node is not in the queue.
+    /// Note that this is also the case for nodes with indices greater than or equal to the `index_bound`.
+    ///
+    /// Note that positions of the nodes change as elements are pushed to or popped from the queue,
+    /// or as their keys are updated.
+    ///
+    /// [`as_slice`]: Self::as_slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// let position = queue.position_of(&0).unwrap();
+    /// assert_eq!(&(0, 42), &queue.as_slice()[position]);
+    ///
+    /// assert_eq!(Some(0), queue.position_of(&1));
+    /// assert_eq!(None, queue.position_of(&3));
+    /// assert_eq!(None, queue.position_of(&42));
+    /// ```
+    pub fn position_of(&self, node: &N) -> Option<usize> {
+        if node.index() < self.index_bound() {
+            self.heap.position_of(node)
+        } else {
+            None
+        }
     }
 
-    #[inline(always)]
-    fn pop_node(&mut self) -> Option<N> {
-        self.heap.pop_node()
+    /// Returns the level of the `node` on the tree of the heap where the root is at level 0;
+    /// returns None if the node is not in the queue.
+    ///
+    /// Note that the level is always less than the [`depth`](Self::depth) of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(16);
+    /// for node in 0..6usize {
+    ///     queue.push(node, node);
+    /// }
+    ///
+    /// assert_eq!(Some(0), queue.level_of(&0));
+    /// assert_eq!(Some(1), queue.level_of(&3));
+    /// assert_eq!(Some(2), queue.level_of(&5));
+    /// assert_eq!(None, queue.level_of(&10));
+    /// ```
+    pub fn level_of(&self, node: &N) -> Option<usize> {
+        self.position_of(node).map(level_of::<D>)
     }
 
-    #[inline(always)]
-    fn pop_key(&mut self) -> Option<K> {
-        self.heap.pop_key()
+    /// Returns the [`Entry`] of the given `node` in the queue, which is either occupied or vacant,
+    /// for in-place manipulation.
+    ///
+    /// The position of the node is looked up only once; hence, the entry allows to combine operations such as
+    /// decreasing the key of a node if it is in the queue and pushing it otherwise, without an additional lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    ///
+    /// for (node, key) in [(0, 7), (1, 21)] {
+    ///     match queue.entry(node) {
+    ///         Entry::Occupied(entry) if &key < entry.key() => entry.decrease_key(key),
+    ///         Entry::Occupied(_) => {}
+    ///         Entry::Vacant(entry) => entry.push(key),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some((0, 7)), queue.pop());
+    /// assert_eq!(Some((1, 21)), queue.pop());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn entry(&mut self, node: N) -> Entry<'_, N, K> {
+        self.heap.entry(node)
     }
 
-    #[inline(always)]
-    fn push(&mut self, node: N, key: K) {
-        self.heap.push(node, key)
+    /// Returns a reference to the key of the `node` if it is in the queue;
+    /// otherwise, pushes the `node` with the key computed by `default` and returns a reference to this key.
+    ///
+    /// The position of the node is looked up only once, unlike the `contains` followed by `push` pattern;
+    /// and `default` is called only if the node is absent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index of the node is greater than or equal to the `index_bound` of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    ///
+    /// assert_eq!(&42, queue.get_or_insert_with(0usize, || 7));
+    /// assert_eq!(&21, queue.get_or_insert_with(1, || 21));
+    ///
+    /// assert_eq!(2, queue.len());
+    /// assert_eq!(Some((1, 21)), queue.pop());
+    /// assert_eq!(Some((0, 42)), queue.pop());
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, node: N, default: F) -> &K
+    where
+        F: FnOnce() -> K,
+    {
+        self.heap.get_or_insert_with(node, default)
     }
 
-    #[inline(always)]
-    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
-        self.heap.push_then_pop(node, key)
+    /// Replaces the `old` node with the `new` node keeping its key and its position on the heap.
+    ///
+    /// Since the key is unchanged, the heap property is preserved without any sift;
+    /// unlike removing the `old` node and pushing the `new` node, which might move the element to another position.
+    ///
+    /// Returns [`RenameError::NodeNotFound`] if the `old` node is not in the queue, and
+    /// [`RenameError::NodeAlreadyExists`] if the `new` node is already in the queue; the queue is unchanged in both cases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index of the `new` node is greater than or equal to the `index_bound` of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    ///
+    /// assert_eq!(Ok(()), queue.rename(&0, 2));
+    /// assert!(!queue.contains(&0));
+    /// assert_eq!(Some(42), queue.key_of(&2));
+    ///
+    /// assert_eq!(Err(RenameError::NodeNotFound), queue.rename(&0, 0));
+    /// assert_eq!(Err(RenameError::NodeAlreadyExists), queue.rename(&1, 2));
+    /// ```
+    pub fn rename(&mut self, old: &N, new: N) -> Result<(), RenameError> {
+        self.heap.rename(old, new)
     }
 
-    fn iter(&self) -> Self::Iter<'_> {
-        self.as_slice().iter()
-    }
-}
-impl<N, K, const D: usize> PriorityQueueDecKey<N, K> for DaryHeapOfIndices<N, K, D>
-where
-    N: HasIndex,
-    K: PartialOrd + Clone,
-{
-    #[inline(always)]
-    fn contains(&self, node: &N) -> bool {
-        self.heap.contains(node)
+    /// Decreases the keys of the nodes in bulk, where the new keys are given as a dense slice indexed by the
+    /// indices of the nodes: `new_keys[i]` is the decreased key of the node with index `i` if it is `Some`,
+    /// and the key of the node is unchanged if it is `None`.
+    ///
+    /// This fits graph algorithms which compute an entire relaxation pass into an array and then apply it at once.
+    /// When the number of updates is large relative to the length of the queue, the keys are set in place and the
+    /// heap property is restored by a single bottom-up heapify in linear time; otherwise, each update is applied
+    /// as in `decrease_key`.
+    /// The slice may be shorter than the `index_bound`; nodes with indices beyond its length are not updated.
+    ///
+    /// # Panics
+    ///
+    /// Absent nodes are not skipped; matching `decrease_key_all`, this method panics:
+    /// * if a `Some` key is given for a node which is not in the queue, or for an index which is greater than
+    ///   or equal to the `index_bound`; or
+    /// * if a `Some` key is strictly greater than the key of the corresponding node in the queue.
+    ///
+    /// The queue is left valid when the method panics; updates preceding the invalid one are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(5);
+    /// queue.push(0usize, 10);
+    /// queue.push(1, 20);
+    /// queue.push(3, 30);
+    ///
+    /// queue.decrease_key_dense(&[None, Some(5), None, Some(1)]);
+    ///
+    /// assert_eq!(Some((3, 1)), queue.pop());
+    /// assert_eq!(Some((1, 5)), queue.pop());
+    /// assert_eq!(Some((0, 10)), queue.pop());
+    /// ```
+    pub fn decrease_key_dense(&mut self, new_keys: &[Option<K>]) {
+        self.heap.decrease_key_dense(new_keys)
     }
 
-    #[inline(always)]
-    fn key_of(&self, node: &N) -> Option<K> {
-        self.heap.key_of(node)
+    /// Pushes the given (`node`, `key`) pair to the queue provided that the index of the node is
+    /// strictly less than the `index_bound` of the heap.
+    ///
+    /// Returns an [`IndexOutOfBound`] error carrying the index of the node and the bound otherwise,
+    /// in which case the queue is not modified.
+    /// This is the non-panicking counterpart of `push` which is convenient when the nodes are
+    /// coming from an untrusted source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    ///
+    /// assert_eq!(Ok(()), queue.try_push(3usize, 42));
+    /// assert_eq!(
+    ///     Err(IndexOutOfBound { index: 4, bound: 4 }),
+    ///     queue.try_push(4, 7)
+    /// );
+    ///
+    /// assert_eq!(1, queue.len());
+    /// assert_eq!(Some((3, 42)), queue.pop());
+    /// ```
+    pub fn try_push(&mut self, node: N, key: K) -> Result<(), IndexOutOfBound> {
+        let (index, bound) = (node.index(), self.index_bound());
+        if index < bound {
+            self.heap.push(node, key);
+            Ok(())
+        } else {
+            Err(IndexOutOfBound { index, bound })
+        }
     }
 
-    #[inline(always)]
-    fn decrease_key(&mut self, node: &N, decreased_key: K) {
-        self.heap.decrease_key(node, decreased_key)
+    /// Reserves capacity for at least `additional` more elements to be pushed to the heap.
+    /// The heap may reserve more space to speculatively avoid frequent reallocations.
+    ///
+    /// Note that this does not affect the `index_bound` of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::<usize, i32>::with_index_bound(100);
+    /// queue.reserve(100);
+    /// assert!(queue.capacity() >= 100);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.heap.reserve(additional)
     }
 
-    #[inline(always)]
-    fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
-        self.heap.update_key(node, new_key)
+    /// Reserves the minimum capacity for at least `additional` more elements to be pushed to the heap.
+    /// Unlike `reserve`, this will not deliberately over-allocate to speculatively avoid frequent allocations.
+    ///
+    /// Note that this does not affect the `index_bound` of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::<usize, i32>::with_index_bound(100);
+    /// queue.reserve_exact(100);
+    /// assert!(queue.capacity() >= 100);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.heap.reserve_exact(additional)
+    }
+
+    /// Returns the capacity of the tree of the heap; i.e., the number of nodes which can simultaneously be
+    /// on the heap without reallocating the tree.
+    ///
+    /// A `DaryHeapOfIndices` has two storage dimensions which are independent of each other:
+    /// * the positions array whose length is fixed to the `index_bound`, which is allocated upfront; and
+    /// * the tree holding the (node, key) pairs currently in the queue, which grows on demand.
+    ///
+    /// This method returns the latter, which is the same as the `capacity` of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = QuaternaryHeapOfIndices::<usize, i32>::with_index_bound(1000);
+    /// assert_eq!(1000, queue.index_bound());
+    /// assert_eq!(0, queue.tree_capacity());
+    ///
+    /// let queue = QuaternaryHeapOfIndices::<usize, i32>::with_index_bound_and_capacity(1000, 16);
+    /// assert_eq!(1000, queue.index_bound());
+    /// assert!(queue.tree_capacity() >= 16);
+    /// ```
+    pub fn tree_capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more elements on the tree of the heap;
+    /// grows only the tree and never touches the positions array, and hence, the `index_bound`.
+    ///
+    /// See [`tree_capacity`](Self::tree_capacity) for the two storage dimensions of the heap;
+    /// use [`grow_index_bound`](Self::grow_index_bound) in order to grow the positions array instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::<usize, i32>::with_index_bound(1000);
+    /// queue.reserve_tree(64);
+    ///
+    /// assert!(queue.tree_capacity() >= 64);
+    /// assert_eq!(1000, queue.index_bound());
+    /// ```
+    pub fn reserve_tree(&mut self, additional: usize) {
+        self.heap.reserve(additional)
+    }
+
+    /// Shrinks the capacity of the heap as much as possible.
+    ///
+    /// Note that only the underlying tree of elements is shrunk.
+    /// The positions array has a fixed length of `index_bound` and cannot shrink below it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(1000);
+    /// for node in 0..1000usize {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// while queue.len() > 10 {
+    ///     queue.pop();
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.shrink_to_fit();
+    /// assert!(queue.capacity() < 1000);
+    /// assert!(queue.capacity() >= 10);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.heap.shrink_to_fit()
+    }
+
+    /// Clears the heap and releases its memory by shrinking the capacity as much as possible.
+    ///
+    /// Unlike `clear`, which retains the capacity for reuse, this method is useful when the memory held
+    /// by a queue which has once been large is to be released without creating a new queue.
+    ///
+    /// Note that the positions array has a fixed length of `index_bound` and is not released;
+    /// positions of all nodes are reset so that the queue can be reused with the same `index_bound`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(1000);
+    /// for node in 0..1000usize {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.clear_and_shrink();
+    /// assert!(queue.is_empty());
+    /// assert_eq!(0, queue.capacity());
+    /// ```
+    pub fn clear_and_shrink(&mut self) {
+        self.heap.clear_and_shrink()
+    }
+
+    /// Clears the heap and, if its capacity exceeds `max_capacity`, shrinks the capacity down to `max_capacity`;
+    /// the capacity is retained otherwise.
+    ///
+    /// This method lies between `clear`, which retains the capacity, and `clear_and_shrink`, which releases it:
+    /// reusing the queue in a long-running loop avoids reallocations in normal operation,
+    /// while the memory held after an occasional burst is bounded by `max_capacity`.
+    ///
+    /// Only the capacity of the tree is affected; the positions array has a fixed length of `index_bound`,
+    /// and positions of all nodes are reset so that the queue can be reused with the same `index_bound`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(1000);
+    /// for node in 0..1000usize {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.clear_to_capacity(100);
+    /// assert!(queue.is_empty());
+    /// assert!(queue.capacity() >= 100 && queue.capacity() < 1000);
+    ///
+    /// for node in 0..10 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// queue.clear_to_capacity(100);
+    /// assert!(queue.is_empty());
+    /// assert!(queue.capacity() >= 100 && queue.capacity() < 1000);
+    /// ```
+    pub fn clear_to_capacity(&mut self, max_capacity: usize) {
+        self.heap.clear_to_capacity(max_capacity)
+    }
+
+    /// Returns a guard providing mutable access to the key of the foremost element of the heap;
+    /// returns None if the heap is empty.
+    ///
+    /// If the key is mutated, the heap is restored once the guard is dropped;
+    /// see [`PeekMut`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// if let Some(mut top) = queue.peek_mut() {
+    ///     assert_eq!(&1, top.node());
+    ///     *top.key_mut() += 43;
+    /// }
+    ///
+    /// assert_eq!(Some(&(0, 42)), queue.peek());
+    /// assert_eq!(Some(50), queue.key_of(&1));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, N, K>> {
+        self.heap.peek_mut()
+    }
+
+    /// Returns a guard providing mutable access to the keys of all elements of the heap;
+    /// the guard is iterated by a mutable reference yielding `(&N, &mut K)` pairs in arbitrary order.
+    ///
+    /// The heap is restored by a single bottom-up heapify once the guard is dropped;
+    /// see [`IterMut`] for details.
+    /// This is much cheaper than draining and rebuilding the heap to apply an update to all keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// for (node, key) in &mut queue.iter_mut() {
+    ///     match node {
+    ///         1 => *key += 100,
+    ///         _ => *key *= 2,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(&(0, 84)), queue.peek());
+    /// assert_eq!(Some(&107), queue.key_ref(&1));
+    /// assert_eq!(Some((0, 84)), queue.pop());
+    /// assert_eq!(Some((1, 107)), queue.pop());
+    /// assert_eq!(Some((2, 198)), queue.pop());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, N, K> {
+        self.heap.iter_mut()
+    }
+
+    /// Restores the heap property by a single bottom-up heapify over all elements and rebuilds the positions of the nodes.
+    ///
+    /// This is the canonical way to fix the heap after its elements are mutated in a way that could have invalidated
+    /// its invariants; for instance, after mutating the keys through the guards returned by [`iter_mut`] or [`peek_mut`].
+    /// These guards already repair the heap on drop; hence, `repair` is mainly an escape hatch rather than a routine step.
+    ///
+    /// It requires *O(n)* time where n is the number of elements on the heap,
+    /// and leaves an already valid heap valid.
+    ///
+    /// [`iter_mut`]: Self::iter_mut
+    /// [`peek_mut`]: Self::peek_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// queue.repair();
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// assert_eq!(Some(0), queue.position_of(&1));
+    /// assert_eq!(Some((1, 7)), queue.pop());
+    /// ```
+    pub fn repair(&mut self) {
+        self.heap.repair()
+    }
+
+    /// Removes the foremost element of the queue and replaces it with the given (`node`, `key`) pair;
+    /// returns the removed element.
+    /// If the queue is empty, the pair is pushed and None is returned.
+    ///
+    /// This is equivalent to, but faster than, a `pop` followed by a `push`,
+    /// since it requires a single heapify-down rather than two heapify operations.
+    /// Unlike `push_then_pop`, the new element always enters the queue, even when its key is less than that of the root.
+    ///
+    /// Note that the `node` must not already be in the queue unless it is the node of the current root,
+    /// just as nodes which are already in the queue must not be pushed again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index of the `node` is greater than or equal to the `index_bound` of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// assert_eq!(None, queue.replace_root(0usize, 42));
+    ///
+    /// queue.push(1, 7);
+    /// assert_eq!(Some((1, 7)), queue.replace_root(2, 99));
+    ///
+    /// assert_eq!(Some((0, 42)), queue.pop());
+    /// assert_eq!(Some((2, 99)), queue.pop());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn replace_root(&mut self, node: N, key: K) -> Option<(N, K)> {
+        self.heap.replace_root(node, key)
+    }
+
+    /// Returns an iterator visiting all (node, key) pairs on the heap in ascending order of keys,
+    /// without mutating or consuming the heap.
+    ///
+    /// The order is the same as the order in which the elements would be popped,
+    /// while elements with equal keys are yielded in an arbitrary order.
+    ///
+    /// Yielding the first `k` elements requires *O(k log k)* time and *O(k)* additional space;
+    /// see [`IterSorted`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    /// queue.push(3, 7);
+    ///
+    /// let keys: Vec<_> = queue.iter_sorted().map(|x| x.1).collect();
+    /// assert_eq!(vec![7, 7, 42, 99], keys);
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(4, queue.len());
+    /// ```
+    pub fn iter_sorted(&self) -> IterSorted<'_, N, K, D> {
+        self.heap.iter_sorted()
+    }
+
+    /// Returns an iterator over the `k` foremost (node, key) pairs of the heap in ascending order of keys,
+    /// without mutating or consuming the heap.
+    ///
+    /// The iterator yields all elements of the heap if the heap has fewer than `k` elements.
+    ///
+    /// The iterator is a [`IterSorted`] limited to `k` elements;
+    /// hence, it requires *O(k log k)* time and *O(k)* additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    /// queue.push(3, 21);
+    ///
+    /// let top2: Vec<_> = queue.peek_k(2).collect();
+    /// assert_eq!(vec![&(1, 7), &(3, 21)], top2);
+    ///
+    /// assert_eq!(4, queue.peek_k(10).count());
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(4, queue.len());
+    /// ```
+    pub fn peek_k(&self, k: usize) -> Take<IterSorted<'_, N, K, D>> {
+        self.iter_sorted().take(k)
+    }
+
+    /// Returns the (node, key) pair which would be the `n`-th element to be popped, counting from zero,
+    /// without mutating the heap; returns None if the heap has `n` or fewer elements.
+    ///
+    /// `peek_nth(0)` is equivalent to `peek`.
+    /// It requires *O(n log n)* time and *O(n)* additional space, where n is the argument rather than the length of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// assert_eq!(Some(&(1, 7)), queue.peek_nth(0));
+    /// assert_eq!(Some(&(0, 42)), queue.peek_nth(1));
+    /// assert_eq!(Some(&(2, 99)), queue.peek_nth(2));
+    /// assert_eq!(None, queue.peek_nth(3));
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&(N, K)> {
+        self.iter_sorted().nth(n)
+    }
+
+    /// Returns the (node, key) pair which would be the second element to be popped without mutating the heap;
+    /// returns None if the heap has fewer than two elements.
+    ///
+    /// Since the second smallest element of a valid heap is one of the children of the root,
+    /// only the up to `D` children of the root are examined; hence, it requires *O(D)* time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// assert_eq!(None, queue.peek_second());
+    ///
+    /// queue.push(0usize, 42);
+    /// assert_eq!(None, queue.peek_second());
+    ///
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    /// queue.push(3, 13);
+    ///
+    /// assert_eq!(Some(&(1, 7)), queue.peek());
+    /// assert_eq!(Some(&(3, 13)), queue.peek_second());
+    /// ```
+    pub fn peek_second(&self) -> Option<&(N, K)> {
+        self.heap.peek_second()
+    }
+
+    /// Returns a vector of clones of the (node, key) pairs of the heap sorted in ascending order of keys,
+    /// without mutating the heap.
+    ///
+    /// Unlike [`as_slice`], the elements are sorted; and unlike [`into_iter_sorted`], the heap is not consumed.
+    /// It requires *O(n log n)* time and clones every element of the heap.
+    /// Elements with equal keys are in an arbitrary order.
+    ///
+    /// [`as_slice`]: Self::as_slice
+    /// [`into_iter_sorted`]: Self::into_iter_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// assert_eq!(vec![(1, 7), (0, 42), (2, 99)], queue.to_sorted_vec());
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(3, queue.len());
+    /// ```
+    pub fn to_sorted_vec(&self) -> Vec<(N, K)> {
+        self.iter_sorted().cloned().collect()
+    }
+
+    /// Consumes the heap and returns an iterator yielding its (node, key) pairs in ascending order of keys.
+    ///
+    /// The iterator is lazy; each call to `next` pops the element with the lowest key.
+    /// Elements with equal keys are yielded in an arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// let sorted: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(vec![(1, 7), (0, 42), (2, 99)], sorted);
+    /// ```
+    pub fn into_iter_sorted(self) -> IntoIterSorted<N, K, D> {
+        IntoIterSorted::new(self.heap.into_heap_without_positions())
+    }
+
+    /// Consumes the heap and returns its `k` foremost (node, key) pairs in ascending order of keys;
+    /// the remaining elements are dropped.
+    ///
+    /// All elements of the heap are returned in sorted order if the heap has fewer than `k` elements.
+    ///
+    /// Elements are popped one by one and popping stops after the `k`-th element;
+    /// hence, the method requires *O(k log n)* time rather than sorting all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    /// queue.push(3, 21);
+    ///
+    /// let top2 = queue.k_smallest(2);
+    /// assert_eq!(vec![(1, 7), (3, 21)], top2);
+    /// ```
+    pub fn k_smallest(self, k: usize) -> Vec<(N, K)> {
+        let mut smallest = Vec::with_capacity(k.min(self.len()));
+        smallest.extend(self.into_iter_sorted().take(k));
+        smallest
+    }
+
+    /// Consumes the heap and returns all of its (node, key) pairs in ascending order of keys.
+    ///
+    /// Elements are popped one by one; hence, the method requires *O(n log n)* time.
+    /// Elements with equal keys are returned in an arbitrary order;
+    /// [`into_sorted_vec_by`] can be used to break the ties deterministically.
+    ///
+    /// [`into_sorted_vec_by`]: Self::into_sorted_vec_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(3);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// assert_eq!(vec![(1, 7), (0, 42), (2, 99)], queue.into_sorted_vec());
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<(N, K)> {
+        let mut sorted = Vec::with_capacity(self.len());
+        sorted.extend(self.into_iter_sorted());
+        sorted
+    }
+
+    /// Consumes the heap and returns all of its (node, key) pairs sorted by the comparator `cmp`,
+    /// which is called with pairs of (node, key) pairs.
+    ///
+    /// The elements are taken out of the heap without popping and sorted once with `cmp`, in the manner of
+    /// [`slice::sort_unstable_by`].
+    /// The comparator is expected to be consistent with the order of the keys, such as comparing the keys and
+    /// breaking the ties by the nodes, for the result to be the fully sorted sequence of the heap;
+    /// otherwise, the result is simply the elements of the heap sorted by `cmp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(3);
+    /// queue.push(2usize, 7);
+    /// queue.push(0, 42);
+    /// queue.push(1, 7);
+    ///
+    /// // keys collide; ties are broken by the nodes
+    /// let sorted = queue.into_sorted_vec_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+    /// assert_eq!(vec![(1, 7), (2, 7), (0, 42)], sorted);
+    /// ```
+    pub fn into_sorted_vec_by<F>(self, cmp: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&(N, K), &(N, K)) -> Ordering,
+    {
+        let mut elements = self.heap.into_elements();
+        elements.sort_unstable_by(cmp);
+        elements
+    }
+
+    /// Consumes the heap and returns a [`DaryHeap`] of the same nodes where each key is mapped by `f`.
+    /// Since the mapped keys are not tracked by node, the result is a [`DaryHeap`] which does not track positions of the nodes.
+    ///
+    /// The mapped heap is restored by a single bottom-up heapify; hence, `f` is not required to preserve the order of the keys
+    /// and the method requires *O(n)* time where n is the number of elements on the heap.
+    /// When `f` is known to be monotonically non-decreasing, [`map_keys_monotonic`] avoids the heapify.
+    ///
+    /// [`map_keys_monotonic`]: Self::map_keys_monotonic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    /// queue.push(2, 2);
+    ///
+    /// // reverses the order of the keys
+    /// let mut mapped = queue.map_keys(|key| 10 - key);
+    ///
+    /// assert_eq!(Ok(()), mapped.validate());
+    /// assert_eq!(Some((0, 7)), mapped.pop());
+    /// assert_eq!(Some((2, 8)), mapped.pop());
+    /// assert_eq!(Some((1, 9)), mapped.pop());
+    /// ```
+    pub fn map_keys<K2, F>(self, f: F) -> DaryHeap<N, K2, D>
+    where
+        K2: PartialOrd + Clone,
+        F: FnMut(K) -> K2,
+    {
+        DaryHeap::from_heap(self.heap.map_keys(f, false))
+    }
+
+    /// Consumes the heap and returns a [`DaryHeap`] of the same nodes where each key is mapped by the monotonic function `f`.
+    /// Since the mapped keys are not tracked by node, the result is a [`DaryHeap`] which does not track positions of the nodes.
+    ///
+    /// Since the order of the keys is preserved, the positions of the elements are kept as they are without a heapify.
+    /// This is useful for unit conversions on the keys such as converting seconds to milliseconds.
+    ///
+    /// `f` must be monotonically non-decreasing; i.e., `a <= b` must imply `f(a) <= f(b)` for all keys on the heap.
+    /// Otherwise, the returned heap is invalid and the order of the elements popped from it is unspecified;
+    /// [`map_keys`] must be used instead if `f` does not satisfy this requirement.
+    ///
+    /// [`map_keys`]: Self::map_keys
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    /// queue.push(2, 2);
+    ///
+    /// // seconds to milliseconds
+    /// let mut mapped = queue.map_keys_monotonic(|seconds| seconds as f64 * 1000.0);
+    ///
+    /// assert_eq!(Ok(()), mapped.validate());
+    /// assert_eq!(Some((1, 1000.0)), mapped.pop());
+    /// assert_eq!(Some((2, 2000.0)), mapped.pop());
+    /// assert_eq!(Some((0, 3000.0)), mapped.pop());
+    /// ```
+    pub fn map_keys_monotonic<K2, F>(self, f: F) -> DaryHeap<N, K2, D>
+    where
+        K2: PartialOrd + Clone,
+        F: FnMut(K) -> K2,
+    {
+        DaryHeap::from_heap(self.heap.map_keys(f, true))
+    }
+
+    /// Consumes the heap and converts it into a [`DaryHeapWithMap`],
+    /// which tracks the positions of the nodes in a map rather than in a fixed size array.
+    ///
+    /// The elements are moved to the new heap at the same positions; hence, no heapify is required
+    /// and the conversion requires *O(n)* time to build the positions map.
+    ///
+    /// The conversion always succeeds, and the resulting heap no longer limits the nodes by an index bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(3usize, 42);
+    /// queue.push(1, 7);
+    ///
+    /// let mut queue = queue.into_map();
+    /// queue.push(100, 21);
+    /// assert_eq!(Some(&42), queue.key_ref(&3));
+    ///
+    /// assert_eq!(Some((1, 7)), queue.pop());
+    /// assert_eq!(Some((100, 21)), queue.pop());
+    /// assert_eq!(Some((3, 42)), queue.pop());
+    /// ```
+    pub fn into_map(self) -> DaryHeapWithMap<N, K, D>
+    where
+        N: Index,
+    {
+        let positions = HeapPositionsMap::with_capacity(self.len());
+        DaryHeapWithMap::from_heap(self.heap.into_heap_with_positions(positions))
+    }
+
+    /// Deconstructs the heap into the vector of its tree and the positions of its nodes,
+    /// which can be used to recreate the heap by [`from_parts`](Self::from_parts) without copying the elements.
+    ///
+    /// The returned tree is the vector backing the heap, exactly as it is stored:
+    /// * it is empty if no element has been pushed to the heap since it is created, and otherwise,
+    /// * its first `offset` elements are padding which keep the children of the nodes aligned,
+    ///   where `offset` is [`heap_offset::<D>()`](crate::heap_offset); i.e., `D - 1` when `D` is a power of two not greater than 64, and 0 otherwise;
+    ///   the padding elements are copies of an arbitrary element and not among the elements of the heap,
+    /// * the remaining elements are the elements of the heap, equal to [`as_slice`](Self::as_slice),
+    ///   where the children of the element at position `offset + i` are at positions `offset + D * i + 1` to `offset + D * i + D`.
+    ///
+    /// The positions hold the position of each node on the heap in the returned tree, including the offset,
+    /// in an array of length [`index_bound`](Self::index_bound).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = QuaternaryHeapOfIndices::from([(0usize, 3), (1, 1), (2, 2)]);
+    /// let slice = queue.as_slice().to_vec();
+    ///
+    /// let (tree, positions) = queue.into_parts();
+    /// assert_eq!(3 + 3, tree.len());
+    /// assert_eq!(slice.as_slice(), &tree[3..]);
+    ///
+    /// let mut queue = unsafe { QuaternaryHeapOfIndices::from_parts(tree, positions) };
+    /// assert_eq!(3, queue.index_bound());
+    /// assert_eq!(Some(&2), queue.key_ref(&2));
+    /// assert_eq!(Some((1, 1)), queue.pop());
+    /// ```
+    pub fn into_parts(self) -> (Vec<(N, K)>, HeapPositionsHasIndex<N>) {
+        self.heap.into_parts()
+    }
+
+    /// Creates the heap from the vector of its tree and the positions of its nodes as returned by [`into_parts`](Self::into_parts),
+    /// without copying or heapifying the elements.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the `tree` and the `positions` satisfy the invariants of the heap, which are
+    /// * the layout of the tree described in [`into_parts`](Self::into_parts),
+    /// * the heap property that the key of every element is not less than the key of its parent, and
+    /// * the positions hold exactly the nodes on the heap each at its position in the `tree`, including the offset.
+    ///
+    /// These are always satisfied by the parts returned by `into_parts` as long as no element is inserted, removed
+    /// or moved, and no key is changed in a way that breaks the heap property.
+    ///
+    /// The invariants are validated only in debug builds;
+    /// when they are violated, the heap may return elements out of order, fail to find its nodes or panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = QuaternaryHeapOfIndices::from([(0usize, 3), (1, 1), (2, 2)]);
+    ///
+    /// let (mut tree, positions) = queue.into_parts();
+    ///
+    /// // increasing all keys preserves the heap property
+    /// tree.iter_mut().for_each(|(_, key)| *key += 10);
+    ///
+    /// let mut queue = unsafe { QuaternaryHeapOfIndices::from_parts(tree, positions) };
+    /// assert_eq!(Ok(()), queue.validate());
+    /// assert_eq!(Some(&12), queue.key_ref(&2));
+    /// assert_eq!(Some((1, 11)), queue.pop());
+    /// ```
+    pub unsafe fn from_parts(tree: Vec<(N, K)>, positions: HeapPositionsHasIndex<N>) -> Self {
+        Self::from_heap(Heap::from_parts(tree, positions))
+    }
+
+    pub(crate) fn from_heap(heap: Heap<N, K, HeapPositionsHasIndex<N>, D>) -> Self {
+        Self { heap }
+    }
+
+    /// Creates a queue of the given elements with the index bound of one more than the largest index among the nodes,
+    /// keeping the smallest key of the nodes appearing more than once.
+    fn from_elements<E>(elements: E) -> Self
+    where
+        E: AsRef<[(N, K)]> + IntoIterator<Item = (N, K)>,
+    {
+        let slice = elements.as_ref();
+        let index_bound = slice
+            .iter()
+            .map(|(node, _)| node.index() + 1)
+            .max()
+            .unwrap_or(0);
+        let mut queue = Self::with_index_bound_and_capacity(index_bound, slice.len());
+        queue.heap.extend_keep_min(elements);
+        queue
+    }
+
+    /// Pops up to `n` elements from the queue and returns them in ascending order of keys;
+    /// stops early if the queue becomes empty.
+    ///
+    /// The elements are popped one by one and come out sorted;
+    /// hence, the method requires *O(n log m)* time where m is the length of the queue, without any additional sorting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    /// queue.push(3, 21);
+    ///
+    /// let batch = queue.pop_n(2);
+    /// assert_eq!(vec![(1, 7), (3, 21)], batch);
+    /// assert!(!queue.contains(&1));
+    ///
+    /// let batch = queue.pop_n(16);
+    /// assert_eq!(vec![(0, 42), (2, 99)], batch);
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn pop_n(&mut self, n: usize) -> Vec<(N, K)> {
+        self.heap.pop_n(n)
+    }
+
+    /// Pops the element with the minimum key together with all elements whose keys are equal to it;
+    /// returns an empty vector if the queue is empty.
+    ///
+    /// Popping stops at the first element whose key is not equal to the minimum key.
+    /// The order of the elements within the returned group is unspecified.
+    ///
+    /// Keys are compared by `PartialEq`; hence, float keys are grouped only if they are exactly equal,
+    /// keys which differ by a rounding error fall into different groups and a NaN key is never grouped with another key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(3);
+    /// queue.push(0usize, 7);
+    /// queue.push(1, 3);
+    /// queue.push(2, 3);
+    ///
+    /// let mut group = queue.pop_min_group();
+    /// group.sort();
+    /// assert_eq!(vec![(1, 3), (2, 3)], group);
+    /// assert!(!queue.contains(&1));
+    ///
+    /// assert_eq!(vec![(0, 7)], queue.pop_min_group());
+    /// assert!(queue.is_empty());
+    /// assert!(queue.pop_min_group().is_empty());
+    /// ```
+    pub fn pop_min_group(&mut self) -> Vec<(N, K)> {
+        self.heap.pop_min_group()
+    }
+
+    /// Repeatedly pops the foremost element while it satisfies the predicate `f`,
+    /// and returns the popped elements in ascending order of their keys.
+    ///
+    /// The predicate is evaluated on the foremost element before each pop; popping stops at the first element
+    /// for which `f(node, key)` returns false, which is left on the queue.
+    /// This is the drain pattern of schedulers and timer wheels, such as popping all tasks whose deadlines have passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(1usize, 12);
+    /// queue.push(0, 3);
+    /// queue.push(2, 10);
+    /// queue.push(3, 42);
+    ///
+    /// let now = 10;
+    /// let expired = queue.pop_while(|_, deadline| *deadline <= now);
+    /// assert_eq!(expired, [(0, 3), (2, 10)]);
+    /// assert!(!queue.contains(&0));
+    /// assert_eq!(Some(&(1, 12)), queue.peek());
+    /// ```
+    pub fn pop_while<F>(&mut self, f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.pop_while(f)
+    }
+
+    /// Returns an iterator which pops the (node, key) pairs of the heap in ascending order of keys.
+    ///
+    /// The iterator is lazy; each call to `next` pops the element with the lowest key.
+    /// Once the iterator is dropped, the heap is empty even if the iterator is not fully consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// let mut drain = queue.drain_sorted();
+    /// assert_eq!(Some((1, 7)), drain.next());
+    /// drop(drain);
+    ///
+    /// assert!(queue.is_empty());
+    /// assert!(!queue.contains(&0));
+    /// ```
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, N, K, D> {
+        self.heap.drain_sorted()
+    }
+
+    /// Returns an iterator which removes all (node, key) pairs of the heap in an arbitrary order.
+    ///
+    /// Unlike `drain_sorted`, the elements are yielded in the order of the underlying tree without any sift cost;
+    /// hence, draining all elements requires linear time rather than the O(n log n) time of repeated `pop` calls.
+    /// This is useful when the elements are to be consumed regardless of their order, such as to reclaim owned nodes.
+    /// Once the iterator is dropped, the heap is empty even if the iterator is not fully consumed.
+    ///
+    /// Positions of the nodes are reset; i.e., `contains` returns false for all drained nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(3);
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// let mut drained: Vec<_> = queue.drain().collect();
+    /// drained.sort();
+    /// assert_eq!(3, drained.len());
+    ///
+    /// assert!(queue.is_empty());
+    /// assert!(!queue.contains(&0));
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, N, K> {
+        self.heap.drain()
+    }
+
+    /// Pushes all (node, key) pairs of the `items` to the queue.
+    ///
+    /// Rather than heapifying each element on insertion, the elements are first appended to the end of the tree.
+    /// Then, the heap property is restored depending on the size of the batch relative to the current length of the queue:
+    /// * if the batch contains at least as many elements as the queue held before the call,
+    ///   the entire tree is rebuilt with a single bottom-up heapify in *O(n)* time;
+    /// * otherwise, each appended element is sifted up, which is equivalent to pushing the elements one by one.
+    ///
+    /// Positions of all inserted nodes are tracked as in `push`.
+    /// Since the nodes of the queue form a set, each node must be absent from the queue and appear at most once in the `items`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 3);
+    ///
+    /// queue.push_all([(1, 1), (2, 4), (3, 0)]);
+    /// assert_eq!(4, queue.len());
+    /// assert_eq!(Some(&4), queue.key_ref(&2));
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(3, 0), (1, 1), (0, 3), (2, 4)]);
+    /// ```
+    pub fn push_all<I>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        self.heap.extend(items)
+    }
+
+    /// Moves all elements of `other` into this heap, leaving `other` empty.
+    ///
+    /// Elements are moved without being heapified one by one;
+    /// the heap property is then restored once on the combined tree,
+    /// which is cheaper than popping from `other` and pushing to this heap.
+    /// Allocation of `other` is retained for reuse.
+    ///
+    /// Since a node can exist at most once in the queue,
+    /// a node which is in both heaps is kept with the smaller of its two keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` contains a node with an index greater than or equal to the `index_bound` of this heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    ///
+    /// let mut other = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// other.push(0, 0);
+    /// other.push(1, 2);
+    /// other.push(2, 4);
+    ///
+    /// queue.append(&mut other);
+    /// assert!(other.is_empty());
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 1), (2, 4)]);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.heap.append(&mut other.heap)
+    }
+
+    /// Moves all elements of `other` into this heap, consuming `other`.
+    ///
+    /// Unlike `append`, `other` is taken by value; and hence, its allocation is not retained.
+    ///
+    /// Since a node can exist at most once in the queue,
+    /// a node which is in both heaps is kept with the smaller of its two keys.
+    ///
+    /// # Complexity
+    ///
+    /// Being valid heaps, both trees are already partially ordered, which is meant to be exploited by a
+    /// specialized merge in the future.
+    /// Currently, elements of `other` are moved to the end of the tree and the heap property is restored once
+    /// as in `append`; this requires O(n + m) time when `other` is at least as large as this heap, where n and m
+    /// are the lengths of this heap and `other`, respectively, and O(m log(n + m)) time otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` contains a node with an index greater than or equal to the `index_bound` of this heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    ///
+    /// let mut other = QuaternaryHeapOfIndices::with_index_bound(4);
+    /// other.push(0, 0);
+    /// other.push(1, 2);
+    /// other.push(2, 4);
+    ///
+    /// queue.append_sorted(other);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 1), (2, 4)]);
+    /// ```
+    pub fn append_sorted(&mut self, other: Self) {
+        self.heap.append_sorted(other.heap)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, removes all (node, key) pairs for which `f(&node, &key)` returns false.
+    /// The heap property is restored once after all removals, which requires linear time.
+    /// No heapify is required and the heap is not modified if all elements are retained.
+    /// For heaps with positions, positions of the remaining nodes are updated accordingly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(10);
+    /// (0..10usize).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// queue.retain(|node, _| node % 3 == 0);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (3, 30), (6, 60), (9, 90)]);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.retain(f)
+    }
+
+    /// Retains only the elements specified by the predicate, which may also mutate the keys of the elements.
+    ///
+    /// In other words, removes all (node, key) pairs for which `f(&node, &mut key)` returns false;
+    /// and the keys of the retained elements are updated as mutated by `f`.
+    /// This allows to both evict elements and adjust the keys of the survivors in a single pass,
+    /// such as decaying priorities while dropping the expired ones.
+    ///
+    /// The heap property is restored once after all removals and mutations by a bottom-up heapify;
+    /// hence, the method requires *O(n)* time.
+    /// Unlike [`retain`], the heap is heapified even if all elements are retained, since their keys might have changed.
+    ///
+    /// [`retain`]: Self::retain
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(10);
+    /// (0..10usize).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// // drops the odd nodes, and reverses the order of the keys of the others
+    /// queue.retain_mut(|node, key| {
+    ///     *key = 100 - *key;
+    ///     node % 2 == 0
+    /// });
+    ///
+    /// assert!(!queue.contains(&3));
+    /// assert_eq!(Some(&60), queue.key_ref(&4));
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(8, 20), (6, 40), (4, 60), (2, 80), (0, 100)]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&N, &mut K) -> bool,
+    {
+        self.heap.retain_mut(f)
+    }
+
+    /// Removes all elements for which `f(&node, &key)` returns true from the queue, and returns them.
+    ///
+    /// The order of the returned (node, key) pairs is unspecified.
+    /// Unlike [`retain`], the removed elements are returned, which allows to partition the queue into kept and evicted elements in one pass;
+    /// hence, it corresponds to the `drain_filter` (`extract_if`) operation of the standard collections.
+    ///
+    /// [`retain`]: Self::retain
+    ///
+    /// The heap property is restored once after all removals;
+    /// hence, the method requires linear time regardless of the number of removed elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapOfIndices::with_index_bound(10);
+    /// (0..10usize).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// let mut removed = queue.remove_if(|_, key| *key >= 50);
+    /// removed.sort();
+    /// assert_eq!(removed, [(5, 50), (6, 60), (7, 70), (8, 80), (9, 90)]);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+    /// ```
+    #[doc(alias = "drain_filter")]
+    #[doc(alias = "extract_if")]
+    pub fn remove_if<F>(&mut self, f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.remove_if(f)
+    }
+
+    /// Splits the queue at the given `threshold`: removes all elements with keys greater than or equal to the
+    /// `threshold` into a new queue which is returned, leaving the elements with smaller keys in this queue.
+    ///
+    /// The tree is scanned once to partition the elements, and the heap property of each of the two queues
+    /// is restored by a single bottom-up heapify; hence, the method requires linear time.
+    /// Elements whose keys are not comparable to the `threshold`, such as NaN keys, remain in this queue.
+    ///
+    /// The returned queue has the same `index_bound` as this queue;
+    /// and the positions of the nodes of both queues are rebuilt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut urgent = QuaternaryHeapOfIndices::with_index_bound(10);
+    /// (0..10usize).for_each(|x| urgent.push(x, x * 10));
+    ///
+    /// let later = urgent.split_off_ge(50);
+    /// assert_eq!(10, later.index_bound());
+    /// assert!(!urgent.contains(&7));
+    /// assert!(later.contains(&7));
+    ///
+    /// let popped: Vec<_> = urgent.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+    ///
+    /// let popped: Vec<_> = later.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(5, 50), (6, 60), (7, 70), (8, 80), (9, 90)]);
+    /// ```
+    pub fn split_off_ge(&mut self, threshold: K) -> Self {
+        let positions = HeapPositionsHasIndex::with_index_bound(self.index_bound());
+        Self {
+            heap: self.heap.split_off_ge(&threshold, positions),
+        }
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueue<N, K> for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a>
+        = &'a (N, K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = core::slice::Iter<'a, (N, K)>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.heap.peek()
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop()
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node()
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key()
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        self.heap.push(node, key)
+    }
+
+    #[inline(always)]
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        self.heap.push_then_pop(node, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
+
+/// Two heaps are equal if they contain the same (node, key) pairs, regardless of the layouts of their trees;
+/// i.e., if they would pop identical sequences up to the order of equal keys.
+///
+/// Since the nodes of each queue form a set, the comparison looks up the position of each node of one heap on the other
+/// and requires *O(n)* time.
+impl<N, K, const D: usize> PartialEq for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let other_slice = other.as_slice();
+        self.len() == other.len()
+            && self.as_slice().iter().all(|(node, key)| {
+                other
+                    .position_of(node)
+                    .is_some_and(|position| &other_slice[position].1 == key)
+            })
+    }
+}
+
+impl<N, K, const D: usize> Eq for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone + Eq,
+{
+}
+
+/// Hashes the heap as the multiset of its (node, key) pairs, consistently with `PartialEq`;
+/// the element hashes are sorted to be independent of the layout of the tree, which requires *O(n log n)* time.
+///
+/// Similar to the equality, the index bound of the heap does not affect the hash.
+impl<N, K, const D: usize> Hash for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex + Hash,
+    K: PartialOrd + Clone + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.heap.hash_elements(state)
+    }
+}
+
+/// Pushes all (node, key) pairs of the iterator to the queue.
+///
+/// Since the nodes of the queue form a set, a node which is already in the queue or appears more than once in the iterator
+/// is kept once with the smallest of its keys, as in the `FromIterator` implementation.
+impl<N, K, const D: usize> Extend<(N, K)> for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        self.heap.extend_keep_min(iter)
+    }
+}
+
+/// Creates a queue of the given (node, key) pairs with a single bottom-up heapify in *O(n)* time.
+///
+/// The index bound of the queue is inferred as one more than the largest index among the nodes;
+/// zero if there are no elements. [`DaryHeapOfIndices::grow_index_bound`] can be used to admit nodes with larger indices later.
+///
+/// Since the nodes of the queue form a set, a node appearing more than once among the elements
+/// enters the queue once with the smallest of its keys.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = BinaryHeapOfIndices::from(vec![(3usize, 5.0), (1, 2.0), (3, 4.0)]);
+///
+/// assert_eq!(4, queue.index_bound());
+/// assert_eq!(2, queue.len());
+/// assert_eq!(Some((1, 2.0)), queue.pop());
+/// assert_eq!(Some((3, 4.0)), queue.pop());
+/// ```
+impl<N, K, const D: usize> From<Vec<(N, K)>> for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    fn from(elements: Vec<(N, K)>) -> Self {
+        Self::from_elements(elements)
+    }
+}
+
+/// Creates a queue of the given (node, key) pairs with a single bottom-up heapify in *O(n)* time.
+///
+/// The index bound of the queue is inferred as one more than the largest index among the nodes;
+/// zero if there are no elements. [`DaryHeapOfIndices::grow_index_bound`] can be used to admit nodes with larger indices later.
+///
+/// Since the nodes of the queue form a set, a node appearing more than once among the elements
+/// enters the queue once with the smallest of its keys.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = QuaternaryHeapOfIndices::from([(7usize, 42), (2, 7), (7, 3)]);
+///
+/// assert_eq!(8, queue.index_bound());
+/// assert_eq!(Some(&3), queue.key_ref(&7));
+/// assert_eq!(Some((7, 3)), queue.pop());
+/// assert_eq!(Some((2, 7)), queue.pop());
+/// ```
+impl<N, K, const D: usize, const M: usize> From<[(N, K); M]> for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    fn from(elements: [(N, K); M]) -> Self {
+        Self::from_elements(elements)
+    }
+}
+impl<N, K, const D: usize> PriorityQueueDecKey<N, K> for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    #[inline(always)]
+    fn contains(&self, node: &N) -> bool {
+        self.heap.contains(node)
+    }
+
+    #[inline(always)]
+    fn key_of(&self, node: &N) -> Option<K> {
+        self.heap.key_of(node)
+    }
+
+    #[inline(always)]
+    fn key_ref(&self, node: &N) -> Option<&K> {
+        self.heap.key_ref(node)
+    }
+
+    #[inline(always)]
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K {
+        self.heap.decrease_key(node, decreased_key)
+    }
+
+    #[inline(always)]
+    fn decrease_key_ref(&mut self, node: &N, decreased_key: &K) -> K {
+        self.heap.decrease_key_ref(node, decreased_key)
+    }
+
+    #[inline(always)]
+    fn decrease_key_with<F>(&mut self, node: &N, decrease: F) -> K
+    where
+        F: FnOnce(&K) -> K,
+    {
+        self.heap.decrease_key_with(node, decrease)
+    }
+
+    #[inline(always)]
+    fn decrease_key_all<I>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        self.heap.decrease_key_all(updates)
+    }
+
+    #[inline(always)]
+    fn increase_key(&mut self, node: &N, increased_key: K) {
+        self.heap.increase_key(node, increased_key)
+    }
+
+    #[inline(always)]
+    fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
+        self.heap.update_key(node, new_key)
     }
 
     #[inline(always)]
     fn remove(&mut self, node: &N) -> K {
         self.heap.remove(node)
     }
+
+    #[inline(always)]
+    fn take(&mut self, node: &N) -> Option<(N, K)> {
+        self.heap.take(node)
+    }
+
+    #[inline(always)]
+    fn update_key_if<F>(&mut self, node: &N, new_key: K, should_update: F) -> bool
+    where
+        F: FnOnce(&K, &K) -> bool,
+    {
+        self.heap.update_key_if(node, new_key, should_update)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct SerHeapOfIndices<'a, N, K> {
+    index_bound: usize,
+    elements: &'a [(N, K)],
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DeHeapOfIndices<N, K> {
+    index_bound: usize,
+    elements: Vec<(N, K)>,
+}
+
+/// Serializes the heap as its `index_bound` together with the sequence of its (node, key) pairs.
+#[cfg(feature = "serde")]
+impl<N, K, const D: usize> serde::Serialize for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex + serde::Serialize,
+    K: PartialOrd + Clone + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerHeapOfIndices {
+            index_bound: self.index_bound(),
+            elements: self.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the heap from its `index_bound` together with the sequence of its (node, key) pairs.
+///
+/// Fails if a node appears more than once in the sequence, or if the index of a node is not less than the `index_bound`.
+#[cfg(feature = "serde")]
+impl<'de, N, K, const D: usize> serde::Deserialize<'de> for DaryHeapOfIndices<N, K, D>
+where
+    N: HasIndex + serde::Deserialize<'de>,
+    K: PartialOrd + Clone + serde::Deserialize<'de>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let DeHeapOfIndices {
+            index_bound,
+            elements,
+        } = DeHeapOfIndices::<N, K>::deserialize(deserializer)?;
+
+        let out_of_bound = elements
+            .iter()
+            .map(|x| x.0.index())
+            .find(|&i| i >= index_bound);
+        if let Some(index) = out_of_bound {
+            let error = IndexOutOfBound {
+                index,
+                bound: index_bound,
+            };
+            return Err(serde::de::Error::custom(error));
+        }
+
+        let positions = HeapPositionsHasIndex::with_index_bound(index_bound);
+        let heap = Heap::from_elements(elements, positions)
+            .ok_or_else(|| serde::de::Error::custom("duplicate node"))?;
+        Ok(Self { heap })
+    }
 }
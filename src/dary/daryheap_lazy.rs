@@ -0,0 +1,423 @@
+use super::daryheap::DaryHeap;
+use crate::{
+    positions::map::Index, PriorityQueue, PriorityQueueDecKey, ResDecreaseKeyOrPush, ResUpdateKey,
+};
+
+use core::iter::FusedIterator;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Map of the nodes in the queue to their current keys together with the generation of their live entry on the heap.
+#[cfg(not(feature = "std"))]
+type Map<N, K> = BTreeMap<N, (K, u64)>;
+/// Map of the nodes in the queue to their current keys together with the generation of their live entry on the heap.
+#[cfg(feature = "std")]
+type Map<N, K> = HashMap<N, (K, u64)>;
+
+/// A d-ary heap which implements both `PriorityQueue` and `PriorityQueueDecKey` by lazy deletion.
+///
+/// See [`PriorityQueueDecKey`] for additional functionalities.
+///
+/// `LazyDecKeyHeap` wraps a plain [`DaryHeap`] together with a map of nodes to their current keys
+/// (`HashMap` or `BTreeMap` when no-std).
+/// Rather than moving the node on the heap, a key update simply pushes the (node, new_key) pair to the heap,
+/// leaving the element with the former key on the heap as a stale entry.
+/// Stale entries are discarded once they reach the root;
+/// and the heap is rebuilt from the map whenever the stale entries outnumber the nodes in the queue.
+///
+/// Each entry on the heap is tagged by a generation, and the map holds the generation of the live entry of each node.
+/// This allows to distinguish the live entry from the stale entries of the same node even when their keys are equal;
+/// and hence, [`iter`] traverses the heap skipping the stale entries without any allocation.
+///
+/// [`iter`]: PriorityQueue::iter
+///
+/// # Flexibility and Performance
+///
+/// Similar to `DaryHeapWithMap`, the nodes are required to implement `Hash + Eq`, and the queue is open-set.
+/// However, the map does not need to be updated on every movement of the nodes on the heap,
+/// which makes the sift operations as cheap as those of `DaryHeap`.
+/// In return, the heap temporarily holds more elements than the queue.
+///
+/// Therefore, it might outperform `DaryHeapWithMap` in workloads with many decrease-key operations,
+/// particularly when the keys are decreased by `decrease_key_or_push` as in Dijkstra's shortest path algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = LazyDecKeyHeap::<_, _, 4>::default();
+///
+/// queue.push('a', 42);
+/// queue.push('b', 17);
+///
+/// queue.decrease_key_or_push(&'a', 7);
+/// queue.decrease_key_or_push(&'c', 11);
+/// assert_eq!(3, queue.len());
+///
+/// queue.update_key(&'b', 99);
+///
+/// assert_eq!(Some(('a', 7)), queue.pop());
+/// assert_eq!(Some(('c', 11)), queue.pop());
+/// assert_eq!(Some(('b', 99)), queue.pop());
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazyDecKeyHeap<N, K, const D: usize = 2>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+{
+    heap: DaryHeap<(N, u64), K, D>,
+    keys: Map<N, K>,
+    generation: u64,
+}
+
+impl<N, K, const D: usize> Default for LazyDecKeyHeap<N, K, D>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        Self {
+            heap: DaryHeap::default(),
+            keys: Map::new(),
+            generation: 0,
+        }
+    }
+}
+
+impl<N, K, const D: usize> LazyDecKeyHeap<N, K, D>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+{
+    /// Creates a new empty lazy decrease-key heap.
+    ///
+    ///  # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut heap = LazyDecKeyHeap::<_, _, 4>::new();
+    ///
+    /// heap.push('a', 4);
+    /// heap.push('b', 42);
+    ///
+    /// assert_eq!(Some('a'), heap.pop_node());
+    /// assert_eq!(Some('b'), heap.pop_node());
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new lazy decrease-key heap with the given initial `capacity` on the number of nodes to simultaneously exist on the heap.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: DaryHeap::with_capacity(capacity),
+            #[cfg(not(feature = "std"))]
+            keys: Map::new(),
+            #[cfg(feature = "std")]
+            keys: Map::with_capacity(capacity),
+            generation: 0,
+        }
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Returns the number of elements on the underlying heap including the stale entries;
+    /// which is greater than or equal to the number of nodes in the queue, `len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = LazyDecKeyHeap::<_, _, 4>::default();
+    ///
+    /// queue.push('a', 42);
+    /// queue.push('b', 17);
+    /// queue.decrease_key(&'a', 7);
+    ///
+    /// assert_eq!(2, queue.len());
+    /// assert_eq!(3, queue.heap_len());
+    /// ```
+    pub fn heap_len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Sets the current key of the `node` in the map to the `key` tagged by a new generation;
+    /// then, pushes the corresponding live entry to the heap.
+    fn insert_and_push(&mut self, node: N, key: K) -> Option<K> {
+        self.generation += 1;
+        let previous = self
+            .keys
+            .insert(node.clone(), (key.clone(), self.generation));
+        self.push_entry(node, key);
+        previous.map(|(key, _)| key)
+    }
+
+    /// Pushes the (`node`, `key`) pair tagged by the current generation to the heap;
+    /// then, rebuilds the heap from the map if the stale entries outnumber the nodes in the queue.
+    fn push_entry(&mut self, node: N, key: K) {
+        self.heap.push((node, self.generation), key);
+        if self.heap.len() > 2 * self.keys.len() {
+            self.heap.clear();
+            let valid = self
+                .keys
+                .iter()
+                .map(|(n, (k, g))| ((n.clone(), *g), k.clone()));
+            self.heap.extend(valid);
+        }
+    }
+
+    /// Pops the stale entries at the root of the heap, if any;
+    /// so that the root of the heap is always the element with the minimum key in the queue.
+    fn discard_stale_root(&mut self) {
+        while let Some(((node, generation), _)) = self.heap.peek() {
+            match is_live(&self.keys, node, *generation) {
+                true => break,
+                false => _ = self.heap.pop(),
+            }
+        }
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueue<N, K> for LazyDecKeyHeap<N, K, D>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a>
+        = (&'a N, &'a K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = IterLazy<'a, N, K>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<(&N, &K)> {
+        self.heap.peek().map(|((node, _), key)| (node, key))
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear();
+        self.keys.clear();
+    }
+
+    fn pop(&mut self) -> Option<(N, K)> {
+        let ((node, _), key) = self.heap.pop()?;
+        self.keys.remove(&node);
+        self.discard_stale_root();
+        Some((node, key))
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.pop().map(|x| x.0)
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.pop().map(|x| x.1)
+    }
+
+    fn push(&mut self, node: N, key: K) {
+        let previous = self.insert_and_push(node, key);
+        debug_assert!(previous.is_none(), "pushing an already added node");
+    }
+
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        match self.heap.peek() {
+            Some(root) if root.1 < key => {
+                self.generation += 1;
+                self.keys
+                    .insert(node.clone(), (key.clone(), self.generation));
+                let ((node, _), key) = self.heap.push_then_pop((node, self.generation), key);
+                self.keys.remove(&node);
+                self.discard_stale_root();
+                (node, key)
+            }
+            _ => (node, key),
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        IterLazy {
+            iter: self.heap.as_slice().iter(),
+            keys: &self.keys,
+        }
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueueDecKey<N, K> for LazyDecKeyHeap<N, K, D>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+{
+    #[inline(always)]
+    fn contains(&self, node: &N) -> bool {
+        self.keys.contains_key(node)
+    }
+
+    #[inline(always)]
+    fn key_of(&self, node: &N) -> Option<K> {
+        self.key_ref(node).cloned()
+    }
+
+    #[inline(always)]
+    fn key_ref(&self, node: &N) -> Option<&K> {
+        self.keys.get(node).map(|(key, _)| key)
+    }
+
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K {
+        let key = self
+            .key_ref(node)
+            .expect("cannot decrease key of a node that is not on the queue");
+        assert!(
+            decreased_key <= *key,
+            "decrease_key is called with a greater key"
+        );
+        match decreased_key < *key {
+            true => self
+                .insert_and_push(node.clone(), decreased_key)
+                .expect("node exists"),
+            false => decreased_key,
+        }
+    }
+
+    fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
+        let key = self
+            .key_ref(node)
+            .expect("cannot update key of a node that is not on the queue");
+        let result = if new_key < *key {
+            ResUpdateKey::Decreased
+        } else {
+            ResUpdateKey::Increased
+        };
+        if new_key != *key {
+            self.insert_and_push(node.clone(), new_key);
+            self.discard_stale_root();
+        }
+        result
+    }
+
+    fn remove(&mut self, node: &N) -> K {
+        let (key, _) = self
+            .keys
+            .remove(node)
+            .expect("cannot remove a node that is not on the queue");
+        self.discard_stale_root();
+        key
+    }
+
+    fn take(&mut self, node: &N) -> Option<(N, K)> {
+        let (node, (key, _)) = self.keys.remove_entry(node)?;
+        self.discard_stale_root();
+        Some((node, key))
+    }
+
+    fn decrease_key_or_push(&mut self, node: &N, key: K) -> ResDecreaseKeyOrPush {
+        match self.keys.contains_key(node) {
+            true => {
+                self.decrease_key(node, key);
+                ResDecreaseKeyOrPush::Decreased
+            }
+            false => {
+                self.push(node.clone(), key);
+                ResDecreaseKeyOrPush::Pushed
+            }
+        }
+    }
+}
+
+impl<N, K, const D: usize> Extend<(N, K)> for LazyDecKeyHeap<N, K, D>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        for (node, key) in iter {
+            self.push(node, key);
+        }
+    }
+}
+
+/// Returns whether the entry of the `node` with the given `generation` is its live entry on the heap.
+#[inline(always)]
+fn is_live<N: Index, K>(keys: &Map<N, K>, node: &N, generation: u64) -> bool {
+    keys.get(node).is_some_and(|(_, g)| *g == generation)
+}
+
+/// An iterator over the (node, key) pairs of a [`LazyDecKeyHeap`] in an arbitrary order.
+///
+/// Created by the `iter` method of the heap.
+/// It traverses the underlying heap skipping the stale entries;
+/// hence, it does not allocate, while visiting all entries on the heap including the stale ones.
+pub struct IterLazy<'a, N, K>
+where
+    N: Index,
+{
+    iter: core::slice::Iter<'a, ((N, u64), K)>,
+    keys: &'a Map<N, K>,
+}
+
+impl<'a, N, K> Iterator for IterLazy<'a, N, K>
+where
+    N: Index,
+{
+    type Item = (&'a N, &'a K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let keys = self.keys;
+        self.iter
+            .find(|((node, generation), _)| is_live(keys, node, *generation))
+            .map(|((node, _), key)| (node, key))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<N, K> DoubleEndedIterator for IterLazy<'_, N, K>
+where
+    N: Index,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let keys = self.keys;
+        self.iter
+            .rfind(|((node, generation), _)| is_live(keys, node, *generation))
+            .map(|((node, _), key)| (node, key))
+    }
+}
+
+impl<N, K> FusedIterator for IterLazy<'_, N, K> where N: Index {}
@@ -1,13 +1,41 @@
-use super::heap::Heap;
+use super::{
+    daryheap::DaryHeap,
+    daryheap_const_helpers::{level_of, offset},
+    daryheap_index::DaryHeapOfIndices,
+    drain::Drain,
+    drain_sorted::DrainSorted,
+    entry::{Entry, HeapEntry},
+    heap::Heap,
+    into_iter_sorted::IntoIterSorted,
+    iter_mut::IterMut,
+    iter_sorted::IterSorted,
+    peek_mut::PeekMut,
+};
 use crate::{
-    positions::map::{HeapPositionsMap, Index},
-    PriorityQueue, PriorityQueueDecKey, ResUpdateKey,
+    positions::{
+        has_index::HeapPositionsHasIndex,
+        map::{DefaultHashBuilder, HashBuilder, HeapPositionsMap, Index, Lookup},
+    },
+    HasIndex, HeapError, IndexOutOfBound, PriorityQueue, PriorityQueueDecKey, RenameError,
+    ResUpdateKey,
+};
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    iter::{Rev, Take},
+    slice::Iter,
 };
 
 /// Type alias for `DaryHeapWithMap<N, K, 2>`; see [`DaryHeapWithMap`] for details.
 pub type BinaryHeapWithMap<N, K> = DaryHeapWithMap<N, K, 2>;
+/// Type alias for `DaryHeapWithMap<N, K, 3>`; see [`DaryHeapWithMap`] for details.
+pub type TernaryHeapWithMap<N, K> = DaryHeapWithMap<N, K, 3>;
 /// Type alias for `DaryHeapWithMap<N, K, 4>`; see [`DaryHeapWithMap`] for details.
 pub type QuaternaryHeapWithMap<N, K> = DaryHeapWithMap<N, K, 4>;
+/// Type alias for `DaryHeapWithMap<N, K, 8>`; see [`DaryHeapWithMap`] for details.
+pub type OctaryHeapWithMap<N, K> = DaryHeapWithMap<N, K, 8>;
 
 /// A d-ary heap which implements both `PriorityQueue` and `PriorityQueueDecKey`.
 ///
@@ -119,19 +147,53 @@ pub type QuaternaryHeapWithMap<N, K> = DaryHeapWithMap<N, K, 4>;
 /// test_priority_queue_deckey(QuaternaryHeapWithMap::default());
 /// test_priority_queue_deckey(QuaternaryHeapWithMap::with_capacity(16));
 /// ```
-#[derive(Debug, Clone)]
-pub struct DaryHeapWithMap<N, K, const D: usize = 2>
+#[derive(Debug)]
+pub struct DaryHeapWithMap<N, K, const D: usize = 2, S = DefaultHashBuilder>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder,
+{
+    heap: Heap<N, K, HeapPositionsMap<N, S>, D>,
+}
+
+impl<N, K, const D: usize, S> Clone for DaryHeapWithMap<N, K, D, S>
 where
     N: Index,
     K: PartialOrd + Clone,
+    S: HashBuilder,
 {
-    heap: Heap<N, K, HeapPositionsMap<N>, D>,
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+        }
+    }
+
+    /// Clones the elements of `source` into this heap reusing the allocations of both the tree and the positions map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let source = QuaternaryHeapWithMap::from([('a', 3), ('b', 1), ('c', 2)]);
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::with_capacity(16);
+    /// queue.clone_from(&source);
+    ///
+    /// assert!(queue == source);
+    /// assert_eq!(Some(1), queue.key_of(&'b'));
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        self.heap.clone_from(&source.heap);
+    }
 }
 
-impl<N, K, const D: usize> Default for DaryHeapWithMap<N, K, D>
+impl<N, K, const D: usize, S> Default for DaryHeapWithMap<N, K, D, S>
 where
     N: Index,
     K: PartialOrd + Clone,
+    S: HashBuilder + Default,
 {
     fn default() -> Self {
         Self {
@@ -170,6 +232,105 @@ where
             heap: Heap::new(Some(capacity), HeapPositionsMap::with_capacity(capacity)),
         }
     }
+}
+
+impl<N, K, const D: usize, S> DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder + Default,
+{
+    /// Creates a queue of the (node, key) pairs of the iterator with a single bottom-up heapify, exactly as the
+    /// `FromIterator` implementation; and additionally returns the pairs which are left out due to duplicate nodes.
+    ///
+    /// Since the nodes of the queue form a set, a node appearing more than once in the iterator
+    /// enters the queue once with the smallest of its keys.
+    /// Each time a node reappears, the pair with the greater key is left out; the pair already in the queue
+    /// is kept when the keys are equal.
+    /// The left out pairs are returned in the order of the reappearances in the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let edges = [('a', 4), ('b', 3), ('a', 1), ('c', 7), ('b', 5)];
+    /// let (mut queue, superseded) = QuaternaryHeapWithMap::from_iter_reporting(edges);
+    ///
+    /// assert_eq!(vec![('a', 4), ('b', 5)], superseded);
+    ///
+    /// assert_eq!(3, queue.len());
+    /// assert_eq!(Some(('a', 1)), queue.pop());
+    /// assert_eq!(Some(('b', 3)), queue.pop());
+    /// assert_eq!(Some(('c', 7)), queue.pop());
+    /// ```
+    pub fn from_iter_reporting<I>(iter: I) -> (Self, Vec<(N, K)>)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        let mut queue = Self::default();
+        let mut superseded = Vec::new();
+        queue
+            .heap
+            .extend_keep_min_reporting(iter, |pair| superseded.push(pair));
+        (queue, superseded)
+    }
+}
+
+impl<N, K, const D: usize, S> DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder,
+{
+    /// Creates a new empty d-ary heap which uses the given `hash_builder` to hash the nodes in its positions map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+    /// let mut heap = DaryHeapWithMap::<_, _, 4, _>::with_hasher(hash_builder);
+    ///
+    /// heap.push('a', 4);
+    /// heap.push('b', 42);
+    /// heap.decrease_key(&'b', 2);
+    ///
+    /// assert_eq!(Some('b'), heap.pop_node());
+    /// assert_eq!(Some('a'), heap.pop_node());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Creates a new d-ary heap with the given initial `capacity` on the number of nodes to simultaneously exist on the heap,
+    /// which uses the given `hash_builder` to hash the nodes in its positions map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    /// use std::hash::BuildHasherDefault;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+    /// let mut heap = DaryHeapWithMap::<_, _, 4, _>::with_capacity_and_hasher(16, hash_builder);
+    ///
+    /// heap.push('a', 4);
+    /// assert!(heap.capacity() >= 16);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let positions = HeapPositionsMap::with_capacity_and_hasher(capacity, hash_builder);
+        Self {
+            heap: Heap::new(Some(capacity), positions),
+        }
+    }
+
     /// Returns the 'd' of the d-ary heap.
     /// In other words, it represents the maximum number of children that each node on the heap can have.
     pub const fn d() -> usize {
@@ -200,90 +361,1810 @@ where
     pub fn as_slice(&self) -> &[(N, K)] {
         self.heap.as_slice()
     }
-}
-
-impl<N, K, const D: usize> PriorityQueue<N, K> for DaryHeapWithMap<N, K, D>
-where
-    N: Index,
-    K: PartialOrd + Clone,
-{
-    type NodeKey<'a> = &'a (N, K) where Self: 'a, N: 'a, K: 'a;
-    type Iter<'a> = core::slice::Iter<'a, (N, K)> where Self: 'a, N: 'a, K: 'a;
 
-    #[inline(always)]
-    fn len(&self) -> usize {
-        self.heap.len()
+    /// Returns an iterator visiting the nodes and keys currently in the queue in the reverse order of `as_slice`.
+    ///
+    /// Note that the order is still arbitrary, this is only the reverse traversal of the internal tree;
+    /// in particular, the elements are not yielded in descending order of their keys.
+    /// The iterator is double-ended; `iter_rev().rev()` is equivalent to `iter()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let reversed: Vec<_> = queue.iter_rev().collect();
+    /// let mut forward: Vec<_> = queue.iter().collect();
+    /// forward.reverse();
+    /// assert_eq!(forward, reversed);
+    /// ```
+    pub fn iter_rev(&self) -> Rev<Iter<'_, (N, K)>> {
+        self.as_slice().iter().rev()
     }
 
-    #[inline(always)]
-    fn capacity(&self) -> usize {
-        self.heap.capacity()
+    /// Returns the number of levels of the tree of the heap, which is 0 for an empty heap and 1 for a heap
+    /// with a single element; each level holds `D` times the elements of its parent level.
+    ///
+    /// This is useful for comparing the heights of the heaps with different arities on a particular workload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// assert_eq!(0, queue.depth());
+    ///
+    /// queue.push(0usize, 42);
+    /// assert_eq!(1, queue.depth());
+    ///
+    /// // levels of sizes 1 and 4
+    /// for node in 1..5 {
+    ///     queue.push(node, 42);
+    /// }
+    /// assert_eq!(2, queue.depth());
+    ///
+    /// queue.push(5, 42);
+    /// assert_eq!(3, queue.depth());
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.heap.depth()
     }
 
-    fn peek(&self) -> Option<&(N, K)> {
-        self.heap.peek()
+    /// Validates the heap; i.e., checks whether or not:
+    /// * the key of every element is greater than or equal to the key of its parent on the tree, and
+    /// * the positions of the nodes on the tree are tracked correctly.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    ///
+    /// Since the heap maintains its invariants on its own, this method is mainly useful for debugging and testing.
+    /// It requires *O(n)* time where n is the number of elements on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
     }
 
-    fn clear(&mut self) {
-        self.heap.clear()
+    /// Returns the position of the `node` in the slice of elements of the heap returned by [`as_slice`];
+    /// returns None if the node is not in the queue.
+    ///
+    /// Note that positions of the nodes change as elements are pushed to or popped from the queue,
+    /// or as their keys are updated.
+    ///
+    /// [`as_slice`]: Self::as_slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let position = queue.position_of(&"x").unwrap();
+    /// assert_eq!(&("x", 42), &queue.as_slice()[position]);
+    ///
+    /// assert_eq!(Some(0), queue.position_of(&"y"));
+    /// assert_eq!(None, queue.position_of(&"w"));
+    /// ```
+    pub fn position_of<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.tree_position_of(node)
+            .map(|position| position - offset::<D>())
     }
 
-    #[inline(always)]
-    fn pop(&mut self) -> Option<(N, K)> {
-        self.heap.pop()
+    /// Returns the level of the `node` on the tree of the heap where the root is at level 0;
+    /// returns None if the node is not in the queue.
+    ///
+    /// Note that the level is always less than the [`depth`](Self::depth) of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// for node in 0..6 {
+    ///     queue.push(node, node);
+    /// }
+    ///
+    /// assert_eq!(Some(0), queue.level_of(&0));
+    /// assert_eq!(Some(1), queue.level_of(&3));
+    /// assert_eq!(Some(2), queue.level_of(&5));
+    /// assert_eq!(None, queue.level_of(&10));
+    /// ```
+    pub fn level_of<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.position_of(node).map(level_of::<D>)
     }
 
-    #[inline(always)]
-    fn pop_node(&mut self) -> Option<N> {
-        self.heap.pop_node()
+    /// Returns the position of the `node` in the underlying tree of the heap, including the offset.
+    fn tree_position_of<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.heap.positions().position_of_borrowed(node)
     }
 
-    #[inline(always)]
-    fn pop_key(&mut self) -> Option<K> {
-        self.heap.pop_key()
+    /// Returns whether the given `node` is in the queue or not.
+    ///
+    /// The node may be any borrowed form of the node type, such as `&str` for `String` nodes, mirroring `HashMap::contains_key`;
+    /// see [`PriorityQueueDecKey::contains`] for the owned form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(String::from("x"), 42);
+    ///
+    /// assert!(queue.contains("x"));
+    /// assert!(!queue.contains("y"));
+    /// ```
+    pub fn contains<Q>(&self, node: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.tree_position_of(node).is_some()
     }
 
-    #[inline(always)]
-    fn push(&mut self, node: N, key: K) {
-        self.heap.push(node, key)
+    /// Returns the key of the given `node` if it is in the queue; returns None otherwise.
+    ///
+    /// The node may be any borrowed form of the node type, such as `&str` for `String` nodes;
+    /// see [`PriorityQueueDecKey::key_of`] for the owned form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(String::from("x"), 42);
+    ///
+    /// assert_eq!(Some(42), queue.key_of("x"));
+    /// assert_eq!(None, queue.key_of("y"));
+    /// ```
+    pub fn key_of<Q>(&self, node: &Q) -> Option<K>
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.key_ref(node).cloned()
     }
 
-    #[inline(always)]
-    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
-        self.heap.push_then_pop(node, key)
+    /// Returns a reference to the key of the given `node` if it is in the queue; returns None otherwise.
+    ///
+    /// The node may be any borrowed form of the node type, such as `&str` for `String` nodes;
+    /// see [`PriorityQueueDecKey::key_ref`] for the owned form.
+    pub fn key_ref<Q>(&self, node: &Q) -> Option<&K>
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.tree_position_of(node)
+            .map(|position| &self.heap.element_at(position).1)
     }
 
-    fn iter(&self) -> Self::Iter<'_> {
-        self.as_slice().iter()
+    /// Decreases key of the `node` which is already in the queue to the given `decreased_key`, and returns its previous key.
+    ///
+    /// The node may be any borrowed form of the node type, such as `&str` for `String` nodes;
+    /// see [`PriorityQueueDecKey::decrease_key`] for the owned form.
+    ///
+    /// # Panics
+    /// This method panics if:
+    /// * the `node` is not in the queue; or
+    /// * `decreased_key` is strictly larger than key of the `node` in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(String::from("x"), 42);
+    /// queue.push(String::from("y"), 7);
+    ///
+    /// assert_eq!(42, queue.decrease_key("x", 3));
+    /// assert_eq!(Some((String::from("x"), 3)), queue.pop());
+    /// ```
+    pub fn decrease_key<Q>(&mut self, node: &Q, decreased_key: K) -> K
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        let position = self
+            .tree_position_of(node)
+            .expect("cannot decrease key of a node that is not on the queue");
+        self.heap.decrease_key_at(position, decreased_key)
     }
-}
-impl<N, K, const D: usize> PriorityQueueDecKey<N, K> for DaryHeapWithMap<N, K, D>
-where
-    N: Index,
-    K: PartialOrd + Clone,
-{
-    #[inline(always)]
-    fn contains(&self, node: &N) -> bool {
-        self.heap.contains(node)
+
+    /// Updates key of the `node` which is already in the queue as the given `new_key`,
+    /// and returns whether the key is decreased or increased.
+    ///
+    /// The node may be any borrowed form of the node type, such as `&str` for `String` nodes;
+    /// see [`PriorityQueueDecKey::update_key`] for the owned form.
+    ///
+    /// # Panics
+    /// This method panics if the `node` is not in the queue.
+    pub fn update_key<Q>(&mut self, node: &Q, new_key: K) -> ResUpdateKey
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        let position = self
+            .tree_position_of(node)
+            .expect("cannot update key of a node that is not on the queue");
+        self.heap.update_key_at(position, new_key)
     }
 
-    #[inline(always)]
-    fn key_of(&self, node: &N) -> Option<K> {
-        self.heap.key_of(node)
+    /// Removes the `node` from the queue; and returns its current key.
+    ///
+    /// The node may be any borrowed form of the node type, such as `&str` for `String` nodes;
+    /// see [`PriorityQueueDecKey::remove`] for the owned form.
+    ///
+    /// # Panics
+    /// This method panics if the `node` is not in the queue.
+    pub fn remove<Q>(&mut self, node: &Q) -> K
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        let position = self
+            .tree_position_of(node)
+            .expect("cannot remove a node that is not on the queue");
+        self.heap.remove_at(position)
     }
 
-    #[inline(always)]
-    fn decrease_key(&mut self, node: &N, decreased_key: K) {
-        self.heap.decrease_key(node, decreased_key)
+    /// Removes the `node` from the queue if it is present, and returns the removed (node, key) pair;
+    /// returns None and leaves the queue unchanged if the `node` is not in the queue.
+    ///
+    /// The node may be any borrowed form of the node type, such as `&str` for `String` nodes;
+    /// see [`PriorityQueueDecKey::take`] for the owned form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(String::from("x"), 42);
+    ///
+    /// assert_eq!(None, queue.take("y"));
+    /// assert_eq!(Some((String::from("x"), 42)), queue.take("x"));
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn take<Q>(&mut self, node: &Q) -> Option<(N, K)>
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.tree_position_of(node)
+            .map(|position| self.heap.remove_and_heapify(position))
     }
 
-    #[inline(always)]
-    fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
-        self.heap.update_key(node, new_key)
+    /// Returns the [`Entry`] of the given `node` in the queue, which is either occupied or vacant,
+    /// for in-place manipulation.
+    ///
+    /// The position of the node is looked up only once; hence, the entry allows to combine operations such as
+    /// decreasing the key of a node if it is in the queue and pushing it otherwise, without an additional lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("a", 42);
+    ///
+    /// for (node, key) in [("a", 7), ("b", 21)] {
+    ///     match queue.entry(node) {
+    ///         Entry::Occupied(entry) if &key < entry.key() => entry.decrease_key(key),
+    ///         Entry::Occupied(_) => {}
+    ///         Entry::Vacant(entry) => entry.push(key),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(("a", 7)), queue.pop());
+    /// assert_eq!(Some(("b", 21)), queue.pop());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn entry(&mut self, node: N) -> Entry<'_, N, K> {
+        self.heap.entry(node)
     }
 
-    #[inline(always)]
-    fn remove(&mut self, node: &N) -> K {
-        self.heap.remove(node)
+    /// Returns a reference to the key of the `node` if it is in the queue;
+    /// otherwise, pushes the `node` with the key computed by `default` and returns a reference to this key.
+    ///
+    /// The position of the node is looked up only once, unlike the `contains` followed by `push` pattern;
+    /// and `default` is called only if the node is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("a", 42);
+    ///
+    /// assert_eq!(&42, queue.get_or_insert_with("a", || 7));
+    /// assert_eq!(&21, queue.get_or_insert_with("b", || 21));
+    ///
+    /// assert_eq!(2, queue.len());
+    /// assert_eq!(Some(("b", 21)), queue.pop());
+    /// assert_eq!(Some(("a", 42)), queue.pop());
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, node: N, default: F) -> &K
+    where
+        F: FnOnce() -> K,
+    {
+        self.heap.get_or_insert_with(node, default)
+    }
+
+    /// Replaces the `old` node with the `new` node keeping its key and its position on the heap.
+    ///
+    /// Since the key is unchanged, the heap property is preserved without any sift;
+    /// unlike removing the `old` node and pushing the `new` node, which might move the element to another position.
+    ///
+    /// Returns [`RenameError::NodeNotFound`] if the `old` node is not in the queue, and
+    /// [`RenameError::NodeAlreadyExists`] if the `new` node is already in the queue; the queue is unchanged in both cases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("a", 42);
+    /// queue.push("b", 7);
+    ///
+    /// assert_eq!(Ok(()), queue.rename(&"a", "c"));
+    /// assert!(!queue.contains(&"a"));
+    /// assert_eq!(Some(42), queue.key_of(&"c"));
+    ///
+    /// assert_eq!(Err(RenameError::NodeNotFound), queue.rename(&"a", "a"));
+    /// assert_eq!(Err(RenameError::NodeAlreadyExists), queue.rename(&"b", "c"));
+    /// ```
+    pub fn rename(&mut self, old: &N, new: N) -> Result<(), RenameError> {
+        self.heap.rename(old, new)
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be pushed to the heap.
+    /// The heap may reserve more space to speculatively avoid frequent reallocations.
+    ///
+    /// Capacity of the map of positions of the nodes is increased as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::<char, i32>::new();
+    /// queue.reserve(100);
+    /// assert!(queue.capacity() >= 100);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.heap.reserve(additional)
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more elements to be pushed to the heap.
+    /// Unlike `reserve`, this will not deliberately over-allocate to speculatively avoid frequent allocations.
+    ///
+    /// Capacity of the map of positions of the nodes is increased as well; however, the map may over-allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::<char, i32>::new();
+    /// queue.reserve_exact(100);
+    /// assert!(queue.capacity() >= 100);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.heap.reserve_exact(additional)
+    }
+
+    /// Shrinks the capacity of the heap as much as possible.
+    ///
+    /// The map of positions of the nodes is shrunk as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::with_capacity(1000);
+    /// for node in 0..1000 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// while queue.len() > 10 {
+    ///     queue.pop();
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.shrink_to_fit();
+    /// assert!(queue.capacity() < 1000);
+    /// assert!(queue.capacity() >= 10);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.heap.shrink_to_fit();
+        self.heap.positions_mut().shrink_to_fit();
+    }
+
+    /// Clears the heap and releases its memory by shrinking the capacity as much as possible.
+    ///
+    /// Unlike `clear`, which retains the capacity for reuse, this method is useful when the memory held
+    /// by a queue which has once been large is to be released without creating a new queue.
+    ///
+    /// The map of positions of the nodes is cleared and shrunk as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::with_capacity(1000);
+    /// for node in 0..1000 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.clear_and_shrink();
+    /// assert!(queue.is_empty());
+    /// assert_eq!(0, queue.capacity());
+    /// ```
+    pub fn clear_and_shrink(&mut self) {
+        self.heap.clear_and_shrink();
+        self.heap.positions_mut().shrink_to_fit();
+    }
+
+    /// Clears the heap and, if its capacity exceeds `max_capacity`, shrinks the capacity down to `max_capacity`;
+    /// the capacity is retained otherwise.
+    ///
+    /// This method lies between `clear`, which retains the capacity, and `clear_and_shrink`, which releases it:
+    /// reusing the queue in a long-running loop avoids reallocations in normal operation,
+    /// while the memory held after an occasional burst is bounded by `max_capacity`.
+    ///
+    /// The map of positions of the nodes is cleared and shrunk in the same manner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::with_capacity(1000);
+    /// for node in 0..1000 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// assert!(queue.capacity() >= 1000);
+    ///
+    /// queue.clear_to_capacity(100);
+    /// assert!(queue.is_empty());
+    /// assert!(queue.capacity() >= 100 && queue.capacity() < 1000);
+    ///
+    /// for node in 0..10 {
+    ///     queue.push(node, node as u64);
+    /// }
+    /// queue.clear_to_capacity(100);
+    /// assert!(queue.is_empty());
+    /// assert!(queue.capacity() >= 100 && queue.capacity() < 1000);
+    /// ```
+    pub fn clear_to_capacity(&mut self, max_capacity: usize) {
+        self.heap.clear_to_capacity(max_capacity);
+        self.heap.positions_mut().shrink_to(max_capacity);
+    }
+
+    /// Returns a guard providing mutable access to the key of the foremost element of the heap;
+    /// returns None if the heap is empty.
+    ///
+    /// If the key is mutated, the heap is restored once the guard is dropped;
+    /// see [`PeekMut`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// if let Some(mut top) = queue.peek_mut() {
+    ///     assert_eq!(&"y", top.node());
+    ///     *top.key_mut() += 43;
+    /// }
+    ///
+    /// assert_eq!(Some(&("x", 42)), queue.peek());
+    /// assert_eq!(Some(50), queue.key_of(&"y"));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, N, K>> {
+        self.heap.peek_mut()
+    }
+
+    /// Returns a guard providing mutable access to the keys of all elements of the heap;
+    /// the guard is iterated by a mutable reference yielding `(&N, &mut K)` pairs in arbitrary order.
+    ///
+    /// The heap is restored by a single bottom-up heapify once the guard is dropped;
+    /// see [`IterMut`] for details.
+    /// This is much cheaper than draining and rebuilding the heap to apply an update to all keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// for (node, key) in &mut queue.iter_mut() {
+    ///     match node {
+    ///         1 => *key += 100,
+    ///         _ => *key *= 2,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(&(0, 84)), queue.peek());
+    /// assert_eq!(Some(&107), queue.key_ref(&1));
+    /// assert_eq!(Some((0, 84)), queue.pop());
+    /// assert_eq!(Some((1, 107)), queue.pop());
+    /// assert_eq!(Some((2, 198)), queue.pop());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, N, K> {
+        self.heap.iter_mut()
+    }
+
+    /// Restores the heap property by a single bottom-up heapify over all elements and rebuilds the positions of the nodes.
+    ///
+    /// This is the canonical way to fix the heap after its elements are mutated in a way that could have invalidated
+    /// its invariants; for instance, after mutating the keys through the guards returned by [`iter_mut`] or [`peek_mut`].
+    /// These guards already repair the heap on drop; hence, `repair` is mainly an escape hatch rather than a routine step.
+    ///
+    /// It requires *O(n)* time where n is the number of elements on the heap,
+    /// and leaves an already valid heap valid.
+    ///
+    /// [`iter_mut`]: Self::iter_mut
+    /// [`peek_mut`]: Self::peek_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(0usize, 42);
+    /// queue.push(1, 7);
+    /// queue.push(2, 99);
+    ///
+    /// queue.repair();
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// assert_eq!(Some(0), queue.position_of(&1));
+    /// assert_eq!(Some((1, 7)), queue.pop());
+    /// ```
+    pub fn repair(&mut self) {
+        self.heap.repair()
+    }
+
+    /// Removes the foremost element of the queue and replaces it with the given (`node`, `key`) pair;
+    /// returns the removed element.
+    /// If the queue is empty, the pair is pushed and None is returned.
+    ///
+    /// This is equivalent to, but faster than, a `pop` followed by a `push`,
+    /// since it requires a single heapify-down rather than two heapify operations.
+    /// Unlike `push_then_pop`, the new element always enters the queue, even when its key is less than that of the root.
+    ///
+    /// Note that the `node` must not already be in the queue unless it is the node of the current root,
+    /// just as nodes which are already in the queue must not be pushed again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// assert_eq!(None, queue.replace_root("x", 42));
+    ///
+    /// queue.push("y", 7);
+    /// assert_eq!(Some(("y", 7)), queue.replace_root("z", 99));
+    ///
+    /// assert_eq!(Some(("x", 42)), queue.pop());
+    /// assert_eq!(Some(("z", 99)), queue.pop());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn replace_root(&mut self, node: N, key: K) -> Option<(N, K)> {
+        self.heap.replace_root(node, key)
+    }
+
+    /// Returns an iterator visiting all (node, key) pairs on the heap in ascending order of keys,
+    /// without mutating or consuming the heap.
+    ///
+    /// The order is the same as the order in which the elements would be popped,
+    /// while elements with equal keys are yielded in an arbitrary order.
+    ///
+    /// Yielding the first `k` elements requires *O(k log k)* time and *O(k)* additional space;
+    /// see [`IterSorted`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 7);
+    ///
+    /// let keys: Vec<_> = queue.iter_sorted().map(|x| x.1).collect();
+    /// assert_eq!(vec![7, 7, 42, 99], keys);
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(4, queue.len());
+    /// ```
+    pub fn iter_sorted(&self) -> IterSorted<'_, N, K, D> {
+        self.heap.iter_sorted()
+    }
+
+    /// Returns an iterator over the `k` foremost (node, key) pairs of the heap in ascending order of keys,
+    /// without mutating or consuming the heap.
+    ///
+    /// The iterator yields all elements of the heap if the heap has fewer than `k` elements.
+    ///
+    /// The iterator is a [`IterSorted`] limited to `k` elements;
+    /// hence, it requires *O(k log k)* time and *O(k)* additional space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 21);
+    ///
+    /// let top2: Vec<_> = queue.peek_k(2).collect();
+    /// assert_eq!(vec![&("y", 7), &("w", 21)], top2);
+    ///
+    /// assert_eq!(4, queue.peek_k(10).count());
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(4, queue.len());
+    /// ```
+    pub fn peek_k(&self, k: usize) -> Take<IterSorted<'_, N, K, D>> {
+        self.iter_sorted().take(k)
+    }
+
+    /// Returns the (node, key) pair which would be the `n`-th element to be popped, counting from zero,
+    /// without mutating the heap; returns None if the heap has `n` or fewer elements.
+    ///
+    /// `peek_nth(0)` is equivalent to `peek`.
+    /// It requires *O(n log n)* time and *O(n)* additional space, where n is the argument rather than the length of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Some(&("y", 7)), queue.peek_nth(0));
+    /// assert_eq!(Some(&("x", 42)), queue.peek_nth(1));
+    /// assert_eq!(Some(&("z", 99)), queue.peek_nth(2));
+    /// assert_eq!(None, queue.peek_nth(3));
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&(N, K)> {
+        self.iter_sorted().nth(n)
+    }
+
+    /// Returns the (node, key) pair which would be the second element to be popped without mutating the heap;
+    /// returns None if the heap has fewer than two elements.
+    ///
+    /// Since the second smallest element of a valid heap is one of the children of the root,
+    /// only the up to `D` children of the root are examined; hence, it requires *O(D)* time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// assert_eq!(None, queue.peek_second());
+    ///
+    /// queue.push("x", 42);
+    /// assert_eq!(None, queue.peek_second());
+    ///
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 13);
+    ///
+    /// assert_eq!(Some(&("y", 7)), queue.peek());
+    /// assert_eq!(Some(&("w", 13)), queue.peek_second());
+    /// ```
+    pub fn peek_second(&self) -> Option<&(N, K)> {
+        self.heap.peek_second()
+    }
+
+    /// Returns a vector of clones of the (node, key) pairs of the heap sorted in ascending order of keys,
+    /// without mutating the heap.
+    ///
+    /// Unlike [`as_slice`], the elements are sorted; and unlike [`into_iter_sorted`], the heap is not consumed.
+    /// It requires *O(n log n)* time and clones every element of the heap.
+    /// Elements with equal keys are in an arbitrary order.
+    ///
+    /// [`as_slice`]: Self::as_slice
+    /// [`into_iter_sorted`]: Self::into_iter_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(vec![("y", 7), ("x", 42), ("z", 99)], queue.to_sorted_vec());
+    ///
+    /// // queue is not mutated
+    /// assert_eq!(3, queue.len());
+    /// ```
+    pub fn to_sorted_vec(&self) -> Vec<(N, K)> {
+        self.iter_sorted().cloned().collect()
+    }
+
+    /// Consumes the heap and returns an iterator yielding its (node, key) pairs in ascending order of keys.
+    ///
+    /// The iterator is lazy; each call to `next` pops the element with the lowest key.
+    /// Elements with equal keys are yielded in an arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let sorted: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(vec![("y", 7), ("x", 42), ("z", 99)], sorted);
+    /// ```
+    pub fn into_iter_sorted(self) -> IntoIterSorted<N, K, D> {
+        IntoIterSorted::new(self.heap.into_heap_without_positions())
+    }
+
+    /// Consumes the heap and returns its `k` foremost (node, key) pairs in ascending order of keys;
+    /// the remaining elements are dropped.
+    ///
+    /// All elements of the heap are returned in sorted order if the heap has fewer than `k` elements.
+    ///
+    /// Elements are popped one by one and popping stops after the `k`-th element;
+    /// hence, the method requires *O(k log n)* time rather than sorting all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 21);
+    ///
+    /// let top2 = queue.k_smallest(2);
+    /// assert_eq!(vec![("y", 7), ("w", 21)], top2);
+    /// ```
+    pub fn k_smallest(self, k: usize) -> Vec<(N, K)> {
+        let mut smallest = Vec::with_capacity(k.min(self.len()));
+        smallest.extend(self.into_iter_sorted().take(k));
+        smallest
+    }
+
+    /// Consumes the heap and returns all of its (node, key) pairs in ascending order of keys.
+    ///
+    /// Elements are popped one by one; hence, the method requires *O(n log n)* time.
+    /// Elements with equal keys are returned in an arbitrary order;
+    /// [`into_sorted_vec_by`] can be used to break the ties deterministically.
+    ///
+    /// [`into_sorted_vec_by`]: Self::into_sorted_vec_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(vec![("y", 7), ("x", 42), ("z", 99)], queue.into_sorted_vec());
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<(N, K)> {
+        let mut sorted = Vec::with_capacity(self.len());
+        sorted.extend(self.into_iter_sorted());
+        sorted
+    }
+
+    /// Consumes the heap and returns all of its (node, key) pairs sorted by the comparator `cmp`,
+    /// which is called with pairs of (node, key) pairs.
+    ///
+    /// The elements are taken out of the heap without popping and sorted once with `cmp`, in the manner of
+    /// [`slice::sort_unstable_by`].
+    /// The comparator is expected to be consistent with the order of the keys, such as comparing the keys and
+    /// breaking the ties by the nodes, for the result to be the fully sorted sequence of the heap;
+    /// otherwise, the result is simply the elements of the heap sorted by `cmp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("z", 7);
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    ///
+    /// // keys collide; ties are broken by the nodes
+    /// let sorted = queue.into_sorted_vec_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+    /// assert_eq!(vec![("y", 7), ("z", 7), ("x", 42)], sorted);
+    /// ```
+    pub fn into_sorted_vec_by<F>(self, cmp: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&(N, K), &(N, K)) -> Ordering,
+    {
+        let mut elements = self.heap.into_elements();
+        elements.sort_unstable_by(cmp);
+        elements
+    }
+
+    /// Consumes the heap and returns a [`DaryHeap`] of the same nodes where each key is mapped by `f`.
+    /// Since the mapped keys are not tracked by node, the result is a [`DaryHeap`] which does not track positions of the nodes.
+    ///
+    /// The mapped heap is restored by a single bottom-up heapify; hence, `f` is not required to preserve the order of the keys
+    /// and the method requires *O(n)* time where n is the number of elements on the heap.
+    /// When `f` is known to be monotonically non-decreasing, [`map_keys_monotonic`] avoids the heapify.
+    ///
+    /// [`map_keys_monotonic`]: Self::map_keys_monotonic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    /// queue.push(2, 2);
+    ///
+    /// // reverses the order of the keys
+    /// let mut mapped = queue.map_keys(|key| 10 - key);
+    ///
+    /// assert_eq!(Ok(()), mapped.validate());
+    /// assert_eq!(Some((0, 7)), mapped.pop());
+    /// assert_eq!(Some((2, 8)), mapped.pop());
+    /// assert_eq!(Some((1, 9)), mapped.pop());
+    /// ```
+    pub fn map_keys<K2, F>(self, f: F) -> DaryHeap<N, K2, D>
+    where
+        K2: PartialOrd + Clone,
+        F: FnMut(K) -> K2,
+    {
+        DaryHeap::from_heap(self.heap.map_keys(f, false))
+    }
+
+    /// Consumes the heap and returns a [`DaryHeap`] of the same nodes where each key is mapped by the monotonic function `f`.
+    /// Since the mapped keys are not tracked by node, the result is a [`DaryHeap`] which does not track positions of the nodes.
+    ///
+    /// Since the order of the keys is preserved, the positions of the elements are kept as they are without a heapify.
+    /// This is useful for unit conversions on the keys such as converting seconds to milliseconds.
+    ///
+    /// `f` must be monotonically non-decreasing; i.e., `a <= b` must imply `f(a) <= f(b)` for all keys on the heap.
+    /// Otherwise, the returned heap is invalid and the order of the elements popped from it is unspecified;
+    /// [`map_keys`] must be used instead if `f` does not satisfy this requirement.
+    ///
+    /// [`map_keys`]: Self::map_keys
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(0usize, 3);
+    /// queue.push(1, 1);
+    /// queue.push(2, 2);
+    ///
+    /// // seconds to milliseconds
+    /// let mut mapped = queue.map_keys_monotonic(|seconds| seconds as f64 * 1000.0);
+    ///
+    /// assert_eq!(Ok(()), mapped.validate());
+    /// assert_eq!(Some((1, 1000.0)), mapped.pop());
+    /// assert_eq!(Some((2, 2000.0)), mapped.pop());
+    /// assert_eq!(Some((0, 3000.0)), mapped.pop());
+    /// ```
+    pub fn map_keys_monotonic<K2, F>(self, f: F) -> DaryHeap<N, K2, D>
+    where
+        K2: PartialOrd + Clone,
+        F: FnMut(K) -> K2,
+    {
+        DaryHeap::from_heap(self.heap.map_keys(f, true))
+    }
+
+    /// Consumes the heap and converts it into a [`DaryHeapOfIndices`] with the given `index_bound`,
+    /// which tracks the positions of the nodes in a fixed size array rather than in a map.
+    ///
+    /// The elements are moved to the new heap at the same positions; hence, no heapify is required
+    /// and the conversion requires *O(n + index_bound)* time to build the positions array.
+    ///
+    /// This is useful when the queue is built with the flexible map of positions, and the
+    /// faster [`DaryHeapOfIndices`] is preferred once the index bound of the nodes is known.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexOutOfBound`] error if the index of any node on the heap is greater than or equal to `index_bound`;
+    /// the error reports the largest index among the nodes so that `index + 1` is a sufficient bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push(3usize, 42);
+    /// queue.push(7, 7);
+    /// queue.push(1, 99);
+    ///
+    /// let error = queue.clone().into_indices(5);
+    /// assert_eq!(Some(IndexOutOfBound { index: 7, bound: 5 }), error.err());
+    ///
+    /// let mut queue = queue.into_indices(8).unwrap();
+    /// assert_eq!(8, queue.index_bound());
+    /// assert_eq!(Some(&42), queue.key_ref(&3));
+    ///
+    /// assert_eq!(Some((7, 7)), queue.pop());
+    /// assert_eq!(Some((3, 42)), queue.pop());
+    /// assert_eq!(Some((1, 99)), queue.pop());
+    /// ```
+    pub fn into_indices(
+        self,
+        index_bound: usize,
+    ) -> Result<DaryHeapOfIndices<N, K, D>, IndexOutOfBound>
+    where
+        N: HasIndex,
+    {
+        let max_index = self.as_slice().iter().map(|(node, _)| node.index()).max();
+        match max_index {
+            Some(index) if index >= index_bound => Err(IndexOutOfBound {
+                index,
+                bound: index_bound,
+            }),
+            _ => {
+                let positions = HeapPositionsHasIndex::with_index_bound(index_bound);
+                let heap = self.heap.into_heap_with_positions(positions);
+                Ok(DaryHeapOfIndices::from_heap(heap))
+            }
+        }
+    }
+
+    /// Deconstructs the heap into the vector of its tree and the positions of its nodes,
+    /// which can be used to recreate the heap by [`from_parts`](Self::from_parts) without copying the elements.
+    ///
+    /// The returned tree is the vector backing the heap, exactly as it is stored:
+    /// * it is empty if no element has been pushed to the heap since it is created, and otherwise,
+    /// * its first `offset` elements are padding which keep the children of the nodes aligned,
+    ///   where `offset` is [`heap_offset::<D>()`](crate::heap_offset); i.e., `D - 1` when `D` is a power of two not greater than 64, and 0 otherwise;
+    ///   the padding elements are copies of an arbitrary element and not among the elements of the heap,
+    /// * the remaining elements are the elements of the heap, equal to [`as_slice`](Self::as_slice),
+    ///   where the children of the element at position `offset + i` are at positions `offset + D * i + 1` to `offset + D * i + D`.
+    ///
+    /// The positions map each node on the heap to its position in the returned tree, including the offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = QuaternaryHeapWithMap::from([('a', 3), ('b', 1), ('c', 2)]);
+    /// let slice = queue.as_slice().to_vec();
+    ///
+    /// let (tree, positions) = queue.into_parts();
+    /// assert_eq!(3 + 3, tree.len());
+    /// assert_eq!(slice.as_slice(), &tree[3..]);
+    ///
+    /// let mut queue = unsafe { QuaternaryHeapWithMap::from_parts(tree, positions) };
+    /// assert_eq!(Some(&2), queue.key_ref(&'c'));
+    /// assert_eq!(Some(('b', 1)), queue.pop());
+    /// ```
+    pub fn into_parts(self) -> (Vec<(N, K)>, HeapPositionsMap<N, S>) {
+        self.heap.into_parts()
+    }
+
+    /// Creates the heap from the vector of its tree and the positions of its nodes as returned by [`into_parts`](Self::into_parts),
+    /// without copying or heapifying the elements.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the `tree` and the `positions` satisfy the invariants of the heap, which are
+    /// * the layout of the tree described in [`into_parts`](Self::into_parts),
+    /// * the heap property that the key of every element is not less than the key of its parent, and
+    /// * the positions hold exactly the nodes on the heap each at its position in the `tree`, including the offset.
+    ///
+    /// These are always satisfied by the parts returned by `into_parts` as long as no element is inserted, removed
+    /// or moved, and no key is changed in a way that breaks the heap property.
+    ///
+    /// The invariants are validated only in debug builds;
+    /// when they are violated, the heap may return elements out of order, fail to find its nodes or panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = QuaternaryHeapWithMap::from([('a', 3), ('b', 1), ('c', 2)]);
+    ///
+    /// let (mut tree, positions) = queue.into_parts();
+    ///
+    /// // increasing all keys preserves the heap property
+    /// tree.iter_mut().for_each(|(_, key)| *key += 10);
+    ///
+    /// let mut queue = unsafe { QuaternaryHeapWithMap::from_parts(tree, positions) };
+    /// assert_eq!(Ok(()), queue.validate());
+    /// assert_eq!(Some(&12), queue.key_ref(&'c'));
+    /// assert_eq!(Some(('b', 11)), queue.pop());
+    /// ```
+    pub unsafe fn from_parts(tree: Vec<(N, K)>, positions: HeapPositionsMap<N, S>) -> Self {
+        Self::from_heap(Heap::from_parts(tree, positions))
+    }
+
+    pub(crate) fn from_heap(heap: Heap<N, K, HeapPositionsMap<N, S>, D>) -> Self {
+        Self { heap }
+    }
+
+    /// Pops up to `n` elements from the queue and returns them in ascending order of keys;
+    /// stops early if the queue becomes empty.
+    ///
+    /// The elements are popped one by one and come out sorted;
+    /// hence, the method requires *O(n log m)* time where m is the length of the queue, without any additional sorting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    /// queue.push("w", 21);
+    ///
+    /// let batch = queue.pop_n(2);
+    /// assert_eq!(vec![("y", 7), ("w", 21)], batch);
+    /// assert!(!queue.contains(&"y"));
+    ///
+    /// let batch = queue.pop_n(16);
+    /// assert_eq!(vec![("x", 42), ("z", 99)], batch);
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn pop_n(&mut self, n: usize) -> Vec<(N, K)> {
+        self.heap.pop_n(n)
+    }
+
+    /// Pops the element with the minimum key together with all elements whose keys are equal to it;
+    /// returns an empty vector if the queue is empty.
+    ///
+    /// Popping stops at the first element whose key is not equal to the minimum key.
+    /// The order of the elements within the returned group is unspecified.
+    ///
+    /// Keys are compared by `PartialEq`; hence, float keys are grouped only if they are exactly equal,
+    /// keys which differ by a rounding error fall into different groups and a NaN key is never grouped with another key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 7);
+    /// queue.push("y", 3);
+    /// queue.push("z", 3);
+    ///
+    /// let mut group = queue.pop_min_group();
+    /// group.sort();
+    /// assert_eq!(vec![("y", 3), ("z", 3)], group);
+    /// assert!(!queue.contains(&"y"));
+    ///
+    /// assert_eq!(vec![("x", 7)], queue.pop_min_group());
+    /// assert!(queue.is_empty());
+    /// assert!(queue.pop_min_group().is_empty());
+    /// ```
+    pub fn pop_min_group(&mut self) -> Vec<(N, K)> {
+        self.heap.pop_min_group()
+    }
+
+    /// Repeatedly pops the foremost element while it satisfies the predicate `f`,
+    /// and returns the popped elements in ascending order of their keys.
+    ///
+    /// The predicate is evaluated on the foremost element before each pop; popping stops at the first element
+    /// for which `f(node, key)` returns false, which is left on the queue.
+    /// This is the drain pattern of schedulers and timer wheels, such as popping all tasks whose deadlines have passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("b", 12);
+    /// queue.push("a", 3);
+    /// queue.push("c", 10);
+    /// queue.push("d", 42);
+    ///
+    /// let now = 10;
+    /// let expired = queue.pop_while(|_, deadline| *deadline <= now);
+    /// assert_eq!(expired, [("a", 3), ("c", 10)]);
+    /// assert!(!queue.contains(&"a"));
+    /// assert_eq!(Some(&("b", 12)), queue.peek());
+    /// ```
+    pub fn pop_while<F>(&mut self, f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.pop_while(f)
+    }
+
+    /// Returns an iterator which pops the (node, key) pairs of the heap in ascending order of keys.
+    ///
+    /// The iterator is lazy; each call to `next` pops the element with the lowest key.
+    /// Once the iterator is dropped, the heap is empty even if the iterator is not fully consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let mut drain = queue.drain_sorted();
+    /// assert_eq!(Some(("y", 7)), drain.next());
+    /// drop(drain);
+    ///
+    /// assert!(queue.is_empty());
+    /// assert!(!queue.contains(&"x"));
+    /// ```
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, N, K, D> {
+        self.heap.drain_sorted()
+    }
+
+    /// Returns an iterator which removes all (node, key) pairs of the heap in an arbitrary order.
+    ///
+    /// Unlike `drain_sorted`, the elements are yielded in the order of the underlying tree without any sift cost;
+    /// hence, draining all elements requires linear time rather than the O(n log n) time of repeated `pop` calls.
+    /// This is useful when the elements are to be consumed regardless of their order, such as to reclaim owned nodes.
+    /// Once the iterator is dropped, the heap is empty even if the iterator is not fully consumed.
+    ///
+    /// Positions of the nodes are cleared; i.e., `contains` returns false for all drained nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let mut drained: Vec<_> = queue.drain().collect();
+    /// drained.sort();
+    /// assert_eq!(3, drained.len());
+    ///
+    /// assert!(queue.is_empty());
+    /// assert!(!queue.contains(&"x"));
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, N, K> {
+        self.heap.drain()
+    }
+
+    /// Pushes all (node, key) pairs of the `items` to the queue.
+    ///
+    /// Rather than heapifying each element on insertion, the elements are first appended to the end of the tree.
+    /// Then, the heap property is restored depending on the size of the batch relative to the current length of the queue:
+    /// * if the batch contains at least as many elements as the queue held before the call,
+    ///   the entire tree is rebuilt with a single bottom-up heapify in *O(n)* time;
+    /// * otherwise, each appended element is sifted up, which is equivalent to pushing the elements one by one.
+    ///
+    /// Positions of all inserted nodes are tracked as in `push`.
+    /// Since the nodes of the queue form a set, each node must be absent from the queue and appear at most once in the `items`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push('a', 3);
+    ///
+    /// queue.push_all([('b', 1), ('c', 4), ('d', 0)]);
+    /// assert_eq!(4, queue.len());
+    /// assert_eq!(Some(&4), queue.key_ref(&'c'));
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [('d', 0), ('b', 1), ('a', 3), ('c', 4)]);
+    /// ```
+    pub fn push_all<I>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        self.extend(items)
+    }
+
+    /// Moves all elements of `other` into this heap, leaving `other` empty.
+    ///
+    /// Elements are moved without being heapified one by one;
+    /// the heap property is then restored once on the combined tree,
+    /// which is cheaper than popping from `other` and pushing to this heap.
+    /// Allocation of `other` is retained for reuse.
+    ///
+    /// Since a node can exist at most once in the queue,
+    /// a node which is in both heaps is kept with the smaller of its two keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    ///
+    /// let mut other = QuaternaryHeapWithMap::default();
+    /// other.push('a', 0);
+    /// other.push('b', 2);
+    /// other.push('c', 4);
+    ///
+    /// queue.append(&mut other);
+    /// assert!(other.is_empty());
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [('a', 0), ('b', 1), ('c', 4)]);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.heap.append(&mut other.heap)
+    }
+
+    /// Moves all elements of `other` into this heap, consuming `other`.
+    ///
+    /// Unlike `append`, `other` is taken by value; and hence, its allocation is not retained.
+    ///
+    /// Since a node can exist at most once in the queue,
+    /// a node which is in both heaps is kept with the smaller of its two keys.
+    ///
+    /// # Complexity
+    ///
+    /// Being valid heaps, both trees are already partially ordered, which is meant to be exploited by a
+    /// specialized merge in the future.
+    /// Currently, elements of `other` are moved to the end of the tree and the heap property is restored once
+    /// as in `append`; this requires O(n + m) time when `other` is at least as large as this heap, where n and m
+    /// are the lengths of this heap and `other`, respectively, and O(m log(n + m)) time otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    ///
+    /// let mut other = QuaternaryHeapWithMap::default();
+    /// other.push('a', 0);
+    /// other.push('b', 2);
+    /// other.push('c', 4);
+    ///
+    /// queue.append_sorted(other);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [('a', 0), ('b', 1), ('c', 4)]);
+    /// ```
+    pub fn append_sorted(&mut self, other: Self) {
+        self.heap.append_sorted(other.heap)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, removes all (node, key) pairs for which `f(&node, &key)` returns false.
+    /// The heap property is restored once after all removals, which requires linear time.
+    /// No heapify is required and the heap is not modified if all elements are retained.
+    /// For heaps with positions, positions of the remaining nodes are updated accordingly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// (0..10).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// queue.retain(|node, _| node % 3 == 0);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (3, 30), (6, 60), (9, 90)]);
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.retain(f)
+    }
+
+    /// Retains only the elements specified by the predicate, which may also mutate the keys of the elements.
+    ///
+    /// In other words, removes all (node, key) pairs for which `f(&node, &mut key)` returns false;
+    /// and the keys of the retained elements are updated as mutated by `f`.
+    /// This allows to both evict elements and adjust the keys of the survivors in a single pass,
+    /// such as decaying priorities while dropping the expired ones.
+    ///
+    /// The heap property is restored once after all removals and mutations by a bottom-up heapify;
+    /// hence, the method requires *O(n)* time.
+    /// Unlike [`retain`], the heap is heapified even if all elements are retained, since their keys might have changed.
+    ///
+    /// [`retain`]: Self::retain
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// (0..10).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// // drops the odd nodes, and reverses the order of the keys of the others
+    /// queue.retain_mut(|node, key| {
+    ///     *key = 100 - *key;
+    ///     node % 2 == 0
+    /// });
+    ///
+    /// assert!(!queue.contains(&3));
+    /// assert_eq!(Some(&60), queue.key_ref(&4));
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(8, 20), (6, 40), (4, 60), (2, 80), (0, 100)]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&N, &mut K) -> bool,
+    {
+        self.heap.retain_mut(f)
+    }
+
+    /// Removes all elements for which `f(&node, &key)` returns true from the queue, and returns them.
+    ///
+    /// The order of the returned (node, key) pairs is unspecified.
+    /// Unlike [`retain`], the removed elements are returned, which allows to partition the queue into kept and evicted elements in one pass;
+    /// hence, it corresponds to the `drain_filter` (`extract_if`) operation of the standard collections.
+    ///
+    /// [`retain`]: Self::retain
+    ///
+    /// The heap property is restored once after all removals;
+    /// hence, the method requires linear time regardless of the number of removed elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithMap::default();
+    /// (0..10).for_each(|x| queue.push(x, x * 10));
+    ///
+    /// let mut removed = queue.remove_if(|_, key| *key >= 50);
+    /// removed.sort();
+    /// assert_eq!(removed, [(5, 50), (6, 60), (7, 70), (8, 80), (9, 90)]);
+    ///
+    /// let popped: Vec<_> = queue.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+    /// ```
+    #[doc(alias = "drain_filter")]
+    #[doc(alias = "extract_if")]
+    pub fn remove_if<F>(&mut self, f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.heap.remove_if(f)
+    }
+
+    /// Splits the queue at the given `threshold`: removes all elements with keys greater than or equal to the
+    /// `threshold` into a new queue which is returned, leaving the elements with smaller keys in this queue.
+    ///
+    /// The tree is scanned once to partition the elements, and the heap property of each of the two queues
+    /// is restored by a single bottom-up heapify; hence, the method requires linear time.
+    /// Elements whose keys are not comparable to the `threshold`, such as NaN keys, remain in this queue.
+    ///
+    /// The returned queue hashes its nodes by a clone of the hash builder of this queue;
+    /// and the positions of the nodes of both queues are rebuilt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut urgent = QuaternaryHeapWithMap::default();
+    /// (0..10).for_each(|x| urgent.push(x, x * 10));
+    ///
+    /// let later = urgent.split_off_ge(50);
+    /// assert!(!urgent.contains(&7));
+    /// assert!(later.contains(&7));
+    ///
+    /// let popped: Vec<_> = urgent.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+    ///
+    /// let popped: Vec<_> = later.into_iter_sorted().collect();
+    /// assert_eq!(popped, [(5, 50), (6, 60), (7, 70), (8, 80), (9, 90)]);
+    /// ```
+    pub fn split_off_ge(&mut self, threshold: K) -> Self {
+        let positions = self.heap.positions().empty_with_same_hasher(0);
+        Self {
+            heap: self.heap.split_off_ge(&threshold, positions),
+        }
+    }
+}
+
+impl<N, K, const D: usize, S> PriorityQueue<N, K> for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder,
+{
+    type NodeKey<'a> = &'a (N, K) where Self: 'a, N: 'a, K: 'a;
+    type Iter<'a> = core::slice::Iter<'a, (N, K)> where Self: 'a, N: 'a, K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.heap.peek()
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop()
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node()
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key()
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        self.heap.push(node, key)
+    }
+
+    #[inline(always)]
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        self.heap.push_then_pop(node, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
+
+/// Two heaps are equal if they contain the same (node, key) pairs, regardless of the layouts of their trees;
+/// i.e., if they would pop identical sequences up to the order of equal keys.
+///
+/// Since the nodes of each queue form a set, the comparison looks up the position of each node of one heap on the other
+/// and requires *O(n)* time.
+impl<N, K, const D: usize, S> PartialEq for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let other_slice = other.as_slice();
+        self.len() == other.len()
+            && self.as_slice().iter().all(|(node, key)| {
+                other
+                    .position_of(node)
+                    .is_some_and(|position| &other_slice[position].1 == key)
+            })
+    }
+}
+
+impl<N, K, const D: usize, S> Eq for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone + Eq,
+    S: HashBuilder,
+{
+}
+
+/// Hashes the heap as the multiset of its (node, key) pairs, consistently with `PartialEq`;
+/// the element hashes are sorted to be independent of the layout of the tree, which requires *O(n log n)* time.
+///
+/// Similar to the equality, the hasher of the positions map does not affect the hash.
+impl<N, K, const D: usize, S> Hash for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index + Hash,
+    K: PartialOrd + Clone + Hash,
+    S: HashBuilder,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.heap.hash_elements(state)
+    }
+}
+
+/// Creates a queue of the (node, key) pairs of the iterator with a single bottom-up heapify.
+///
+/// Since the nodes of the queue form a set, a node appearing more than once in the iterator
+/// enters the queue once with the smallest of its keys.
+/// In other words, the result is the same as calling `try_decrease_key_or_push` for each pair on an empty queue.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let edges = [('a', 4), ('b', 3), ('a', 1), ('c', 7), ('b', 5)];
+/// let mut queue: QuaternaryHeapWithMap<_, _> = edges.into_iter().collect();
+///
+/// assert_eq!(3, queue.len());
+/// assert_eq!(Some(('a', 1)), queue.pop());
+/// assert_eq!(Some(('b', 3)), queue.pop());
+/// assert_eq!(Some(('c', 7)), queue.pop());
+/// ```
+impl<N, K, const D: usize, S> FromIterator<(N, K)> for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (N, K)>>(iter: I) -> Self {
+        let mut queue = Self::default();
+        queue.heap.extend_keep_min(iter);
+        queue
+    }
+}
+
+/// Creates a queue of the given (node, key) pairs with a single bottom-up heapify in *O(n)* time.
+///
+/// Since the nodes of the queue form a set, a node appearing more than once among the elements
+/// enters the queue once with the smallest of its keys, as in the `FromIterator` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = BinaryHeapWithMap::from(vec![('a', 5.0), ('b', 2.0), ('a', 3.0)]);
+///
+/// assert_eq!(2, queue.len());
+/// assert_eq!(Some(('b', 2.0)), queue.pop());
+/// assert_eq!(Some(('a', 3.0)), queue.pop());
+/// ```
+impl<N, K, const D: usize, S> From<Vec<(N, K)>> for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder + Default,
+{
+    fn from(elements: Vec<(N, K)>) -> Self {
+        Self::from_iter(elements)
+    }
+}
+
+/// Creates a queue of the given (node, key) pairs with a single bottom-up heapify in *O(n)* time.
+///
+/// Since the nodes of the queue form a set, a node appearing more than once among the elements
+/// enters the queue once with the smallest of its keys, as in the `FromIterator` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = QuaternaryHeapWithMap::from([("x", 42), ("y", 7), ("x", 3), ("z", 99)]);
+///
+/// assert_eq!(3, queue.len());
+/// assert_eq!(Some(&3), queue.key_ref("x"));
+/// assert_eq!(Some(("x", 3)), queue.pop());
+/// assert_eq!(Some(("y", 7)), queue.pop());
+/// assert_eq!(Some(("z", 99)), queue.pop());
+/// ```
+impl<N, K, const D: usize, S, const M: usize> From<[(N, K); M]> for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder + Default,
+{
+    fn from(elements: [(N, K); M]) -> Self {
+        Self::from_iter(elements)
+    }
+}
+
+/// Pushes all (node, key) pairs of the iterator to the queue.
+///
+/// Since the nodes of the queue form a set, a node which is already in the queue or appears more than once in the iterator
+/// is kept once with the smallest of its keys, as in the `FromIterator` implementation.
+///
+/// Both the heap and the map of positions of the nodes are pre-sized by the lower bound of the `size_hint` of the iterator,
+/// which avoids incremental rehashes of the map while loading many nodes.
+impl<N, K, const D: usize, S> Extend<(N, K)> for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        self.heap.extend_keep_min(iter)
+    }
+}
+impl<N, K, const D: usize, S> PriorityQueueDecKey<N, K> for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index,
+    K: PartialOrd + Clone,
+    S: HashBuilder,
+{
+    #[inline(always)]
+    fn contains(&self, node: &N) -> bool {
+        self.heap.contains(node)
+    }
+
+    #[inline(always)]
+    fn key_of(&self, node: &N) -> Option<K> {
+        self.heap.key_of(node)
+    }
+
+    #[inline(always)]
+    fn key_ref(&self, node: &N) -> Option<&K> {
+        self.heap.key_ref(node)
+    }
+
+    #[inline(always)]
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K {
+        self.heap.decrease_key(node, decreased_key)
+    }
+
+    #[inline(always)]
+    fn decrease_key_ref(&mut self, node: &N, decreased_key: &K) -> K {
+        self.heap.decrease_key_ref(node, decreased_key)
+    }
+
+    #[inline(always)]
+    fn decrease_key_with<F>(&mut self, node: &N, decrease: F) -> K
+    where
+        F: FnOnce(&K) -> K,
+    {
+        self.heap.decrease_key_with(node, decrease)
+    }
+
+    #[inline(always)]
+    fn decrease_key_all<I>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        self.heap.decrease_key_all(updates)
+    }
+
+    #[inline(always)]
+    fn increase_key(&mut self, node: &N, increased_key: K) {
+        self.heap.increase_key(node, increased_key)
+    }
+
+    #[inline(always)]
+    fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
+        self.heap.update_key(node, new_key)
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, node: &N) -> K {
+        self.heap.remove(node)
+    }
+
+    #[inline(always)]
+    fn take(&mut self, node: &N) -> Option<(N, K)> {
+        self.heap.take(node)
+    }
+
+    #[inline(always)]
+    fn update_key_if<F>(&mut self, node: &N, new_key: K, should_update: F) -> bool
+    where
+        F: FnOnce(&K, &K) -> bool,
+    {
+        self.heap.update_key_if(node, new_key, should_update)
+    }
+}
+
+/// Serializes the heap as the sequence of its (node, key) pairs.
+#[cfg(feature = "serde")]
+impl<N, K, const D: usize, S> serde::Serialize for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index + serde::Serialize,
+    K: PartialOrd + Clone + serde::Serialize,
+    S: HashBuilder,
+{
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+/// Deserializes the heap from the sequence of its (node, key) pairs.
+///
+/// Fails if a node appears more than once in the sequence.
+#[cfg(feature = "serde")]
+impl<'de, N, K, const D: usize, S> serde::Deserialize<'de> for DaryHeapWithMap<N, K, D, S>
+where
+    N: Index + serde::Deserialize<'de>,
+    K: PartialOrd + Clone + serde::Deserialize<'de>,
+    S: HashBuilder + Default,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let elements = Vec::<(N, K)>::deserialize(deserializer)?;
+        let positions = HeapPositionsMap::with_capacity(elements.len());
+        let heap = Heap::from_elements(elements, positions)
+            .ok_or_else(|| serde::de::Error::custom("duplicate node"))?;
+        Ok(Self { heap })
     }
 }
@@ -0,0 +1,426 @@
+use super::daryheap_const_helpers::level_of;
+use crate::{HeapError, PriorityQueue};
+use alloc::vec::Vec;
+
+/// A double-ended d-ary heap providing both the element with the smallest key and the element with the largest key
+/// in constant time, and removing either of them in *O(log n)* time.
+///
+/// This is the d-ary variant of the min-max heap where the levels of the tree alternate between min and max levels,
+/// starting with the min level of the root:
+/// * the key of an element on a min level is less than or equal to the keys of all of its descendants, and
+/// * the key of an element on a max level is greater than or equal to the keys of all of its descendants.
+///
+/// Therefore, the root is the element with the smallest key, and the element with the largest key is one of the
+/// children of the root.
+///
+/// The tree is laid out as a vector of (node, key) pairs where the root is at position 0 and the children of the
+/// element at position p are at positions `D * p + 1` to `D * p + D`.
+///
+/// `DaryMinMaxHeap` implements [`PriorityQueue`] by its min side; i.e., `peek` and `pop` are equivalent to
+/// [`peek_min`](Self::peek_min) and [`pop_min`](Self::pop_min).
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = DaryMinMaxHeap::<_, _, 4>::default();
+///
+/// for (node, key) in [('a', 5), ('b', 2), ('c', 8), ('d', 1), ('e', 7)] {
+///     queue.push(node, key);
+/// }
+///
+/// assert_eq!(Some(&('d', 1)), queue.peek_min());
+/// assert_eq!(Some(&('c', 8)), queue.peek_max());
+///
+/// assert_eq!(Some(('d', 1)), queue.pop_min());
+/// assert_eq!(Some(('c', 8)), queue.pop_max());
+/// assert_eq!(Some(('e', 7)), queue.pop_max());
+/// assert_eq!(Some(('b', 2)), queue.pop_min());
+/// assert_eq!(Some(('a', 5)), queue.pop_max());
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DaryMinMaxHeap<N, K, const D: usize = 2>
+where
+    K: PartialOrd,
+{
+    tree: Vec<(N, K)>,
+}
+
+impl<N, K, const D: usize> Default for DaryMinMaxHeap<N, K, D>
+where
+    K: PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, K, const D: usize> DaryMinMaxHeap<N, K, D>
+where
+    K: PartialOrd,
+{
+    const ASSERT_VALID_D: () = assert!(D >= 2, "d-ary heap requires D >= 2");
+
+    /// Creates a new empty d-ary min-max heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = DaryMinMaxHeap::<_, _, 4>::new();
+    ///
+    /// queue.push('a', 4);
+    /// queue.push('b', 42);
+    ///
+    /// assert_eq!(Some(('a', 4)), queue.pop_min());
+    /// assert_eq!(Some(('b', 42)), queue.pop_max());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        let () = Self::ASSERT_VALID_D;
+        Self { tree: Vec::new() }
+    }
+
+    /// Creates a new d-ary min-max heap with the given initial `capacity` on the number of nodes to simultaneously exist on the heap.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let () = Self::ASSERT_VALID_D;
+        Self {
+            tree: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Returns the nodes and keys currently in the queue as a slice;
+    /// not necessarily sorted.
+    pub fn as_slice(&self) -> &[(N, K)] {
+        &self.tree
+    }
+
+    /// Returns the element with the smallest key; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = DaryMinMaxHeap::<_, _, 4>::new();
+    /// assert_eq!(None, queue.peek_min());
+    ///
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    /// queue.push('c', 7);
+    /// assert_eq!(Some(&('b', 1)), queue.peek_min());
+    /// ```
+    pub fn peek_min(&self) -> Option<&(N, K)> {
+        self.tree.first()
+    }
+
+    /// Returns the element with the largest key; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = DaryMinMaxHeap::<_, _, 4>::new();
+    /// assert_eq!(None, queue.peek_max());
+    ///
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    /// queue.push('c', 7);
+    /// assert_eq!(Some(&('c', 7)), queue.peek_max());
+    /// ```
+    pub fn peek_max(&self) -> Option<&(N, K)> {
+        self.max_position().map(|position| &self.tree[position])
+    }
+
+    /// Removes and returns the element with the smallest key; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = DaryMinMaxHeap::<_, _, 4>::new();
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    ///
+    /// assert_eq!(Some(('b', 1)), queue.pop_min());
+    /// assert_eq!(Some(('a', 3)), queue.pop_min());
+    /// assert_eq!(None, queue.pop_min());
+    /// ```
+    pub fn pop_min(&mut self) -> Option<(N, K)> {
+        match self.tree.is_empty() {
+            true => None,
+            false => Some(self.remove_at(0)),
+        }
+    }
+
+    /// Removes and returns the element with the largest key; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = DaryMinMaxHeap::<_, _, 4>::new();
+    /// queue.push('a', 3);
+    /// queue.push('b', 1);
+    ///
+    /// assert_eq!(Some(('a', 3)), queue.pop_max());
+    /// assert_eq!(Some(('b', 1)), queue.pop_max());
+    /// assert_eq!(None, queue.pop_max());
+    /// ```
+    pub fn pop_max(&mut self) -> Option<(N, K)> {
+        self.max_position().map(|position| self.remove_at(position))
+    }
+
+    /// Validates the heap; i.e., checks whether or not the key of every element is greater than or equal to the key of
+    /// its parent and less than or equal to the key of its grandparent when it is on a max level, and vice versa when
+    /// it is on a min level.
+    ///
+    /// Returns the first violated invariant as a [`HeapError::MinMaxOrder`], if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = DaryMinMaxHeap::<_, _, 4>::new();
+    /// for (node, key) in [('a', 3), ('b', 1), ('c', 7), ('d', 5)] {
+    ///     queue.push(node, key);
+    /// }
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        for position in 1..self.tree.len() {
+            let is_min = is_min_level::<D>(position);
+            let key = &self.tree[position].1;
+
+            let parent = parent_of::<D>(position);
+            if precedes(key, &self.tree[parent].1, !is_min) {
+                return Err(HeapError::MinMaxOrder {
+                    ancestor: parent,
+                    descendant: position,
+                });
+            }
+
+            if parent > 0 {
+                let grandparent = parent_of::<D>(parent);
+                if precedes(key, &self.tree[grandparent].1, is_min) {
+                    return Err(HeapError::MinMaxOrder {
+                        ancestor: grandparent,
+                        descendant: position,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // helpers
+    /// Returns the position of the element with the largest key, which is either the root or one of its children.
+    fn max_position(&self) -> Option<usize> {
+        match self.tree.len() {
+            0 => None,
+            1 => Some(0),
+            len => Some(self.best_of(1..(D + 1).min(len), false)),
+        }
+    }
+
+    /// Returns the position of the element which comes first among the given `positions` with respect to the order
+    /// of the min level if `is_min`, and of the max level otherwise; the first such position wins the ties.
+    fn best_of(&self, mut positions: impl Iterator<Item = usize>, is_min: bool) -> usize {
+        let first = positions.next().expect("positions is not empty");
+        positions.fold(first, |best, position| {
+            match precedes(&self.tree[position].1, &self.tree[best].1, is_min) {
+                true => position,
+                false => best,
+            }
+        })
+    }
+
+    /// Removes the element at the `position`, which must be either the root or the max element, and fills the hole
+    /// with the last element.
+    fn remove_at(&mut self, position: usize) -> (N, K) {
+        let removed = self.tree.swap_remove(position);
+        if position < self.tree.len() {
+            self.trickle_down(position);
+        }
+        removed
+    }
+
+    /// Moves the element at the `position`, which has just been placed at the end of the tree, up the tree.
+    fn bubble_up(&mut self, position: usize) {
+        if position == 0 {
+            return;
+        }
+
+        let is_min = is_min_level::<D>(position);
+        let parent = parent_of::<D>(position);
+        match precedes(&self.tree[position].1, &self.tree[parent].1, !is_min) {
+            true => {
+                self.tree.swap(position, parent);
+                self.bubble_up_grandparents(parent, !is_min);
+            }
+            false => self.bubble_up_grandparents(position, is_min),
+        }
+    }
+
+    /// Moves the element at the `position` up among the levels of the same kind, which are `is_min` levels.
+    fn bubble_up_grandparents(&mut self, mut position: usize, is_min: bool) {
+        while position > D {
+            let grandparent = parent_of::<D>(parent_of::<D>(position));
+            match precedes(&self.tree[position].1, &self.tree[grandparent].1, is_min) {
+                true => {
+                    self.tree.swap(position, grandparent);
+                    position = grandparent;
+                }
+                false => break,
+            }
+        }
+    }
+
+    /// Moves the element at the `position` down the tree among the levels of the same kind,
+    /// fixing the order with the levels of the other kind in between.
+    fn trickle_down(&mut self, mut position: usize) {
+        let is_min = is_min_level::<D>(position);
+        let len = self.tree.len();
+
+        loop {
+            let first_child = left_child_of::<D>(position);
+            if first_child >= len {
+                break;
+            }
+            let last_child = (first_child + D).min(len);
+
+            let best_child = self.best_of(first_child..last_child, is_min);
+            let first_grandchild = left_child_of::<D>(first_child);
+            let best = match first_grandchild < len {
+                true => {
+                    let last_grandchild = left_child_of::<D>(last_child - 1) + D;
+                    let grandchildren = first_grandchild..last_grandchild.min(len);
+                    let best_grandchild = self.best_of(grandchildren, is_min);
+                    self.best_of([best_child, best_grandchild].into_iter(), is_min)
+                }
+                false => best_child,
+            };
+
+            if !precedes(&self.tree[best].1, &self.tree[position].1, is_min) {
+                break;
+            }
+            self.tree.swap(best, position);
+
+            if best == best_child {
+                break;
+            }
+
+            let parent = parent_of::<D>(best);
+            if precedes(&self.tree[parent].1, &self.tree[best].1, is_min) {
+                self.tree.swap(best, parent);
+            }
+            position = best;
+        }
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueue<N, K> for DaryMinMaxHeap<N, K, D>
+where
+    K: PartialOrd,
+{
+    type NodeKey<'a> = &'a (N, K) where Self: 'a, N: 'a, K: 'a;
+    type Iter<'a> = core::slice::Iter<'a, (N, K)> where Self: 'a, N: 'a, K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.tree.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.peek_min()
+    }
+
+    fn clear(&mut self) {
+        self.tree.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.pop_min()
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.pop_min().map(|x| x.0)
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.pop_min().map(|x| x.1)
+    }
+
+    fn push(&mut self, node: N, key: K) {
+        self.tree.push((node, key));
+        self.bubble_up(self.tree.len() - 1);
+    }
+
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        match self.tree.first() {
+            Some((_, min)) if min < &key => {
+                let popped = core::mem::replace(&mut self.tree[0], (node, key));
+                self.trickle_down(0);
+                popped
+            }
+            _ => (node, key),
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.tree.iter()
+    }
+}
+
+/// Returns whether or not the key `a` strictly precedes the key `b` in the order of a min level if `is_min`,
+/// and of a max level otherwise.
+#[inline(always)]
+fn precedes<K: PartialOrd>(a: &K, b: &K, is_min: bool) -> bool {
+    match is_min {
+        true => a < b,
+        false => a > b,
+    }
+}
+
+/// Returns whether or not the `position` is on a min level of the tree rooted at position 0.
+#[inline(always)]
+fn is_min_level<const D: usize>(position: usize) -> bool {
+    level_of::<D>(position).is_multiple_of(2)
+}
+
+#[inline(always)]
+const fn parent_of<const D: usize>(child: usize) -> usize {
+    (child - 1) / D
+}
+
+#[inline(always)]
+const fn left_child_of<const D: usize>(parent: usize) -> usize {
+    D * parent + 1
+}
@@ -0,0 +1,390 @@
+use super::{heap::Heap, iter_mut::IterMut, iter_sorted::IterSorted, peek_mut::PeekMut};
+use crate::{positions::none::HeapPositionsNone, HeapError, PriorityQueue};
+use smallvec::SmallVec;
+
+type SmallTree<N, K, const INLINE: usize> = SmallVec<[(N, K); INLINE]>;
+
+/// A d-ary heap which implements `PriorityQueue`, but not `PriorityQueueDecKey`, storing its tree in a `SmallVec`
+/// rather than in a `Vec`; requires `--features smallvec`.
+///
+/// The tree is stored inline, without any heap allocation, until it grows beyond `INLINE` elements;
+/// it then spills over to a heap allocated buffer just as the `Vec` of a [`DaryHeap`](crate::DaryHeap).
+/// The sift operations are identical to those of [`DaryHeap`](crate::DaryHeap).
+///
+/// This is useful for many small queues which rarely exceed a known length, where the allocation of the tree
+/// would otherwise dominate the cost of the queue operations.
+///
+/// Note that the tree of a d-ary heap also holds the offset which aligns the children of the nodes;
+/// the offset is `D - 1` when `D` is a power of two not greater than 64, and 0 otherwise.
+/// Therefore, the heap stays inline as long as its length plus the offset does not exceed `INLINE`.
+/// For instance, a `SmallDaryHeap<N, K, 2, 16>` holds up to 15 elements without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = SmallDaryHeap::<usize, f64, 2, 16>::new();
+///
+/// for node in 0..15 {
+///     queue.push(node, 100.0 - node as f64);
+/// }
+/// assert!(!queue.spilled());
+///
+/// queue.push(15, 0.0);
+/// assert!(queue.spilled());
+///
+/// assert_eq!(Some((15, 0.0)), queue.pop());
+/// assert_eq!(Some((14, 86.0)), queue.pop());
+/// ```
+#[derive(Debug)]
+pub struct SmallDaryHeap<N, K, const D: usize, const INLINE: usize>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    heap: Heap<N, K, HeapPositionsNone, D, SmallTree<N, K, INLINE>>,
+}
+
+impl<N, K, const D: usize, const INLINE: usize> Clone for SmallDaryHeap<N, K, D, INLINE>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.heap.clone_from(&source.heap);
+    }
+}
+
+impl<N, K, const D: usize, const INLINE: usize> Default for SmallDaryHeap<N, K, D, INLINE>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        Self {
+            heap: Heap::new(None, HeapPositionsNone),
+        }
+    }
+}
+
+impl<N, K, const D: usize, const INLINE: usize> SmallDaryHeap<N, K, D, INLINE>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    /// Creates a new empty d-ary heap storing its tree inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut heap = SmallDaryHeap::<_, _, 4, 8>::new();
+    ///
+    /// heap.push('a', 4);
+    /// heap.push('b', 42);
+    ///
+    /// assert_eq!(Some('a'), heap.pop_node());
+    /// assert_eq!(Some('b'), heap.pop_node());
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new d-ary heap with the given initial `capacity` on the number of nodes to simultaneously exist on the heap.
+    ///
+    /// The tree is allocated on the heap right away if the `capacity` plus the offset of the tree exceeds `INLINE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let queue = SmallDaryHeap::<char, i32, 4, 8>::with_capacity(4);
+    /// assert!(!queue.spilled());
+    ///
+    /// let queue = SmallDaryHeap::<char, i32, 4, 8>::with_capacity(100);
+    /// assert!(queue.spilled());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: Heap::new(Some(capacity), HeapPositionsNone),
+        }
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Returns whether or not the tree of the heap has spilled over from its inline storage to a heap allocated buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<char, i32, 3, 2>::new();
+    ///
+    /// queue.push('a', 1);
+    /// queue.push('b', 2);
+    /// assert!(!queue.spilled());
+    ///
+    /// queue.push('c', 3);
+    /// assert!(queue.spilled());
+    /// ```
+    pub fn spilled(&self) -> bool {
+        self.heap.tree().spilled()
+    }
+
+    /// Returns the nodes and keys currently in the queue as a slice;
+    /// not necessarily sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let slice = queue.as_slice();
+    ///
+    /// assert_eq!(3, slice.len());
+    /// assert!(slice.contains(&("x", 42)));
+    /// assert!(slice.contains(&("y", 7)));
+    /// assert!(slice.contains(&("z", 99)));
+    /// ```
+    pub fn as_slice(&self) -> &[(N, K)] {
+        self.heap.as_slice()
+    }
+
+    /// Validates the heap; i.e., checks whether or not the key of every element is greater than or equal to
+    /// the key of its parent on the tree.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be pushed to the heap;
+    /// spills the tree over to a heap allocated buffer if the inline storage is not sufficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<char, i32, 4, 8>::new();
+    /// queue.reserve(100);
+    /// assert!(queue.capacity() >= 100);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.heap.reserve(additional)
+    }
+
+    /// Shrinks the capacity of the heap as much as possible;
+    /// moves the tree back to the inline storage if it fits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<char, i32, 4, 8>::with_capacity(100);
+    /// queue.push('a', 1);
+    /// assert!(queue.spilled());
+    ///
+    /// queue.shrink_to_fit();
+    /// assert!(!queue.spilled());
+    /// assert_eq!(Some(('a', 1)), queue.pop());
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.heap.shrink_to_fit()
+    }
+
+    /// Returns a mutable reference to the element with the smallest key, which restores the heap property
+    /// once it is dropped if its key has been mutated; returns None if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push('a', 1);
+    /// queue.push('b', 2);
+    ///
+    /// if let Some(mut root) = queue.peek_mut() {
+    ///     *root.key_mut() = 3;
+    /// }
+    ///
+    /// assert_eq!(Some(('b', 2)), queue.pop());
+    /// assert_eq!(Some(('a', 3)), queue.pop());
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, N, K>> {
+        self.heap.peek_mut()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the heap in arbitrary order;
+    /// the heap property is restored once the iterator is dropped if the keys are mutated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push('a', 1);
+    /// queue.push('b', 2);
+    ///
+    /// for (node, key) in &mut queue.iter_mut() {
+    ///     if *node == 'b' {
+    ///         *key = 0;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(('b', 0)), queue.pop());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, N, K> {
+        self.heap.iter_mut()
+    }
+
+    /// Returns an iterator visiting the elements of the heap in ascending order of keys without mutating the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = SmallDaryHeap::<_, _, 4, 8>::new();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let sorted: Vec<_> = queue.iter_sorted().collect();
+    /// assert_eq!(vec![&("y", 7), &("x", 42), &("z", 99)], sorted);
+    /// ```
+    pub fn iter_sorted(&self) -> IterSorted<'_, N, K, D> {
+        self.heap.iter_sorted()
+    }
+}
+
+impl<N, K, const D: usize, const INLINE: usize> PriorityQueue<N, K>
+    for SmallDaryHeap<N, K, D, INLINE>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a>
+        = &'a (N, K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = core::slice::Iter<'a, (N, K)>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.heap.peek()
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop()
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node()
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key()
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        self.heap.push(node, key)
+    }
+
+    #[inline(always)]
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        self.heap.push_then_pop(node, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
+
+impl<N, K, const D: usize, const INLINE: usize> Extend<(N, K)> for SmallDaryHeap<N, K, D, INLINE>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        self.heap.extend(iter)
+    }
+}
+
+impl<N, K, const D: usize, const INLINE: usize> FromIterator<(N, K)>
+    for SmallDaryHeap<N, K, D, INLINE>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (N, K)>>(iter: I) -> Self {
+        let mut heap = Self::default();
+        heap.extend(iter);
+        heap
+    }
+}
@@ -0,0 +1,452 @@
+use super::{daryheap_const_helpers::level_of, heap::Heap};
+use crate::{
+    positions::sorted_vec::HeapPositionsSortedVec, HeapError, PriorityQueue, PriorityQueueDecKey,
+    RenameError, ResUpdateKey,
+};
+
+/// Type alias for `DaryHeapWithSortedVec<N, K, 2>`; see [`DaryHeapWithSortedVec`] for details.
+pub type BinaryHeapWithSortedVec<N, K> = DaryHeapWithSortedVec<N, K, 2>;
+/// Type alias for `DaryHeapWithSortedVec<N, K, 4>`; see [`DaryHeapWithSortedVec`] for details.
+pub type QuaternaryHeapWithSortedVec<N, K> = DaryHeapWithSortedVec<N, K, 4>;
+
+/// A d-ary heap which implements both `PriorityQueue` and `PriorityQueueDecKey`.
+///
+/// See [`PriorityQueueDecKey`] for additional functionalities.
+///
+/// `DaryHeapWithSortedVec` achieves the additional features by making use of a vector of (node, position) pairs
+/// sorted by the nodes, which is searched by binary search.
+///
+/// # Sorted Vec vs Map
+///
+/// Similar to `DaryHeapWithMap`, it does not require to know the absolute size of the closed set;
+/// and it only requires the nodes to implement `Ord + Clone`, which makes it available in no-std environments.
+///
+/// Compared to the `BTreeMap` which `DaryHeapWithMap` falls back to when no-std,
+/// * looking up the position of a node requires *O(log n)* time, and is often faster due to the contiguous memory layout;
+/// * updating the position of a node, which happens on every movement of the node on the heap, requires *O(log n)* time;
+/// * however, inserting or removing a node requires *O(n)* time to shift the elements of the vector;
+/// * and memory usage is lower since there is no per-entry allocation.
+///
+/// Therefore, it is a good fit for small to medium numbers of nodes simultaneously in the queue.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = QuaternaryHeapWithSortedVec::default();
+///
+/// queue.push('a', 42);
+/// queue.push('b', 17);
+/// queue.push('c', 99);
+///
+/// queue.decrease_key(&'a', 7);
+/// assert_eq!(Some(&7), queue.key_ref(&'a'));
+///
+/// assert_eq!(ResUpdateKey::Increased, queue.update_key(&'b', 100));
+/// assert_eq!(Some(&100), queue.key_ref(&'b'));
+///
+/// assert_eq!(Some(('a', 7)), queue.pop());
+/// assert_eq!(Some(('c', 99)), queue.pop());
+/// assert_eq!(Some(('b', 100)), queue.pop());
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DaryHeapWithSortedVec<N, K, const D: usize = 2>
+where
+    N: Ord + Clone,
+    K: PartialOrd + Clone,
+{
+    heap: Heap<N, K, HeapPositionsSortedVec<N>, D>,
+}
+
+impl<N, K, const D: usize> Default for DaryHeapWithSortedVec<N, K, D>
+where
+    N: Ord + Clone,
+    K: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        Self {
+            heap: Heap::new(None, HeapPositionsSortedVec::default()),
+        }
+    }
+}
+impl<N, K, const D: usize> DaryHeapWithSortedVec<N, K, D>
+where
+    N: Ord + Clone,
+    K: PartialOrd + Clone,
+{
+    /// Creates a new empty d-ary heap.
+    ///
+    ///  # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut heap = BinaryHeapWithSortedVec::new();
+    ///
+    /// heap.push('a', 4);
+    /// heap.push('b', 42);
+    ///
+    /// assert_eq!(Some('a'), heap.pop_node());
+    /// assert_eq!(Some('b'), heap.pop_node());
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new d-ary heap with the given initial `capacity` on the number of nodes to simultaneously exist on the heap.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: Heap::new(
+                Some(capacity),
+                HeapPositionsSortedVec::with_capacity(capacity),
+            ),
+        }
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Returns the nodes and keys currently in the queue as a slice;
+    /// not necessarily sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithSortedVec::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// let slice = queue.as_slice();
+    ///
+    /// assert_eq!(3, slice.len());
+    /// assert!(slice.contains(&("x", 42)));
+    /// assert!(slice.contains(&("y", 7)));
+    /// assert!(slice.contains(&("z", 99)));
+    /// ```
+    pub fn as_slice(&self) -> &[(N, K)] {
+        self.heap.as_slice()
+    }
+
+    /// Returns the number of levels of the tree of the heap, which is 0 for an empty heap and 1 for a heap
+    /// with a single element; each level holds `D` times the elements of its parent level.
+    ///
+    /// This is useful for comparing the heights of the heaps with different arities on a particular workload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithSortedVec::default();
+    /// assert_eq!(0, queue.depth());
+    ///
+    /// queue.push(0usize, 42);
+    /// assert_eq!(1, queue.depth());
+    ///
+    /// // levels of sizes 1 and 4
+    /// for node in 1..5 {
+    ///     queue.push(node, 42);
+    /// }
+    /// assert_eq!(2, queue.depth());
+    ///
+    /// queue.push(5, 42);
+    /// assert_eq!(3, queue.depth());
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.heap.depth()
+    }
+
+    /// Validates the heap; i.e., checks whether or not:
+    /// * the key of every element is greater than or equal to the key of its parent on the tree, and
+    /// * the positions of the nodes on the tree are tracked correctly.
+    ///
+    /// Returns the first violated invariant as a [`HeapError`], if any.
+    ///
+    /// Since the heap maintains its invariants on its own, this method is mainly useful for debugging and testing.
+    /// It requires *O(n log n)* time where n is the number of elements on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithSortedVec::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    /// queue.push("z", 99);
+    ///
+    /// assert_eq!(Ok(()), queue.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), HeapError> {
+        self.heap.validate()
+    }
+
+    /// Returns the position of the `node` in the slice of elements of the heap returned by [`as_slice`];
+    /// returns None if the node is not in the queue.
+    ///
+    /// [`as_slice`]: Self::as_slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithSortedVec::default();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    ///
+    /// assert_eq!(Some(0), queue.position_of(&"y"));
+    /// assert_eq!(None, queue.position_of(&"w"));
+    /// ```
+    pub fn position_of(&self, node: &N) -> Option<usize> {
+        self.heap.position_of(node)
+    }
+
+    /// Returns the level of the `node` on the tree of the heap where the root is at level 0;
+    /// returns None if the node is not in the queue.
+    ///
+    /// Note that the level is always less than the [`depth`](Self::depth) of the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithSortedVec::default();
+    /// for node in 0..6 {
+    ///     queue.push(node, node);
+    /// }
+    ///
+    /// assert_eq!(Some(0), queue.level_of(&0));
+    /// assert_eq!(Some(1), queue.level_of(&3));
+    /// assert_eq!(Some(2), queue.level_of(&5));
+    /// assert_eq!(None, queue.level_of(&10));
+    /// ```
+    pub fn level_of(&self, node: &N) -> Option<usize> {
+        self.position_of(node).map(level_of::<D>)
+    }
+
+    /// Returns a reference to the key of the `node` if it is in the queue;
+    /// otherwise, pushes the `node` with the key computed by `default` and returns a reference to this key.
+    ///
+    /// The position of the node is looked up only once, unlike the `contains` followed by `push` pattern;
+    /// and `default` is called only if the node is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithSortedVec::default();
+    /// queue.push("a", 42);
+    ///
+    /// assert_eq!(&42, queue.get_or_insert_with("a", || 7));
+    /// assert_eq!(&21, queue.get_or_insert_with("b", || 21));
+    ///
+    /// assert_eq!(2, queue.len());
+    /// assert_eq!(Some(("b", 21)), queue.pop());
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, node: N, default: F) -> &K
+    where
+        F: FnOnce() -> K,
+    {
+        self.heap.get_or_insert_with(node, default)
+    }
+
+    /// Replaces the `old` node with the `new` node keeping its key and its position on the heap.
+    ///
+    /// Since the key is unchanged, the heap property is preserved without any sift;
+    /// unlike removing the `old` node and pushing the `new` node, which might move the element to another position.
+    ///
+    /// Returns [`RenameError::NodeNotFound`] if the `old` node is not in the queue, and
+    /// [`RenameError::NodeAlreadyExists`] if the `new` node is already in the queue; the queue is unchanged in both cases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeapWithSortedVec::default();
+    /// queue.push("a", 42);
+    /// queue.push("b", 7);
+    ///
+    /// assert_eq!(Ok(()), queue.rename(&"a", "c"));
+    /// assert!(!queue.contains(&"a"));
+    /// assert_eq!(Some(42), queue.key_of(&"c"));
+    ///
+    /// assert_eq!(Err(RenameError::NodeNotFound), queue.rename(&"a", "a"));
+    /// assert_eq!(Err(RenameError::NodeAlreadyExists), queue.rename(&"b", "c"));
+    /// ```
+    pub fn rename(&mut self, old: &N, new: N) -> Result<(), RenameError> {
+        self.heap.rename(old, new)
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueue<N, K> for DaryHeapWithSortedVec<N, K, D>
+where
+    N: Ord + Clone,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a>
+        = &'a (N, K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = core::slice::Iter<'a, (N, K)>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.heap.peek()
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear()
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop()
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node()
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key()
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        self.heap.push(node, key)
+    }
+
+    #[inline(always)]
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        self.heap.push_then_pop(node, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
+
+/// Pushes all (node, key) pairs of the iterator to the queue.
+///
+/// Since the nodes of the queue form a set, each node must be absent from the queue and appear at most once in the iterator;
+/// this is the same requirement as pushing the nodes one by one with `push`.
+impl<N, K, const D: usize> Extend<(N, K)> for DaryHeapWithSortedVec<N, K, D>
+where
+    N: Ord + Clone,
+    K: PartialOrd + Clone,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        self.heap.extend(iter)
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueueDecKey<N, K> for DaryHeapWithSortedVec<N, K, D>
+where
+    N: Ord + Clone,
+    K: PartialOrd + Clone,
+{
+    #[inline(always)]
+    fn contains(&self, node: &N) -> bool {
+        self.heap.contains(node)
+    }
+
+    #[inline(always)]
+    fn key_of(&self, node: &N) -> Option<K> {
+        self.heap.key_of(node)
+    }
+
+    #[inline(always)]
+    fn key_ref(&self, node: &N) -> Option<&K> {
+        self.heap.key_ref(node)
+    }
+
+    #[inline(always)]
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K {
+        self.heap.decrease_key(node, decreased_key)
+    }
+
+    #[inline(always)]
+    fn decrease_key_ref(&mut self, node: &N, decreased_key: &K) -> K {
+        self.heap.decrease_key_ref(node, decreased_key)
+    }
+
+    #[inline(always)]
+    fn decrease_key_with<F>(&mut self, node: &N, decrease: F) -> K
+    where
+        F: FnOnce(&K) -> K,
+    {
+        self.heap.decrease_key_with(node, decrease)
+    }
+
+    #[inline(always)]
+    fn decrease_key_all<I>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        self.heap.decrease_key_all(updates)
+    }
+
+    #[inline(always)]
+    fn increase_key(&mut self, node: &N, increased_key: K) {
+        self.heap.increase_key(node, increased_key)
+    }
+
+    #[inline(always)]
+    fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
+        self.heap.update_key(node, new_key)
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, node: &N) -> K {
+        self.heap.remove(node)
+    }
+
+    #[inline(always)]
+    fn take(&mut self, node: &N) -> Option<(N, K)> {
+        self.heap.take(node)
+    }
+
+    #[inline(always)]
+    fn update_key_if<F>(&mut self, node: &N, new_key: K, should_update: F) -> bool
+    where
+        F: FnOnce(&K, &K) -> bool,
+    {
+        self.heap.update_key_if(node, new_key, should_update)
+    }
+}
@@ -0,0 +1,246 @@
+use super::daryheap::DaryHeap;
+use crate::PriorityQueue;
+use core::iter::FusedIterator;
+
+/// Key of an element on the [`StableDaryHeap`] together with its insertion sequence number;
+/// ordered by the key first, and by the sequence number for equal keys.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub(crate) struct StableKey<K> {
+    key: K,
+    seq: usize,
+}
+
+/// A d-ary heap which pops the elements with equal keys in the order they are pushed to the queue.
+///
+/// The pop order of elements with equal keys on the other heaps of this crate is arbitrary.
+/// `StableDaryHeap` provides a deterministic first-in-first-out tie-breaking which is useful for reproducible results
+/// of the algorithms sensitive to the order of the elements, such as in golden tests.
+///
+/// # Memory and Sequence Numbers
+///
+/// Each key is internally stored together with a `usize` sequence number, which is the value of an insertion counter.
+/// Therefore, each element requires an additional `usize` of memory compared to a [`DaryHeap`];
+/// and the comparisons of the keys are followed by comparisons of the sequence numbers in case of ties.
+/// The sequence numbers are not exposed, and the queue is used through the (node, key) pairs as the other queues.
+///
+/// The counter is reset when the queue is cleared.
+/// In the practically unreachable case that the counter overflows, the elements on the heap are renumbered in their pop order;
+/// which requires *O(n log n)* time once, and preserves the first-in-first-out order of the elements with equal keys.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = StableDaryHeap::<_, _, 4>::default();
+///
+/// queue.push('a', 2);
+/// queue.push('b', 1);
+/// queue.push('c', 2);
+/// queue.push('d', 1);
+/// queue.push('e', 2);
+///
+/// let popped: Vec<_> = core::iter::from_fn(|| queue.pop_node()).collect();
+/// assert_eq!(vec!['b', 'd', 'a', 'c', 'e'], popped);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StableDaryHeap<N, K, const D: usize = 2>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    heap: DaryHeap<N, StableKey<K>, D>,
+    seq: usize,
+}
+
+impl<N, K, const D: usize> Default for StableDaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn default() -> Self {
+        Self {
+            heap: DaryHeap::default(),
+            seq: 0,
+        }
+    }
+}
+
+impl<N, K, const D: usize> StableDaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    /// Creates a new empty stable d-ary heap.
+    ///
+    ///  # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut heap = StableDaryHeap::<_, _, 4>::new();
+    ///
+    /// heap.push('a', 4);
+    /// heap.push('b', 4);
+    ///
+    /// assert_eq!(Some('a'), heap.pop_node());
+    /// assert_eq!(Some('b'), heap.pop_node());
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new stable d-ary heap with the given initial `capacity` on the number of nodes to simultaneously exist on the heap.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: DaryHeap::with_capacity(capacity),
+            seq: 0,
+        }
+    }
+
+    /// Returns the 'd' of the d-ary heap.
+    /// In other words, it represents the maximum number of children that each node on the heap can have.
+    pub const fn d() -> usize {
+        D
+    }
+
+    /// Returns the key of the next pushed element together with its sequence number.
+    fn stable_key(&mut self, key: K) -> StableKey<K> {
+        if self.seq == usize::MAX {
+            self.resequence();
+        }
+        let seq = self.seq;
+        self.seq += 1;
+        StableKey { key, seq }
+    }
+
+    /// Renumbers the elements on the heap in their pop order, starting from zero.
+    fn resequence(&mut self) {
+        let heap = core::mem::take(&mut self.heap);
+        for (seq, (node, stable_key)) in heap.into_iter_sorted().enumerate() {
+            let key = stable_key.key;
+            self.heap.push(node, StableKey { key, seq });
+        }
+        self.seq = self.heap.len();
+    }
+}
+
+impl<N, K, const D: usize> PriorityQueue<N, K> for StableDaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type NodeKey<'a>
+        = (&'a N, &'a K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = IterStable<'a, N, K>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(D)
+    }
+
+    fn peek(&self) -> Option<(&N, &K)> {
+        self.heap.peek().map(|(node, key)| (node, &key.key))
+    }
+
+    fn clear(&mut self) {
+        self.heap.clear();
+        self.seq = 0;
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.heap.pop().map(|(node, key)| (node, key.key))
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.heap.pop_node()
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.heap.pop_key().map(|key| key.key)
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        let key = self.stable_key(key);
+        self.heap.push(node, key)
+    }
+
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        let key = self.stable_key(key);
+        let (node, key) = self.heap.push_then_pop(node, key);
+        (node, key.key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        IterStable {
+            iter: self.heap.as_slice().iter(),
+        }
+    }
+}
+
+impl<N, K, const D: usize> Extend<(N, K)> for StableDaryHeap<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.heap.reserve(iter.size_hint().0);
+        for (node, key) in iter {
+            self.push(node, key);
+        }
+    }
+}
+
+/// An iterator over the (node, key) pairs of a [`StableDaryHeap`] in an arbitrary order.
+///
+/// Created by the `iter` method of the heap.
+pub struct IterStable<'a, N, K> {
+    iter: core::slice::Iter<'a, (N, StableKey<K>)>,
+}
+
+impl<'a, N, K> Iterator for IterStable<'a, N, K> {
+    type Item = (&'a N, &'a K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|x| (&x.0, &x.1.key))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<N, K> DoubleEndedIterator for IterStable<'_, N, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|x| (&x.0, &x.1.key))
+    }
+}
+
+impl<N, K> ExactSizeIterator for IterStable<'_, N, K> {}
+
+impl<N, K> FusedIterator for IterStable<'_, N, K> {}
@@ -0,0 +1,44 @@
+use core::iter::FusedIterator;
+
+/// A draining iterator over the (node, key) pairs of a d-ary heap in an arbitrary order.
+///
+/// Created by the `drain` method of the d-ary heaps such as [`DaryHeap::drain`].
+///
+/// Elements are yielded in the order of the underlying tree without any sift;
+/// hence, draining all elements requires linear time.
+/// The heap is empty once the iterator is dropped, even if the iterator is not fully consumed.
+///
+/// [`DaryHeap::drain`]: crate::DaryHeap::drain
+pub struct Drain<'a, N, K> {
+    iter: alloc::vec::Drain<'a, (N, K)>,
+}
+
+impl<'a, N, K> Drain<'a, N, K> {
+    pub(crate) fn new(iter: alloc::vec::Drain<'a, (N, K)>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<N, K> Iterator for Drain<'_, N, K> {
+    type Item = (N, K);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<N, K> DoubleEndedIterator for Drain<'_, N, K> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<N, K> ExactSizeIterator for Drain<'_, N, K> {}
+
+impl<N, K> FusedIterator for Drain<'_, N, K> {}
@@ -0,0 +1,74 @@
+use super::heap::Heap;
+use crate::{positions::none::HeapPositionsNone, PriorityQueue};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// A draining iterator over the (node, key) pairs of a d-ary heap in ascending order of keys.
+///
+/// Created by the `drain_sorted` method of the d-ary heaps such as [`DaryHeap::drain_sorted`].
+///
+/// The iterator is lazy; each call to `next` pops the element with the lowest key.
+/// The heap is empty once the iterator is dropped, even if the iterator is not fully consumed.
+///
+/// [`DaryHeap::drain_sorted`]: crate::DaryHeap::drain_sorted
+pub struct DrainSorted<'a, N, K, const D: usize>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    tree: &'a mut Vec<(N, K)>,
+    heap: Heap<N, K, HeapPositionsNone, D>,
+}
+
+impl<'a, N, K, const D: usize> DrainSorted<'a, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    pub(crate) fn new(tree: &'a mut Vec<(N, K)>, heap: Heap<N, K, HeapPositionsNone, D>) -> Self {
+        Self { tree, heap }
+    }
+}
+
+impl<N, K, const D: usize> Iterator for DrainSorted<'_, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type Item = (N, K);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.len();
+        (len, Some(len))
+    }
+}
+
+impl<N, K, const D: usize> ExactSizeIterator for DrainSorted<'_, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+}
+
+impl<N, K, const D: usize> FusedIterator for DrainSorted<'_, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+}
+
+impl<N, K, const D: usize> Drop for DrainSorted<'_, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    fn drop(&mut self) {
+        // give the allocation back to the drained heap
+        *self.tree = self.heap.take_cleared_tree();
+    }
+}
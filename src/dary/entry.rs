@@ -0,0 +1,116 @@
+use crate::ResUpdateKey;
+
+/// Access to the elements of a heap at known positions which is required by [`Entry`].
+pub(crate) trait HeapEntry<N, K> {
+    fn element_at(&self, position: usize) -> &(N, K);
+
+    fn decrease_key_at(&mut self, position: usize, decreased_key: K) -> K;
+
+    fn update_key_at(&mut self, position: usize, new_key: K) -> ResUpdateKey;
+
+    fn remove_at(&mut self, position: usize) -> K;
+
+    fn push_absent(&mut self, node: N, key: K);
+}
+
+/// A view into a single node of a d-ary heap with positions, which may either be vacant or occupied.
+///
+/// Created by the `entry` method of the d-ary heaps such as [`DaryHeapWithMap::entry`].
+///
+/// The position of the node is looked up only once while creating the entry;
+/// subsequent operations on the entry do not require another lookup.
+///
+/// [`DaryHeapWithMap::entry`]: crate::DaryHeapWithMap::entry
+pub enum Entry<'a, N, K> {
+    /// The node is in the queue.
+    Occupied(OccupiedEntry<'a, N, K>),
+    /// The node is not in the queue.
+    Vacant(VacantEntry<'a, N, K>),
+}
+
+impl<N, K> Entry<'_, N, K> {
+    /// Returns a reference to the node of the entry.
+    pub fn node(&self) -> &N {
+        match self {
+            Self::Occupied(x) => x.node(),
+            Self::Vacant(x) => x.node(),
+        }
+    }
+
+    /// Returns a reference to the key of the node if it is in the queue; None otherwise.
+    pub fn key(&self) -> Option<&K> {
+        match self {
+            Self::Occupied(x) => Some(x.key()),
+            Self::Vacant(_) => None,
+        }
+    }
+}
+
+/// A view into an occupied entry of a d-ary heap; i.e., the node is in the queue.
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, N, K> {
+    heap: &'a mut dyn HeapEntry<N, K>,
+    position: usize,
+}
+
+impl<'a, N, K> OccupiedEntry<'a, N, K> {
+    pub(crate) fn new(heap: &'a mut dyn HeapEntry<N, K>, position: usize) -> Self {
+        Self { heap, position }
+    }
+
+    /// Returns a reference to the node of the entry.
+    pub fn node(&self) -> &N {
+        &self.heap.element_at(self.position).0
+    }
+
+    /// Returns a reference to the current key of the node.
+    pub fn key(&self) -> &K {
+        &self.heap.element_at(self.position).1
+    }
+
+    /// Decreases key of the node to the given `decreased_key`.
+    ///
+    /// # Panics
+    /// This method panics if `decreased_key` is strictly larger than the current key of the node.
+    pub fn decrease_key(self, decreased_key: K) {
+        self.heap.decrease_key_at(self.position, decreased_key);
+    }
+
+    /// Updates key of the node to the given `new_key`; and returns whether the key is decreased or increased.
+    pub fn update_key(self, new_key: K) -> ResUpdateKey {
+        self.heap.update_key_at(self.position, new_key)
+    }
+
+    /// Removes the node from the queue; and returns its key.
+    pub fn remove(self) -> K {
+        self.heap.remove_at(self.position)
+    }
+}
+
+/// A view into a vacant entry of a d-ary heap; i.e., the node is not in the queue.
+/// It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, N, K> {
+    heap: &'a mut dyn HeapEntry<N, K>,
+    node: N,
+}
+
+impl<'a, N, K> VacantEntry<'a, N, K> {
+    pub(crate) fn new(heap: &'a mut dyn HeapEntry<N, K>, node: N) -> Self {
+        Self { heap, node }
+    }
+
+    /// Returns a reference to the node of the entry.
+    pub fn node(&self) -> &N {
+        &self.node
+    }
+
+    /// Takes ownership of the node of the entry.
+    pub fn into_node(self) -> N {
+        self.node
+    }
+
+    /// Pushes the node of the entry to the queue with the given `key`.
+    pub fn push(self, key: K) {
+        self.heap.push_absent(self.node, key)
+    }
+}
@@ -1,33 +1,82 @@
-use super::daryheap_const_helpers::{left_child_of, offset, parent_of};
+use super::{
+    daryheap_const_helpers::{depth_of, left_child_of, offset, parent_of},
+    drain::Drain,
+    drain_sorted::DrainSorted,
+    entry::{Entry, HeapEntry, OccupiedEntry, VacantEntry},
+    heap_store::HeapStore,
+    iter_mut::{HeapElements, IterMut},
+    iter_sorted::IterSorted,
+    peek_mut::{HeapRoot, PeekMut},
+};
 use crate::{
-    positions::heap_positions::{HeapPositions, HeapPositionsDecKey},
-    PriorityQueue, PriorityQueueDecKey, ResUpdateKey,
+    positions::{
+        has_index::HeapPositionsHasIndex,
+        heap_positions::{HeapPositions, HeapPositionsDecKey},
+        none::HeapPositionsNone,
+    },
+    HasIndex, HeapError, PriorityQueue, PriorityQueueDecKey, RenameError, ResUpdateKey,
 };
 use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
-#[derive(Clone, Debug)]
-pub(crate) struct Heap<N, K, P, const D: usize>
+#[derive(Debug)]
+pub(crate) struct Heap<N, K, P, const D: usize, S = Vec<(N, K)>>
 where
     N: Clone,
     K: PartialOrd + Clone,
     P: HeapPositions<N>,
+    S: HeapStore<(N, K)>,
 {
-    tree: Vec<(N, K)>,
+    tree: S,
     positions: P,
+    ph: PhantomData<(N, K)>,
 }
 
-impl<N, K, P, const D: usize> Heap<N, K, P, D>
+impl<N, K, P, const D: usize, S> Clone for Heap<N, K, P, D, S>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: HeapPositions<N> + Clone,
+    S: HeapStore<(N, K)> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree.clone(),
+            positions: self.positions.clone(),
+            ph: PhantomData,
+        }
+    }
+
+    /// Reuses the allocations of the tree and the positions of this heap.
+    fn clone_from(&mut self, source: &Self) {
+        self.tree.clone_from(&source.tree);
+        self.positions.clone_from(&source.positions);
+    }
+}
+
+impl<N, K, P, const D: usize, S> Heap<N, K, P, D, S>
 where
     N: Clone,
     K: PartialOrd + Clone,
     P: HeapPositions<N>,
+    S: HeapStore<(N, K)>,
 {
+    /// Fails to compile when the heap is created with `D < 2`:
+    /// `D = 0` would divide by zero while navigating the tree, and `D = 1` degenerates the heap into a sorted list.
+    const ASSERT_VALID_D: () = assert!(D >= 2, "d-ary heap requires D >= 2");
+
     pub fn new(capacity: Option<usize>, positions: P) -> Self {
+        let () = Self::ASSERT_VALID_D;
         let tree = match capacity {
-            Some(c) => Vec::with_capacity(c + offset::<D>()),
-            None => Vec::new(),
+            Some(c) => S::with_capacity(c + offset::<D>()),
+            None => S::default(),
         };
-        Self { tree, positions }
+        Self {
+            tree,
+            positions,
+            ph: PhantomData,
+        }
     }
 
     fn insert_offset(&mut self, node: &N, key: &K) {
@@ -35,20 +84,98 @@ where
             .extend((0..offset::<D>()).map(|_| (node.clone(), key.clone())));
     }
 
+    /// Pushes the element to the end of the tree without restoring the heap property.
+    fn push_unheapified(&mut self, node: N, key: K) {
+        debug_check_comparable(&key, "push");
+        if self.tree.is_empty() {
+            self.insert_offset(&node, &key);
+        }
+
+        let position = self.tree.len();
+        self.positions.insert(&node, position);
+        self.tree.push((node, key));
+    }
+
+    /// Creates a heap of the given elements with a single bottom-up heapify.
+    ///
+    /// Returns None if a node appears more than once among the elements of a heap tracking positions of its nodes.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_elements(elements: Vec<(N, K)>, positions: P) -> Option<Self> {
+        let mut heap = Self::new(Some(elements.len()), positions);
+        for (node, key) in elements {
+            if heap.positions.contains(&node) {
+                return None;
+            }
+            heap.push_unheapified(node, key);
+        }
+        heap.heapify_all();
+        Some(heap)
+    }
+
     pub(crate) fn positions(&self) -> &P {
         &self.positions
     }
 
-    fn heapify_up(&mut self, starting_position: usize) {
+    pub(crate) fn positions_mut(&mut self) -> &mut P {
+        &mut self.positions
+    }
+
+    #[cfg(feature = "smallvec")]
+    pub(crate) fn tree(&self) -> &S {
+        &self.tree
+    }
+
+    /// Reserves capacity for at least `additional` more elements, excluding the offset of the tree,
+    /// both in the tree and in the positions of the nodes.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        match self.tree.is_empty() {
+            true => self.tree.reserve(additional + offset::<D>()),
+            false => self.tree.reserve(additional),
+        }
+        self.positions.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the tree as much as possible, retaining the offset of the tree.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.tree.shrink_to_fit();
+    }
+
+    /// Clears the heap and shrinks the capacity of the tree down to its offset.
+    pub(crate) fn clear_and_shrink(&mut self) {
+        self.tree.truncate(offset::<D>());
+        self.tree.shrink_to_fit();
+        self.positions.clear();
+    }
+
+    /// Clears the heap and shrinks the capacity of the tree, excluding its offset, down to `max_capacity`
+    /// if it exceeds `max_capacity`; the capacity is retained otherwise.
+    pub(crate) fn clear_to_capacity(&mut self, max_capacity: usize) {
+        self.tree.truncate(offset::<D>());
+        self.tree.shrink_to(max_capacity + offset::<D>());
+        self.positions.clear();
+    }
+
+    /// Reserves capacity for exactly `additional` more elements, excluding the offset of the tree.
+    pub(crate) fn reserve_exact(&mut self, additional: usize) {
+        match self.tree.is_empty() {
+            true => self.tree.reserve_exact(additional + offset::<D>()),
+            false => self.tree.reserve_exact(additional),
+        }
+        self.positions.reserve(additional);
+    }
+
+    /// Moves the element at the `starting_position` up the tree until its parent's key is not greater;
+    /// returns the final position of the element.
+    fn heapify_up(&mut self, starting_position: usize) -> usize {
         if starting_position == offset::<D>() {
-            return;
+            return starting_position;
         }
 
         let mut child = starting_position;
         let mut parent = parent_of::<D>(child);
 
         if self.tree[child].1 >= self.tree[parent].1 {
-            return;
+            return child;
         }
 
         // take out the child node to carry upwards in the tree
@@ -68,6 +195,25 @@ where
 
         self.positions.update_position_of(&node.0, child);
         self.tree[child] = node;
+        child
+    }
+
+    /// Returns the position of the child with the minimum key among the children of the parent whose first child
+    /// is at the valid position `first_child`; the first child wins among the children with equal minimum keys.
+    ///
+    /// The children are read through a single slice, which avoids bounds checks on each child.
+    #[inline(always)]
+    fn best_child_of(&self, first_child: usize) -> usize {
+        let last_child = self.tree.len().min(first_child + D);
+        let children = &self.tree[first_child..last_child];
+        let (best_child, _) = children.iter().enumerate().skip(1).fold(
+            (0, &children[0].1),
+            |(best_child, best_key), (child, (_, key))| match key < best_key {
+                true => (child, key),
+                false => (best_child, best_key),
+            },
+        );
+        first_child + best_child
     }
 
     fn heapify_down(&mut self, starting_position: usize) {
@@ -79,19 +225,8 @@ where
             return;
         }
 
-        let mut best_child = first_child;
-        let mut best_child_key = self.tree[best_child].1.clone();
-        for i in 1..D {
-            let next_child = first_child + i;
-            if next_child >= tree_len {
-                break;
-            } else if self.tree[next_child].1 < best_child_key {
-                best_child = first_child + i;
-                best_child_key = self.tree[next_child].1.clone();
-            }
-        }
-
-        if self.tree[parent].1 <= best_child_key {
+        let mut best_child = self.best_child_of(first_child);
+        if self.tree[parent].1 <= self.tree[best_child].1 {
             return;
         }
 
@@ -99,7 +234,7 @@ where
         let node = self.tree[parent].clone();
         let key = &node.1;
 
-        while key > &best_child_key {
+        while key > &self.tree[best_child].1 {
             self.positions
                 .update_position_of(&self.tree[best_child].0, parent);
             self.tree[parent] = self.tree[best_child].clone();
@@ -109,41 +244,63 @@ where
             if first_child >= tree_len {
                 break;
             }
-            best_child = first_child;
-            best_child_key = self.tree[best_child].1.clone();
-            for i in 1..D {
-                let next_child = first_child + i;
-                if next_child >= tree_len {
-                    break;
-                } else if self.tree[next_child].1 < best_child_key {
-                    best_child = first_child + i;
-                    best_child_key = self.tree[next_child].1.clone();
-                }
-            }
+            best_child = self.best_child_of(first_child);
         }
 
         self.positions.update_position_of(&node.0, parent);
         self.tree[parent] = node;
     }
 
-    fn remove_and_heapify(&mut self, starting_position: usize) {
+    /// Restores the heap property after elements are pushed to the end of the tree without being heapified,
+    /// where `tail_start` is the position of the first such element.
+    ///
+    /// When the tail is large relative to the already valid part of the heap,
+    /// the entire tree is rebuilt bottom-up; otherwise, each element of the tail is heapified up.
+    fn heapify_tail(&mut self, tail_start: usize) {
+        let tail_len = self.tree.len().saturating_sub(tail_start);
+        let head_len = tail_start.saturating_sub(offset::<D>());
+        if tail_len == 0 {
+            return;
+        }
+
+        if tail_len >= head_len {
+            self.heapify_all();
+        } else {
+            for position in tail_start..self.tree.len() {
+                self.heapify_up(position);
+            }
+        }
+    }
+
+    fn heapify_all(&mut self) {
+        let tree_len = self.tree.len();
+        if tree_len <= offset::<D>() + 1 {
+            return;
+        }
+
+        let last_parent = parent_of::<D>(tree_len - 1);
+        for position in (offset::<D>()..=last_parent).rev() {
+            self.heapify_down(position);
+        }
+    }
+
+    pub(crate) fn remove_and_heapify(&mut self, starting_position: usize) -> (N, K) {
         let tree_len = self.tree.len();
         let last = tree_len - 1;
         if tree_len == offset::<D>() + 1 {
             debug_assert_eq!(offset::<D>(), starting_position);
             self.positions.remove(&self.tree[offset::<D>()].0);
-            self.tree.truncate(offset::<D>());
+            self.tree.pop().expect("tree cannot be empty")
         } else if starting_position == last {
             self.positions.remove(&self.tree[starting_position].0);
-            self.tree.truncate(last);
+            self.tree.pop().expect("tree cannot be empty")
         } else {
-            // put last element to starting_position, and
-            // drop the new-last element (originally at the starting position)
+            // move last element to starting_position, and
+            // take out the element originally at the starting position
             self.positions.remove(&self.tree[starting_position].0);
             self.positions
                 .update_position_of(&self.tree[last].0, starting_position);
-            self.tree[starting_position] = self.tree[last].clone();
-            self.tree.truncate(last);
+            let removed = self.tree.swap_remove(starting_position);
 
             let key_of_disturbed = &self.tree[starting_position].1;
             if starting_position > offset::<D>()
@@ -153,38 +310,51 @@ where
             } else {
                 self.heapify_down(starting_position);
             }
+            removed
         }
     }
 
-    #[cfg(test)]
-    #[allow(dead_code)]
-    fn is_valid(&self) -> bool {
-        if !self.positions.is_valid(offset::<D>(), &self.tree) {
-            false
-        } else {
-            fn is_valid_downwards<N, K, const D: usize>(parent: usize, tree: &[(N, K)]) -> bool
-            where
-                K: PartialOrd,
-            {
-                for i in 0..D {
-                    let child = left_child_of::<D>(parent) + i;
-                    if child >= tree.len() {
-                        return true;
-                    } else if tree[child].1 < tree[parent].1 {
-                        return false;
-                    } else {
-                        let downwards_from_child = is_valid_downwards::<N, K, D>(child, tree);
-                        if !downwards_from_child {
-                            return false;
-                        }
-                    }
-                }
-                true
-            }
-            is_valid_downwards::<N, K, D>(offset::<D>(), &self.tree)
+    /// Pushes the (`node`, `key`) pair to the heap and returns its final position on the tree.
+    fn push_and_locate(&mut self, node: N, key: K) -> usize {
+        debug_check_comparable(&key, "push");
+        if self.tree.is_empty() {
+            self.insert_offset(&node, &key);
         }
+
+        let position = self.tree.len();
+        self.positions.insert(&node, position);
+        self.tree.push((node, key));
+        self.heapify_up(position)
+    }
+
+    /// Returns the position of the node relative to the slice of elements returned by `as_slice`.
+    pub(crate) fn position_of(&self, node: &N) -> Option<usize> {
+        self.positions
+            .position_of(node)
+            .map(|position| position - offset::<D>())
     }
 
+    /// Returns the number of levels of the tree; 0 if the heap is empty.
+    pub(crate) fn depth(&self) -> usize {
+        depth_of::<D>(self.len())
+    }
+
+    /// Validates the heap ordering of the keys and consistency of the positions of the nodes;
+    /// returns the first violated invariant as an error, if any.
+    pub(crate) fn validate(&self) -> Result<(), HeapError> {
+        self.positions.validate(offset::<D>(), &self.tree)?;
+
+        for child in (offset::<D>() + 1)..self.tree.len() {
+            let parent = parent_of::<D>(child);
+            if self.tree[child].1 < self.tree[parent].1 {
+                return Err(HeapError::KeyOrder {
+                    parent: parent - offset::<D>(),
+                    child: child - offset::<D>(),
+                });
+            }
+        }
+        Ok(())
+    }
     // additional functionalities
     /// Returns the nodes and keys currently in the queue as a slice;
     /// not necessarily sorted.
@@ -207,15 +377,409 @@ where
     /// assert!(slice.contains(&("z", 99)));
     /// ```
     pub(crate) fn as_slice(&self) -> &[(N, K)] {
-        &self.tree[offset::<D>()..]
+        self.tree.get(offset::<D>()..).unwrap_or_default()
+    }
+
+    /// Replaces the root with the given element with a single heapify-down, and returns the replaced root;
+    /// pushes the element and returns None if the heap is empty.
+    pub(crate) fn replace_root(&mut self, node: N, key: K) -> Option<(N, K)> {
+        match self.is_empty() {
+            true => {
+                self.push(node, key);
+                None
+            }
+            false => {
+                self.positions.remove(&self.tree[offset::<D>()].0);
+                self.positions.insert(&node, offset::<D>());
+                let root = core::mem::replace(&mut self.tree[offset::<D>()], (node, key));
+                self.heapify_down(offset::<D>());
+                Some(root)
+            }
+        }
+    }
+
+    /// Returns the element with the second smallest key, which is the smallest of the children of the root.
+    pub(crate) fn peek_second(&self) -> Option<&(N, K)> {
+        let first_child = left_child_of::<D>(offset::<D>());
+        let end = (first_child + D).min(self.tree.len());
+        self.tree
+            .get(first_child..end)?
+            .iter()
+            .reduce(|min, x| if x.1 < min.1 { x } else { min })
+    }
+
+    pub(crate) fn iter_sorted(&self) -> IterSorted<'_, N, K, D> {
+        IterSorted::new(&self.tree)
+    }
+
+    pub(crate) fn peek_mut(&mut self) -> Option<PeekMut<'_, N, K>> {
+        match self.is_empty() {
+            false => Some(PeekMut::new(self)),
+            true => None,
+        }
+    }
+
+    /// Rebuilds the positions of the nodes from the tree and restores the heap property by a single bottom-up heapify.
+    pub(crate) fn repair(&mut self) {
+        self.positions.clear();
+        for (position, (node, _)) in self.tree.iter().enumerate().skip(offset::<D>()) {
+            self.positions.insert(node, position);
+        }
+        self.heapify_all();
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> IterMut<'_, N, K> {
+        IterMut::new(self)
+    }
+
+    /// Pops up to `n` elements in ascending order of keys.
+    pub(crate) fn pop_n(&mut self, n: usize) -> Vec<(N, K)> {
+        let mut popped = Vec::with_capacity(n.min(self.len()));
+        popped.extend(core::iter::from_fn(|| self.pop()).take(n));
+        popped
+    }
+
+    /// Pops the element with the minimum key together with all elements whose keys are equal to it.
+    pub(crate) fn pop_min_group(&mut self) -> Vec<(N, K)> {
+        let mut group = Vec::new();
+        group.extend(self.pop());
+        while let Some((_, min)) = group.first() {
+            match self.peek() {
+                Some((_, key)) if key == min => group.extend(self.pop()),
+                _ => break,
+            }
+        }
+        group
+    }
+
+    /// Pops the elements while the foremost element satisfies the predicate `f`, in ascending order of their keys.
+    pub(crate) fn pop_while<F>(&mut self, mut f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        let mut popped = Vec::new();
+        while self.peek().is_some_and(|(node, key)| f(node, key)) {
+            popped.extend(self.pop());
+        }
+        popped
     }
 }
 
-impl<N, K, P, const D: usize> PriorityQueue<N, K> for Heap<N, K, P, D>
+/// Operations moving the tree into or out of the heap, or draining its elements, which are
+/// provided for the default `Vec` container.
+impl<N, K, P, const D: usize> Heap<N, K, P, D>
 where
     N: Clone,
     K: PartialOrd + Clone,
     P: HeapPositions<N>,
+{
+    /// Moves all elements out to a heap without positions which is owned by the returned iterator,
+    /// leaving this heap and its positions empty.
+    pub(crate) fn drain_sorted(&mut self) -> DrainSorted<'_, N, K, D> {
+        self.positions.clear();
+        let heap = Heap {
+            tree: core::mem::take(&mut self.tree),
+            positions: HeapPositionsNone,
+            ph: PhantomData,
+        };
+        DrainSorted::new(&mut self.tree, heap)
+    }
+
+    /// Clears the positions and returns an iterator draining the elements in the order of the tree;
+    /// the tree is left with its offset only, the state of a cleared heap, once the iterator is dropped.
+    pub(crate) fn drain(&mut self) -> Drain<'_, N, K> {
+        self.positions.clear();
+        let start = offset::<D>().min(self.tree.len());
+        Drain::new(self.tree.drain(start..))
+    }
+
+    /// Clears the heap and takes out its tree, retaining the allocation.
+    pub(crate) fn take_cleared_tree(&mut self) -> Vec<(N, K)> {
+        self.positions.clear();
+        self.tree.clear();
+        core::mem::take(&mut self.tree)
+    }
+
+    /// Keeps only the elements for which `keep` returns true, passing the removed elements to `removed`,
+    /// and restores the heap property once on the compacted tree.
+    ///
+    /// The heap property is restored even if all elements are kept when `keys_mutated` is true.
+    fn compact<F, R>(&mut self, mut keep: F, removed: R, keys_mutated: bool)
+    where
+        F: FnMut(&N, &mut K) -> bool,
+        R: FnMut((N, K)),
+    {
+        let len = self.tree.len();
+        let mut num_kept = offset::<D>();
+        for position in offset::<D>()..len {
+            let (node, key) = &mut self.tree[position];
+            if keep(node, key) {
+                self.tree.swap(num_kept, position);
+                num_kept += 1;
+            }
+        }
+        if num_kept >= len {
+            if keys_mutated {
+                self.heapify_all();
+            }
+            return;
+        }
+
+        for (node, _) in &self.tree[num_kept..] {
+            self.positions.remove(node);
+        }
+        self.tree.drain(num_kept..).for_each(removed);
+        for position in offset::<D>()..num_kept {
+            self.positions
+                .update_position_of(&self.tree[position].0, position);
+        }
+        self.heapify_all();
+    }
+
+    /// Keeps only the elements for which `f` returns true.
+    pub(crate) fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        self.compact(|node, key| f(node, key), drop, false)
+    }
+
+    /// Keeps only the elements for which `f` returns true, allowing `f` to mutate the keys,
+    /// and restores the heap property once.
+    pub(crate) fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&N, &mut K) -> bool,
+    {
+        self.compact(f, drop, true)
+    }
+
+    /// Removes and returns the elements for which `f` returns true.
+    pub(crate) fn remove_if<F>(&mut self, mut f: F) -> Vec<(N, K)>
+    where
+        F: FnMut(&N, &K) -> bool,
+    {
+        let mut removed = Vec::new();
+        self.compact(|node, key| !f(node, key), |x| removed.push(x), false);
+        removed
+    }
+
+    /// Moves the elements with keys greater than or equal to the `threshold` out into a new heap with the given
+    /// empty `positions`; the heap property of both heaps is restored by a single bottom-up heapify.
+    pub(crate) fn split_off_ge(&mut self, threshold: &K, positions: P) -> Self {
+        let removed = self.remove_if(|_, key| key >= threshold);
+        let mut other = Self::new(Some(removed.len()), positions);
+        for (node, key) in removed {
+            other.push_unheapified(node, key);
+        }
+        other.heapify_all();
+        other
+    }
+
+    /// Moves all elements of `other` into this heap leaving `other` empty, while retaining its allocation.
+    ///
+    /// If a node exists in both heaps, it is kept once with the smaller of the two keys.
+    pub(crate) fn append(&mut self, other: &mut Self) {
+        other.positions.clear();
+        let mut other_tree = core::mem::take(&mut other.tree);
+        self.extend_keep_min(other_tree.drain(..).skip(offset::<D>()));
+        other.tree = other_tree;
+    }
+
+    /// Moves all elements of the valid heap `other` into this heap, consuming `other`.
+    ///
+    /// If a node exists in both heaps, it is kept once with the smaller of the two keys.
+    pub(crate) fn append_sorted(&mut self, other: Self) {
+        self.extend_keep_min(other.into_elements());
+    }
+
+    /// Pushes all elements of the iterator and restores the heap property once.
+    ///
+    /// If a node is already in the heap or appears more than once, it is kept once with the smallest of its keys.
+    pub(crate) fn extend_keep_min<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        self.extend_keep_min_reporting(iter, |_| {});
+    }
+
+    /// Pushes all elements of the iterator and restores the heap property once, as `extend_keep_min`;
+    /// and passes each (node, key) pair which is left out since the node is kept with a smaller or equal key
+    /// to `superseded`, in the order of the collisions.
+    pub(crate) fn extend_keep_min_reporting<I, R>(&mut self, iter: I, mut superseded: R)
+    where
+        I: IntoIterator<Item = (N, K)>,
+        R: FnMut((N, K)),
+    {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        self.reserve(lower_bound);
+
+        let tail_start = self.tree.len().max(offset::<D>());
+        let mut decreased = false;
+        for (node, key) in iter {
+            match self.positions.position_of(&node) {
+                Some(position) => match key < self.tree[position].1 {
+                    true => {
+                        let old_key = core::mem::replace(&mut self.tree[position].1, key);
+                        superseded((node, old_key));
+                        decreased = true;
+                    }
+                    false => superseded((node, key)),
+                },
+                None => {
+                    self.push_unheapified(node, key);
+                }
+            }
+        }
+
+        match decreased {
+            true => self.heapify_all(),
+            false => self.heapify_tail(tail_start),
+        }
+    }
+
+    /// Consumes the heap and maps each key by `f` into a heap of the same nodes which does not track their positions.
+    ///
+    /// The heap property is restored by a single bottom-up heapify unless `order_preserving` is true,
+    /// in which case the caller guarantees that `f` preserves the order of the keys.
+    pub(crate) fn map_keys<K2, F>(
+        self,
+        mut f: F,
+        order_preserving: bool,
+    ) -> Heap<N, K2, HeapPositionsNone, D>
+    where
+        K2: PartialOrd + Clone,
+        F: FnMut(K) -> K2,
+    {
+        let len = self.len();
+        let mut heap = Heap::new(Some(len), HeapPositionsNone);
+        for (node, key) in self.tree.into_iter().skip(offset::<D>()) {
+            heap.push_unheapified(node, f(key));
+        }
+        if !order_preserving {
+            heap.heapify_all();
+        }
+        heap
+    }
+
+    /// Feeds the (node, key) pairs of the heap into the `state` as a multiset, independent of the layout of the tree.
+    ///
+    /// Each element is hashed by the deterministic `ElementHasher`, and the sorted element hashes are fed into the `state`.
+    pub(crate) fn hash_elements<H: Hasher>(&self, state: &mut H)
+    where
+        N: Hash,
+        K: Hash,
+    {
+        let mut hashes: Vec<u64> = self
+            .as_slice()
+            .iter()
+            .map(|element| {
+                let mut hasher = ElementHasher::default();
+                element.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        hashes.sort_unstable();
+        hashes.hash(state);
+    }
+
+    /// Converts the heap into a heap of the same elements which does not track positions of the nodes.
+    pub(crate) fn into_heap_without_positions(self) -> Heap<N, K, HeapPositionsNone, D> {
+        Heap {
+            tree: self.tree,
+            positions: HeapPositionsNone,
+            ph: PhantomData,
+        }
+    }
+
+    /// Converts the heap into a heap of the same elements at the same positions, tracking the positions of the nodes by
+    /// the given empty `positions`; hence, the heap property is preserved without a heapify.
+    pub(crate) fn into_heap_with_positions<P2>(self, mut positions: P2) -> Heap<N, K, P2, D>
+    where
+        P2: HeapPositions<N>,
+    {
+        for (position, (node, _)) in self.tree.iter().enumerate().skip(offset::<D>()) {
+            positions.insert(node, position);
+        }
+        Heap {
+            tree: self.tree,
+            positions,
+            ph: PhantomData,
+        }
+    }
+
+    /// Consumes the heap and returns its elements in the order of the tree, excluding the offset.
+    pub(crate) fn into_elements(self) -> Vec<(N, K)> {
+        let mut tree = self.tree;
+        tree.drain(..offset::<D>().min(tree.len()));
+        tree
+    }
+
+    /// Deconstructs the heap into its tree, including the offset, and its positions.
+    pub(crate) fn into_parts(self) -> (Vec<(N, K)>, P) {
+        (self.tree, self.positions)
+    }
+
+    /// Creates the heap from the parts as returned by `into_parts`;
+    /// the caller guarantees that the tree and the positions satisfy the invariants of the heap.
+    pub(crate) fn from_parts(tree: Vec<(N, K)>, positions: P) -> Self {
+        let () = Self::ASSERT_VALID_D;
+        debug_assert!(tree.is_empty() || tree.len() >= offset::<D>());
+        let heap = Self {
+            tree,
+            positions,
+            ph: PhantomData,
+        };
+        debug_assert_eq!(Ok(()), heap.validate());
+        heap
+    }
+}
+
+impl<N, K, P, const D: usize, S> HeapRoot<N, K> for Heap<N, K, P, D, S>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: HeapPositions<N>,
+    S: HeapStore<(N, K)>,
+{
+    fn root(&self) -> &(N, K) {
+        &self.tree[offset::<D>()]
+    }
+
+    fn root_key_mut(&mut self) -> &mut K {
+        &mut self.tree[offset::<D>()].1
+    }
+
+    fn heapify_down_root(&mut self) {
+        self.heapify_down(offset::<D>());
+    }
+}
+
+impl<N, K, P, const D: usize, S> HeapElements<N, K> for Heap<N, K, P, D, S>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: HeapPositions<N>,
+    S: HeapStore<(N, K)>,
+{
+    fn elements_mut(&mut self) -> &mut [(N, K)] {
+        match self.tree.get_mut(offset::<D>()..) {
+            Some(elements) => elements,
+            None => &mut [],
+        }
+    }
+
+    fn heapify_all(&mut self) {
+        Heap::heapify_all(self);
+    }
+}
+
+impl<N, K, P, const D: usize, S> PriorityQueue<N, K> for Heap<N, K, P, D, S>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: HeapPositions<N>,
+    S: HeapStore<(N, K)>,
 {
     type NodeKey<'a> = &'a (N, K) where Self: 'a, N: 'a, K: 'a;
     type Iter<'a> = core::slice::Iter<'a, (N, K)> where Self: 'a, N: 'a, K: 'a;
@@ -232,7 +796,7 @@ where
 
     #[inline(always)]
     fn capacity(&self) -> usize {
-        self.tree.capacity() - offset::<D>()
+        self.tree.capacity().saturating_sub(offset::<D>())
     }
 
     #[inline(always)]
@@ -287,17 +851,11 @@ where
     }
 
     fn push(&mut self, node: N, key: K) {
-        if self.tree.is_empty() {
-            self.insert_offset(&node, &key);
-        }
-
-        let position = self.tree.len();
-        self.positions.insert(&node, position);
-        self.tree.push((node, key));
-        self.heapify_up(position);
+        self.push_and_locate(node, key);
     }
 
     fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        debug_check_comparable(&key, "push_then_pop");
         if self.is_empty() || self.tree[offset::<D>()].1 >= key {
             (node, key)
         } else {
@@ -316,11 +874,170 @@ where
     }
 }
 
-impl<N, K, P, const D: usize> PriorityQueueDecKey<N, K> for Heap<N, K, P, D>
+impl<N, K, P, const D: usize, S> Extend<(N, K)> for Heap<N, K, P, D, S>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: HeapPositions<N>,
+    S: HeapStore<(N, K)>,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        self.reserve(lower_bound);
+
+        let tail_start = self.tree.len().max(offset::<D>());
+        for (node, key) in iter {
+            self.push_unheapified(node, key);
+        }
+
+        self.heapify_tail(tail_start);
+    }
+}
+
+impl<N, K, P, const D: usize, S> Heap<N, K, P, D, S>
 where
     N: Clone,
     K: PartialOrd + Clone,
     P: HeapPositionsDecKey<N>,
+    S: HeapStore<(N, K)>,
+{
+    pub(crate) fn entry(&mut self, node: N) -> Entry<'_, N, K> {
+        match self.positions.position_of(&node) {
+            Some(position) => Entry::Occupied(OccupiedEntry::new(self, position)),
+            None => Entry::Vacant(VacantEntry::new(self, node)),
+        }
+    }
+
+    pub(crate) fn get_or_insert_with<F>(&mut self, node: N, default: F) -> &K
+    where
+        F: FnOnce() -> K,
+    {
+        let position = match self.positions.position_of(&node) {
+            Some(position) => position,
+            None => self.push_and_locate(node, default()),
+        };
+        &self.tree[position].1
+    }
+
+    /// Replaces the `old` node with the `new` node at the same position of the tree keeping its key.
+    pub(crate) fn rename(&mut self, old: &N, new: N) -> Result<(), RenameError> {
+        let position = self
+            .positions
+            .position_of(old)
+            .ok_or(RenameError::NodeNotFound)?;
+        if self.positions.contains(&new) {
+            return Err(RenameError::NodeAlreadyExists);
+        }
+
+        self.positions.remove(old);
+        self.positions.insert(&new, position);
+        self.tree[position].0 = new;
+        Ok(())
+    }
+}
+
+/// Operations which are specific to the heaps whose positions are stored in an array indexed by the nodes.
+impl<N, K, const D: usize> Heap<N, K, HeapPositionsHasIndex<N>, D>
+where
+    N: HasIndex,
+    K: PartialOrd + Clone,
+{
+    /// Decreases the keys of the nodes whose indices have `Some` new keys in the dense `new_keys`.
+    ///
+    /// When the number of updates is large relative to the length of the heap, keys are set in place and the
+    /// heap property is restored once bottom-up; otherwise, each decreased element is heapified up.
+    pub(crate) fn decrease_key_dense(&mut self, new_keys: &[Option<K>]) {
+        let num_updates = new_keys.iter().filter(|x| x.is_some()).count();
+        let rebuild = num_updates > 0 && num_updates >= self.len() / 2;
+
+        let updates = new_keys
+            .iter()
+            .enumerate()
+            .filter_map(|(index, key)| key.as_ref().map(|key| (index, key)));
+        for (index, decreased_key) in updates {
+            let position = self.positions.position_of_index(index);
+            if rebuild {
+                let is_valid = position.is_some_and(|p| *decreased_key <= self.tree[p].1);
+                if !is_valid {
+                    // leave the queue valid before panicking
+                    self.heapify_all();
+                }
+            }
+
+            let position =
+                position.expect("cannot decrease key of a node that is not on the queue");
+            match rebuild {
+                false => {
+                    self.decrease_key_at(position, decreased_key.clone());
+                }
+                true => {
+                    debug_check_comparable(decreased_key, "decrease_key");
+                    assert!(
+                        *decreased_key <= self.tree[position].1,
+                        "decrease_key is called with a greater key"
+                    );
+                    self.tree[position].1 = decreased_key.clone();
+                }
+            }
+        }
+
+        if rebuild {
+            self.heapify_all();
+        }
+    }
+}
+
+impl<N, K, P, const D: usize, S> HeapEntry<N, K> for Heap<N, K, P, D, S>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: HeapPositions<N>,
+    S: HeapStore<(N, K)>,
+{
+    fn element_at(&self, position: usize) -> &(N, K) {
+        &self.tree[position]
+    }
+
+    fn decrease_key_at(&mut self, position: usize, decreased_key: K) -> K {
+        debug_check_comparable(&decreased_key, "decrease_key");
+        assert!(
+            decreased_key <= self.tree[position].1,
+            "decrease_key is called with a greater key"
+        );
+        let old_key = core::mem::replace(&mut self.tree[position].1, decreased_key);
+        self.heapify_up(position);
+        old_key
+    }
+
+    fn update_key_at(&mut self, position: usize, new_key: K) -> ResUpdateKey {
+        debug_check_comparable(&new_key, "update_key");
+        let up = new_key < self.tree[position].1;
+        self.tree[position].1 = new_key;
+        if up {
+            self.heapify_up(position);
+            ResUpdateKey::Decreased
+        } else {
+            self.heapify_down(position);
+            ResUpdateKey::Increased
+        }
+    }
+
+    fn remove_at(&mut self, position: usize) -> K {
+        self.remove_and_heapify(position).1
+    }
+
+    fn push_absent(&mut self, node: N, key: K) {
+        self.push(node, key)
+    }
+}
+
+impl<N, K, P, const D: usize, S> PriorityQueueDecKey<N, K> for Heap<N, K, P, D, S>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: HeapPositionsDecKey<N>,
+    S: HeapStore<(N, K)>,
 {
     fn contains(&self, node: &N) -> bool {
         self.positions.contains(node)
@@ -332,17 +1049,89 @@ where
             .map(|i| self.tree[i].1.clone())
     }
 
-    fn decrease_key(&mut self, node: &N, decreased_key: K) {
+    fn key_ref(&self, node: &N) -> Option<&K> {
+        self.positions.position_of(node).map(|i| &self.tree[i].1)
+    }
+
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K {
+        let position = self
+            .positions
+            .position_of(node)
+            .expect("cannot decrease key of a node that is not on the queue");
+        self.decrease_key_at(position, decreased_key)
+    }
+
+    fn decrease_key_ref(&mut self, node: &N, decreased_key: &K) -> K {
         let position = self
             .positions
             .position_of(node)
             .expect("cannot decrease key of a node that is not on the queue");
+        debug_check_comparable(decreased_key, "decrease_key");
         assert!(
-            decreased_key <= self.tree[position].1,
+            *decreased_key <= self.tree[position].1,
             "decrease_key is called with a greater key"
         );
-        self.tree[position].1 = decreased_key.clone();
-        self.heapify_up(position);
+        self.decrease_key_at(position, decreased_key.clone())
+    }
+
+    fn decrease_key_with<F>(&mut self, node: &N, decrease: F) -> K
+    where
+        F: FnOnce(&K) -> K,
+    {
+        let position = self
+            .positions
+            .position_of(node)
+            .expect("cannot decrease key of a node that is not on the queue");
+        let decreased_key = decrease(&self.tree[position].1);
+        self.decrease_key_at(position, decreased_key)
+    }
+
+    fn decrease_key_all<I>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        let updates = updates.into_iter();
+        let (lower_bound, _) = updates.size_hint();
+        let rebuild = lower_bound > 0 && lower_bound >= self.len() / 2;
+
+        match rebuild {
+            false => {
+                for (node, decreased_key) in updates {
+                    self.decrease_key(&node, decreased_key);
+                }
+            }
+            true => {
+                for (node, decreased_key) in updates {
+                    let position = self.positions.position_of(&node);
+                    let is_valid = position.is_some_and(|p| decreased_key <= self.tree[p].1);
+                    if !is_valid {
+                        // leave the queue valid before panicking
+                        self.heapify_all();
+                    }
+
+                    debug_check_comparable(&decreased_key, "decrease_key");
+                    let position =
+                        position.expect("cannot decrease key of a node that is not on the queue");
+                    assert!(is_valid, "decrease_key is called with a greater key");
+                    self.tree[position].1 = decreased_key;
+                }
+                self.heapify_all();
+            }
+        }
+    }
+
+    fn increase_key(&mut self, node: &N, increased_key: K) {
+        let position = self
+            .positions
+            .position_of(node)
+            .expect("cannot increase key of a node that is not on the queue");
+        debug_check_comparable(&increased_key, "increase_key");
+        assert!(
+            increased_key >= self.tree[position].1,
+            "increase_key is called with a smaller key"
+        );
+        self.tree[position].1 = increased_key;
+        self.heapify_down(position);
     }
 
     fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
@@ -350,15 +1139,7 @@ where
             .positions
             .position_of(node)
             .expect("cannot update key of a node that is not on the queue");
-        let up = new_key < self.tree[position].1;
-        self.tree[position].1 = new_key.clone();
-        if up {
-            self.heapify_up(position);
-            ResUpdateKey::Decreased
-        } else {
-            self.heapify_down(position);
-            ResUpdateKey::Increased
-        }
+        self.update_key_at(position, new_key)
     }
 
     fn remove(&mut self, node: &N) -> K {
@@ -366,8 +1147,69 @@ where
             .positions
             .position_of(node)
             .expect("cannot remove a node that is not on the queue");
-        let key_of_removed = self.tree[position].1.clone();
-        self.remove_and_heapify(position);
-        key_of_removed
+        self.remove_at(position)
+    }
+
+    fn take(&mut self, node: &N) -> Option<(N, K)> {
+        self.positions
+            .position_of(node)
+            .map(|position| self.remove_and_heapify(position))
+    }
+
+    fn update_key_if<F>(&mut self, node: &N, new_key: K, should_update: F) -> bool
+    where
+        F: FnOnce(&K, &K) -> bool,
+    {
+        let position = self
+            .positions
+            .position_of(node)
+            .expect("cannot update key of a node that is not on the queue");
+        match should_update(&self.tree[position].1, &new_key) {
+            true => {
+                self.update_key_at(position, new_key);
+                true
+            }
+            false => false,
+        }
+    }
+}
+
+/// Panics if the `key` is not comparable to itself, such as a NaN float key, which would silently break the heap order.
+///
+/// The check is performed only in debug builds with the `debug_nan_check` feature enabled; it is a no-op otherwise.
+#[inline(always)]
+fn debug_check_comparable<K: PartialOrd>(_key: &K, _operation: &str) {
+    #[cfg(all(debug_assertions, feature = "debug_nan_check"))]
+    assert!(
+        _key.partial_cmp(_key).is_some(),
+        "{} is called with a key which is not comparable to itself, such as NaN; consider wrapping float keys in FloatKey",
+        _operation
+    );
+}
+
+/// A minimal deterministic FNV-1a hasher used to hash the elements of the heap independently of the hasher of the caller.
+///
+/// Unlike `std::hash::DefaultHasher`, it is available in no-std environments.
+struct ElementHasher {
+    hash: u64,
+}
+
+impl Default for ElementHasher {
+    fn default() -> Self {
+        Self {
+            hash: 0xcbf2_9ce4_8422_2325,
+        }
+    }
+}
+
+impl Hasher for ElementHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash = (self.hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
     }
 }
@@ -0,0 +1,157 @@
+use alloc::vec::Vec;
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
+use core::ops::{Deref, DerefMut};
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
+/// Backing container of the tree of a d-ary heap, including the offset of the tree.
+///
+/// The container is accessed as a slice for reads, writes and swaps of the elements by its `Deref` implementations,
+/// such as `len`, `get` and indexing; the sift logic of the heap is independent of the container.
+/// `Vec` is the default container.
+pub(crate) trait HeapStore<T>: Default + Deref<Target = [T]> + DerefMut + Extend<T> {
+    fn with_capacity(capacity: usize) -> Self;
+
+    fn capacity(&self) -> usize;
+
+    fn push(&mut self, element: T);
+
+    fn pop(&mut self) -> Option<T>;
+
+    fn swap_remove(&mut self, position: usize) -> T;
+
+    fn truncate(&mut self, len: usize);
+
+    fn reserve(&mut self, additional: usize);
+
+    fn reserve_exact(&mut self, additional: usize);
+
+    fn shrink_to_fit(&mut self);
+
+    fn shrink_to(&mut self, min_capacity: usize);
+}
+
+impl<T> HeapStore<T> for Vec<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+
+    fn capacity(&self) -> usize {
+        Vec::capacity(self)
+    }
+
+    fn push(&mut self, element: T) {
+        Vec::push(self, element)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    fn swap_remove(&mut self, position: usize) -> T {
+        Vec::swap_remove(self, position)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional)
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        Vec::reserve_exact(self, additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        Vec::shrink_to_fit(self)
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        Vec::shrink_to(self, min_capacity)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<T, const INLINE: usize> HeapStore<T> for SmallVec<[T; INLINE]> {
+    fn with_capacity(capacity: usize) -> Self {
+        SmallVec::with_capacity(capacity)
+    }
+
+    fn capacity(&self) -> usize {
+        SmallVec::capacity(self)
+    }
+
+    fn push(&mut self, element: T) {
+        SmallVec::push(self, element)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        SmallVec::pop(self)
+    }
+
+    fn swap_remove(&mut self, position: usize) -> T {
+        SmallVec::swap_remove(self, position)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        SmallVec::truncate(self, len)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        SmallVec::reserve(self, additional)
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        SmallVec::reserve_exact(self, additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        SmallVec::shrink_to_fit(self)
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        if SmallVec::capacity(self) > min_capacity {
+            SmallVec::grow(self, min_capacity.max(self.len()))
+        }
+    }
+}
+
+/// Fixed capacity container which never allocates; `push` panics when the container is full,
+/// and the capacity related requests are no-ops.
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> HeapStore<T> for ArrayVec<T, CAP> {
+    fn with_capacity(_capacity: usize) -> Self {
+        ArrayVec::new()
+    }
+
+    fn capacity(&self) -> usize {
+        CAP
+    }
+
+    fn push(&mut self, element: T) {
+        ArrayVec::push(self, element)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        ArrayVec::pop(self)
+    }
+
+    fn swap_remove(&mut self, position: usize) -> T {
+        ArrayVec::swap_remove(self, position)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        ArrayVec::truncate(self, len)
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn reserve_exact(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn shrink_to(&mut self, _min_capacity: usize) {}
+}
@@ -0,0 +1,60 @@
+use super::heap::Heap;
+use crate::{positions::none::HeapPositionsNone, PriorityQueue};
+use core::iter::FusedIterator;
+
+/// A consuming iterator over the (node, key) pairs of a d-ary heap in ascending order of keys.
+///
+/// Created by the `into_iter_sorted` method of the d-ary heaps such as [`DaryHeap::into_iter_sorted`].
+///
+/// The iterator is lazy; each call to `next` pops the element with the lowest key from the underlying heap.
+///
+/// [`DaryHeap::into_iter_sorted`]: crate::DaryHeap::into_iter_sorted
+pub struct IntoIterSorted<N, K, const D: usize>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    heap: Heap<N, K, HeapPositionsNone, D>,
+}
+
+impl<N, K, const D: usize> IntoIterSorted<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    pub(crate) fn new(heap: Heap<N, K, HeapPositionsNone, D>) -> Self {
+        Self { heap }
+    }
+}
+
+impl<N, K, const D: usize> Iterator for IntoIterSorted<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type Item = (N, K);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.len();
+        (len, Some(len))
+    }
+}
+
+impl<N, K, const D: usize> ExactSizeIterator for IntoIterSorted<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+}
+
+impl<N, K, const D: usize> FusedIterator for IntoIterSorted<N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+}
@@ -0,0 +1,97 @@
+use core::{iter::Map, slice};
+
+/// Access to the elements of a heap which is required by [`IterMut`].
+pub(crate) trait HeapElements<N, K> {
+    fn elements_mut(&mut self) -> &mut [(N, K)];
+
+    fn heapify_all(&mut self);
+}
+
+/// Structure wrapping a mutable reference to the elements of a d-ary heap, allowing to mutate all keys in bulk.
+///
+/// Created by the `iter_mut` method of the d-ary heaps such as [`DaryHeap::iter_mut`].
+///
+/// The guard is iterated by a mutable reference, yielding `(&N, &mut K)` pairs of all elements in arbitrary order.
+/// The keys can be mutated freely; the heap is restored by a single bottom-up heapify once the guard is dropped,
+/// which requires *O(n)* time where n is the number of elements on the heap.
+///
+/// The nodes cannot be mutated.
+/// Therefore, the set of nodes in the queue is unchanged;
+/// and the heaps tracking positions of the nodes remain consistent since only the keys are mutated.
+///
+/// [`DaryHeap::iter_mut`]: crate::DaryHeap::iter_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = QuaternaryHeapWithMap::default();
+/// queue.push('a', 42);
+/// queue.push('b', 7);
+/// queue.push('c', 99);
+///
+/// for (node, key) in &mut queue.iter_mut() {
+///     match node {
+///         'b' => *key += 100,
+///         _ => *key *= 2,
+///     }
+/// }
+///
+/// assert_eq!(Some(&('a', 84)), queue.peek());
+/// assert_eq!(Some(&107), queue.key_ref(&'b'));
+/// assert_eq!(Some(&198), queue.key_ref(&'c'));
+/// ```
+///
+/// ## Borrows of the keys
+///
+/// The yielded keys are borrowed from the guard; therefore, they cannot be used once the guard is dropped
+/// and the heap is restored, which fails to compile.
+///
+/// ```compile_fail
+/// use orx_priority_queue::*;
+///
+/// let mut queue = QuaternaryHeapWithMap::default();
+/// queue.push('a', 42);
+/// queue.push('b', 7);
+///
+/// let mut guard = queue.iter_mut();
+/// let (_, key) = (&mut guard).into_iter().next().unwrap();
+/// *key = 1000;
+/// drop(guard);
+/// *key = 2000;
+/// ```
+pub struct IterMut<'a, N, K> {
+    heap: &'a mut dyn HeapElements<N, K>,
+    keys_mutable: bool,
+}
+
+impl<'a, N, K> IterMut<'a, N, K> {
+    pub(crate) fn new(heap: &'a mut dyn HeapElements<N, K>) -> Self {
+        Self {
+            heap,
+            keys_mutable: false,
+        }
+    }
+}
+
+impl<'b, N, K> IntoIterator for &'b mut IterMut<'_, N, K> {
+    type Item = (&'b N, &'b mut K);
+    type IntoIter = Map<slice::IterMut<'b, (N, K)>, fn(&'b mut (N, K)) -> (&'b N, &'b mut K)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys_mutable = true;
+        self.heap
+            .elements_mut()
+            .iter_mut()
+            .map(|(node, key)| (&*node, key))
+    }
+}
+
+impl<N, K> Drop for IterMut<'_, N, K> {
+    fn drop(&mut self) {
+        if self.keys_mutable {
+            self.heap.heapify_all();
+        }
+    }
+}
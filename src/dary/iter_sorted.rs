@@ -0,0 +1,79 @@
+use super::daryheap_const_helpers::{left_child_of, offset};
+use crate::{DaryHeap, PriorityQueue};
+use core::iter::FusedIterator;
+
+/// An iterator over the (node, key) pairs of a d-ary heap in ascending order of keys,
+/// which does not mutate or consume the heap.
+///
+/// Created by the `iter_sorted` method of the d-ary heaps such as [`DaryHeap::iter_sorted`].
+///
+/// The iterator keeps an auxiliary heap of the positions of the candidate elements, which starts with the root.
+/// Each time an element is yielded, its children on the tree become candidates.
+/// Therefore, yielding the first `k` elements requires *O(k log k)* time and *O(k)* additional space.
+pub struct IterSorted<'a, N, K, const D: usize>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    tree: &'a [(N, K)],
+    candidates: DaryHeap<usize, &'a K, D>,
+    remaining: usize,
+}
+
+impl<'a, N, K, const D: usize> IterSorted<'a, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    pub(crate) fn new(tree: &'a [(N, K)]) -> Self {
+        let mut candidates = DaryHeap::default();
+        if let Some(root) = tree.get(offset::<D>()) {
+            candidates.push(offset::<D>(), &root.1);
+        }
+        let remaining = tree.len().saturating_sub(offset::<D>());
+        Self {
+            tree,
+            candidates,
+            remaining,
+        }
+    }
+}
+
+impl<'a, N, K, const D: usize> Iterator for IterSorted<'a, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+    type Item = &'a (N, K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.candidates.pop_node()?;
+
+        let first_child = left_child_of::<D>(position);
+        let end_child = (first_child + D).min(self.tree.len());
+        for child in first_child..end_child {
+            self.candidates.push(child, &self.tree[child].1);
+        }
+
+        self.remaining -= 1;
+        Some(&self.tree[position])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<N, K, const D: usize> ExactSizeIterator for IterSorted<'_, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+}
+
+impl<N, K, const D: usize> FusedIterator for IterSorted<'_, N, K, D>
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+{
+}
@@ -1,5 +1,25 @@
 pub(crate) mod daryheap;
-mod daryheap_const_helpers;
+#[cfg(feature = "arrayvec")]
+pub(crate) mod daryheap_array;
+pub(crate) mod daryheap_bounded;
+pub(crate) mod daryheap_const_helpers;
+pub(crate) mod daryheap_handle;
 pub(crate) mod daryheap_index;
+pub(crate) mod daryheap_lazy;
 pub(crate) mod daryheap_map;
+pub(crate) mod daryheap_minmax;
+#[cfg(feature = "smallvec")]
+pub(crate) mod daryheap_small;
+pub(crate) mod daryheap_sorted_vec;
+pub(crate) mod daryheap_stable;
+pub(crate) mod drain;
+pub(crate) mod drain_sorted;
+pub(crate) mod entry;
 mod heap;
+mod heap_store;
+pub(crate) mod into_iter_sorted;
+pub(crate) mod iter_mut;
+pub(crate) mod iter_sorted;
+#[cfg(feature = "rayon")]
+mod par_heapify;
+pub(crate) mod peek_mut;
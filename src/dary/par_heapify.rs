@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+use rayon::prelude::*;
+
+/// Trees with fewer elements are heapified sequentially since the parallelization overhead dominates.
+const MIN_PAR_LEN: usize = 1 << 14;
+
+/// Minimum number of independent subtrees per thread, which allows rayon to balance the load.
+const SUBTREES_PER_THREAD: usize = 4;
+
+/// Heapifies the `elements` bottom-up, where the tree is laid out without an offset; i.e., the root is at 0
+/// and the children of the element at position p are at positions `D * p + 1..=D * p + D`.
+///
+/// The tree is split into its levels of lengths 1, D, D^2, etc.
+/// The subtrees rooted at the elements of a split level are disjoint, and each subtree is the union of
+/// contiguous chunks of the levels below; hence, they are heapified in parallel.
+/// Then, the levels above the split level are heapified sequentially.
+///
+/// Since the sift-down of an element only involves its own subtree, and each element is sifted down after all of its
+/// descendants, the resulting tree is identical to the one obtained by the sequential bottom-up heapify.
+pub(super) fn par_heapify<N, K, const D: usize>(elements: &mut [(N, K)])
+where
+    N: Send,
+    K: PartialOrd + Send,
+{
+    let len = elements.len();
+    let mut levels = split_levels::<_, D>(elements);
+
+    let min_num_subtrees = rayon::current_num_threads() * SUBTREES_PER_THREAD;
+    let split_level = (0..levels.len())
+        .find(|&level| levels[level].len() >= min_num_subtrees)
+        .filter(|&level| level + 1 < levels.len());
+
+    let parallel_start = match split_level {
+        Some(split_level) if len >= MIN_PAR_LEN => {
+            let (roots, below) = levels[split_level..]
+                .split_first_mut()
+                .expect("split level exists");
+            let mut subtrees: Vec<Vec<&mut [(N, K)]>> =
+                roots.chunks_mut(1).map(|root| alloc::vec![root]).collect();
+
+            let mut chunk_len = 1;
+            for level in below {
+                chunk_len *= D;
+                for (subtree, chunk) in subtrees.iter_mut().zip(level.chunks_mut(chunk_len)) {
+                    subtree.push(chunk);
+                }
+            }
+
+            subtrees.par_iter_mut().for_each(|subtree| {
+                let num_levels = subtree.len();
+                heapify_levels::<_, _, D>(subtree, num_levels)
+            });
+
+            split_level
+        }
+        _ => levels.len(),
+    };
+
+    heapify_levels::<_, _, D>(&mut levels, parallel_start);
+}
+
+/// Splits the tree into its levels, the last of which might be partially filled.
+fn split_levels<T, const D: usize>(mut elements: &mut [T]) -> Vec<&mut [T]> {
+    let mut levels = Vec::new();
+    let mut level_len = 1;
+    while !elements.is_empty() {
+        let (level, rest) = elements.split_at_mut(level_len.min(elements.len()));
+        levels.push(level);
+        elements = rest;
+        level_len = level_len.saturating_mul(D);
+    }
+    levels
+}
+
+/// Sifts down the elements of the levels above `end_level` in reverse order, assuming that the subtrees rooted
+/// at `end_level` are already heaps.
+fn heapify_levels<N, K, const D: usize>(levels: &mut [&mut [(N, K)]], end_level: usize)
+where
+    K: PartialOrd,
+{
+    let end_level = end_level.min(levels.len().saturating_sub(1));
+    for level in (0..end_level).rev() {
+        for position in (0..levels[level].len()).rev() {
+            sift_down::<_, _, D>(levels, level, position);
+        }
+    }
+}
+
+/// Moves the element at the `position` of the `level` down the tree until its key is not greater than
+/// the keys of its children; the first child with the minimum key is selected among equal keys.
+fn sift_down<N, K, const D: usize>(
+    levels: &mut [&mut [(N, K)]],
+    mut level: usize,
+    mut position: usize,
+) where
+    K: PartialOrd,
+{
+    while level + 1 < levels.len() {
+        let (upper, lower) = levels.split_at_mut(level + 1);
+        let children = &mut lower[0];
+
+        let first_child = position * D;
+        if first_child >= children.len() {
+            break;
+        }
+        let last_child = (first_child + D).min(children.len());
+
+        let mut best_child = first_child;
+        for child in (first_child + 1)..last_child {
+            if children[child].1 < children[best_child].1 {
+                best_child = child;
+            }
+        }
+
+        let parent = &mut upper[level][position];
+        match parent.1 > children[best_child].1 {
+            true => core::mem::swap(parent, &mut children[best_child]),
+            false => break,
+        }
+
+        level += 1;
+        position = best_child;
+    }
+}
@@ -0,0 +1,67 @@
+use core::ops::Deref;
+
+/// Access to the root of a heap which is required by [`PeekMut`].
+pub(crate) trait HeapRoot<N, K> {
+    fn root(&self) -> &(N, K);
+
+    fn root_key_mut(&mut self) -> &mut K;
+
+    fn heapify_down_root(&mut self);
+}
+
+/// Structure wrapping a mutable reference to the foremost element of a d-ary heap.
+///
+/// Created by the `peek_mut` method of the d-ary heaps such as [`DaryHeap::peek_mut`].
+///
+/// The guard dereferences to the (node, key) pair of the foremost element.
+/// Its key can be mutated by [`PeekMut::key_mut`]; and the heap is restored once the guard is dropped.
+/// The node itself cannot be mutated since the d-ary heaps with positions use it to locate the element.
+///
+/// [`DaryHeap::peek_mut`]: crate::DaryHeap::peek_mut
+pub struct PeekMut<'a, N, K> {
+    heap: &'a mut dyn HeapRoot<N, K>,
+    key_mutated: bool,
+}
+
+impl<'a, N, K> PeekMut<'a, N, K> {
+    pub(crate) fn new(heap: &'a mut dyn HeapRoot<N, K>) -> Self {
+        Self {
+            heap,
+            key_mutated: false,
+        }
+    }
+
+    /// Returns a reference to the node of the foremost element.
+    pub fn node(&self) -> &N {
+        &self.heap.root().0
+    }
+
+    /// Returns a reference to the key of the foremost element.
+    pub fn key(&self) -> &K {
+        &self.heap.root().1
+    }
+
+    /// Returns a mutable reference to the key of the foremost element.
+    ///
+    /// If the key is increased, the element moves down to its correct position on the heap once the guard is dropped.
+    pub fn key_mut(&mut self) -> &mut K {
+        self.key_mutated = true;
+        self.heap.root_key_mut()
+    }
+}
+
+impl<N, K> Deref for PeekMut<'_, N, K> {
+    type Target = (N, K);
+
+    fn deref(&self) -> &Self::Target {
+        self.heap.root()
+    }
+}
+
+impl<N, K> Drop for PeekMut<'_, N, K> {
+    fn drop(&mut self) {
+        if self.key_mutated {
+            self.heap.heapify_down_root();
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{NodeKeyRef, PriorityQueue};
+
+/// An object-safe counterpart of [`PriorityQueue`] which allows to use priority queues as trait objects,
+/// such as `Box<dyn DynPriorityQueue<N, K>>`, in order to choose the queue implementation at runtime.
+///
+/// `PriorityQueue` itself cannot be made into a trait object due to its generic associated types `NodeKey` and `Iter`.
+/// `DynPriorityQueue` exposes the subset of its methods which neither borrow the elements nor are generic;
+/// in particular, [`peek_cloned`] replaces the borrowing `peek`.
+///
+/// The trait is automatically implemented for all priority queues with cloneable nodes and keys.
+///
+/// In order not to clash with the methods of `PriorityQueue` on the concrete queues, the methods of the trait are prefixed by `dyn_`.
+/// The trait objects, on the other hand, additionally provide the methods with the usual names such as `len`, `push` or `pop`.
+///
+/// [`peek_cloned`]: DynPriorityQueue::peek_cloned
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// fn new_queue(arity: usize) -> Box<dyn DynPriorityQueue<char, u32>> {
+///     match arity {
+///         2 => Box::new(BinaryHeap::default()),
+///         4 => Box::new(QuaternaryHeap::default()),
+///         _ => Box::new(DaryHeap::<_, _, 8>::default()),
+///     }
+/// }
+///
+/// for arity in [2, 4, 8] {
+///     let mut queue = new_queue(arity);
+///
+///     queue.push('a', 42);
+///     queue.push('b', 7);
+///     queue.push('c', 99);
+///
+///     assert_eq!(3, queue.len());
+///     assert_eq!(Some(('b', 7)), queue.peek_cloned());
+///     assert_eq!(Some(('b', 7)), queue.pop());
+///     assert_eq!(Some('a'), queue.pop_node());
+///     assert_eq!(Some(99), queue.pop_key());
+///     assert!(queue.is_empty());
+/// }
+/// ```
+pub trait DynPriorityQueue<N, K>
+where
+    K: PartialOrd,
+{
+    /// Number of elements in the queue; see [`PriorityQueue::len`].
+    fn dyn_len(&self) -> usize;
+
+    /// Returns whether the queue is empty or not; see [`PriorityQueue::is_empty`].
+    fn dyn_is_empty(&self) -> bool {
+        self.dyn_len() == 0
+    }
+
+    /// Returns a clone of the element with the least key in the queue, if the queue is not empty; returns None otherwise.
+    ///
+    /// It is the object-safe counterpart of [`PriorityQueue::peek`] which returns references to the node and key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// assert_eq!(None, queue.peek_cloned());
+    ///
+    /// queue.push(String::from("x"), 42);
+    /// queue.push(String::from("y"), 7);
+    /// assert_eq!(Some((String::from("y"), 7)), queue.peek_cloned());
+    /// assert_eq!(2, queue.len());
+    /// ```
+    fn peek_cloned(&self) -> Option<(N, K)>;
+
+    /// Clears the queue; see [`PriorityQueue::clear`].
+    fn dyn_clear(&mut self);
+
+    /// Removes and returns the (node, key) pair with the lowest key in the queue; see [`PriorityQueue::pop`].
+    fn dyn_pop(&mut self) -> Option<(N, K)>;
+
+    /// Removes the element with the lowest key in the queue and returns its node; see [`PriorityQueue::pop_node`].
+    fn dyn_pop_node(&mut self) -> Option<N>;
+
+    /// Removes the element with the lowest key in the queue and returns its key; see [`PriorityQueue::pop_key`].
+    fn dyn_pop_key(&mut self) -> Option<K>;
+
+    /// Pushes the given (`node`, `key`) pair to the queue; see [`PriorityQueue::push`].
+    fn dyn_push(&mut self, node: N, key: K);
+}
+
+impl<N, K, P> DynPriorityQueue<N, K> for P
+where
+    N: Clone,
+    K: PartialOrd + Clone,
+    P: PriorityQueue<N, K>,
+{
+    #[inline(always)]
+    fn dyn_len(&self) -> usize {
+        self.len()
+    }
+
+    fn peek_cloned(&self) -> Option<(N, K)> {
+        self.peek().map(|x| (x.node().clone(), x.key().clone()))
+    }
+
+    #[inline(always)]
+    fn dyn_clear(&mut self) {
+        self.clear()
+    }
+
+    #[inline(always)]
+    fn dyn_pop(&mut self) -> Option<(N, K)> {
+        self.pop()
+    }
+
+    #[inline(always)]
+    fn dyn_pop_node(&mut self) -> Option<N> {
+        self.pop_node()
+    }
+
+    #[inline(always)]
+    fn dyn_pop_key(&mut self) -> Option<K> {
+        self.pop_key()
+    }
+
+    #[inline(always)]
+    fn dyn_push(&mut self, node: N, key: K) {
+        self.push(node, key)
+    }
+}
+
+impl<N, K> dyn DynPriorityQueue<N, K> + '_
+where
+    K: PartialOrd,
+{
+    /// Number of elements in the queue.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.dyn_len()
+    }
+
+    /// Returns whether the queue is empty or not.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.dyn_is_empty()
+    }
+
+    /// Clears the queue.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.dyn_clear()
+    }
+
+    /// Removes and returns the (node, key) pair with the lowest key in the queue;
+    /// returns None if the queue is empty.
+    #[inline(always)]
+    pub fn pop(&mut self) -> Option<(N, K)> {
+        self.dyn_pop()
+    }
+
+    /// Removes the element with the lowest key in the queue, and returns its node;
+    /// returns None if the queue is empty.
+    #[inline(always)]
+    pub fn pop_node(&mut self) -> Option<N> {
+        self.dyn_pop_node()
+    }
+
+    /// Removes the element with the lowest key in the queue, and returns its key;
+    /// returns None if the queue is empty.
+    #[inline(always)]
+    pub fn pop_key(&mut self) -> Option<K> {
+        self.dyn_pop_key()
+    }
+
+    /// Pushes the given (`node`, `key`) pair to the queue.
+    #[inline(always)]
+    pub fn push(&mut self, node: N, key: K) {
+        self.dyn_push(node, key)
+    }
+}
@@ -0,0 +1,109 @@
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+
+/// A totally ordered `f64` key which can safely be used as the key of the priority queues.
+///
+/// The queues only require `PartialOrd` keys; however, a raw `f64` NaN key is not comparable to any key,
+/// and hence, a heap holding a NaN key silently violates its order, after which the behavior of the heap is unspecified.
+/// Wrapping the float keys in `FloatKey` prevents this trap by the total order where:
+/// * NaN is greater than all other values including positive infinity, and all NaNs are equal to each other, and
+/// * `-0.0` is equal to `0.0`;
+///
+/// and all other values are ordered as `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = BinaryHeap::default();
+///
+/// queue.push('a', FloatKey::new(f64::NAN));
+/// queue.push('b', FloatKey::from(2.5));
+/// queue.push('c', 1.5.into());
+///
+/// assert_eq!(Some(('c', FloatKey::new(1.5))), queue.pop());
+/// assert_eq!(Some(2.5), queue.pop().map(|(_, key)| key.get()));
+///
+/// let (node, key) = queue.pop().unwrap();
+/// assert_eq!('a', node);
+/// assert!(f64::from(key).is_nan());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct FloatKey(pub f64);
+
+impl FloatKey {
+    /// Creates a key wrapping the given `value`.
+    pub const fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped `f64` value.
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl PartialEq for FloatKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FloatKey {}
+
+impl PartialOrd for FloatKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FloatKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (false, false) => self
+                .0
+                .partial_cmp(&other.0)
+                .expect("non-NaN floats are comparable"),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (true, true) => Ordering::Equal,
+        }
+    }
+}
+
+/// Equal keys have equal hashes; i.e., `-0.0` and `0.0` are hashed the same, and so are all NaNs.
+impl Hash for FloatKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let bits = match (self.0.is_nan(), self.0 == 0.0) {
+            (true, _) => f64::NAN.to_bits(),
+            (false, true) => 0.0f64.to_bits(),
+            (false, false) => self.0.to_bits(),
+        };
+        bits.hash(state);
+    }
+}
+
+impl Display for FloatKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<f64> for FloatKey {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<FloatKey> for f64 {
+    fn from(key: FloatKey) -> Self {
+        key.0
+    }
+}
@@ -1,3 +1,5 @@
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
 /// A struct which provides an index of type [usize].
 /// Index of the struct can be considered its unchanging id
 /// defined its position in a collection.
@@ -10,6 +12,43 @@
 /// this convention allows to replace a hashmap with an array.
 /// This may be useful in simplifying the algorithms and improving performance when
 /// the elements entering the queue are sampled from a closed and known set, as the cities above.
+///
+/// `HasIndex` is implemented for unsigned integers and their non-zero counterparts,
+/// where the index of a non-zero integer `n` is `n - 1` so that the index zero is usable.
+/// It is also implemented for single element tuples `(T,)` and references `&T` where `T: HasIndex`;
+/// the latter allows to use references to nodes which are stored elsewhere as the nodes of the queue,
+/// such as `DaryHeapOfIndices<&City, K>`, without copying the nodes or wrapping them in newtypes.
+///
+/// Further, `HasIndex` is implemented for `bool` with indices 0 and 1, requiring an `index_bound` of 2;
+/// and for `char` where the index is its Unicode scalar value.
+/// Note that the index of a `char` can be as large as `0x10FFFF`; hence, an `index_bound` of `0x110000`
+/// is required to cover all characters, while an `index_bound` of 128 suffices for ASCII characters.
+///
+/// # Examples
+///
+/// Node ids are often represented by newtypes wrapping an integer,
+/// in which case the index can be forwarded to the wrapped integer.
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct CityId(u32);
+///
+/// impl HasIndex for CityId {
+///     fn index(&self) -> usize {
+///         self.0.index()
+///     }
+/// }
+///
+/// let mut queue = QuaternaryHeapOfIndices::with_index_bound(3);
+/// queue.push(CityId(2), 42);
+/// queue.push(CityId(0), 7);
+/// assert_eq!(Some((CityId(0), 7)), queue.pop());
+/// ```
+///
+/// Alternatively, with the `derive` feature, the implementation above can be derived by `#[derive(HasIndex)]`.
+/// The derive macro forwards to the single field of a newtype, or to the field annotated with `#[has_index]`.
 pub trait HasIndex: Clone {
     /// Returns the index of the element.
     fn index(&self) -> usize;
@@ -45,3 +84,60 @@ impl HasIndex for u8 {
         *self as usize
     }
 }
+
+impl HasIndex for NonZeroUsize {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        self.get() - 1
+    }
+}
+impl HasIndex for NonZeroU64 {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        self.get() as usize - 1
+    }
+}
+impl HasIndex for NonZeroU32 {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        self.get() as usize - 1
+    }
+}
+impl HasIndex for NonZeroU16 {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        self.get() as usize - 1
+    }
+}
+impl HasIndex for NonZeroU8 {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        self.get() as usize - 1
+    }
+}
+
+impl HasIndex for char {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+impl HasIndex for bool {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl<T: HasIndex> HasIndex for (T,) {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        self.0.index()
+    }
+}
+impl<T: HasIndex> HasIndex for &T {
+    #[inline(always)]
+    fn index(&self) -> usize {
+        (**self).index()
+    }
+}
@@ -0,0 +1,74 @@
+use core::fmt::{Display, Formatter};
+
+/// Error describing the first violated invariant of a heap, returned by the `validate` method of the d-ary heaps
+/// such as [`DaryHeap::validate`].
+///
+/// Positions refer to indices of the elements in the slice of elements of the heap,
+/// which can be obtained by the `as_slice` method.
+///
+/// [`DaryHeap::validate`]: crate::DaryHeap::validate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeapError {
+    /// Key of the element at the `child` position is strictly less than the key of the element at its `parent` position.
+    KeyOrder {
+        /// Position of the parent element.
+        parent: usize,
+        /// Position of the child element.
+        child: usize,
+    },
+    /// Position of the node of the element at the given `position` is not tracked correctly.
+    PositionMismatch {
+        /// Position of the element whose node is not tracked correctly.
+        position: usize,
+    },
+    /// Number of nodes whose positions are tracked is different than the number of elements on the heap.
+    PositionsLength {
+        /// Number of nodes whose positions are tracked.
+        num_positions: usize,
+        /// Number of elements on the heap.
+        len: usize,
+    },
+    /// Key of the element at the `descendant` position is on the wrong side of the key of the element at its
+    /// `ancestor` position, which is its parent or grandparent, on a [`DaryMinMaxHeap`].
+    ///
+    /// [`DaryMinMaxHeap`]: crate::DaryMinMaxHeap
+    MinMaxOrder {
+        /// Position of the ancestor element.
+        ancestor: usize,
+        /// Position of the descendant element.
+        descendant: usize,
+    },
+}
+
+impl Display for HeapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::KeyOrder { parent, child } => write!(
+                f,
+                "key of the element at position {} is less than the key of its parent at position {}",
+                child, parent
+            ),
+            Self::PositionMismatch { position } => write!(
+                f,
+                "position of the node of the element at position {} is not tracked correctly",
+                position
+            ),
+            Self::PositionsLength { num_positions, len } => write!(
+                f,
+                "positions of {} nodes are tracked while the heap has {} elements",
+                num_positions, len
+            ),
+            Self::MinMaxOrder {
+                ancestor,
+                descendant,
+            } => write!(
+                f,
+                "key of the element at position {} violates the min-max order with its ancestor at position {}",
+                descendant, ancestor
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeapError {}
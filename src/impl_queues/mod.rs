@@ -2,3 +2,6 @@ mod std_binary_heap;
 
 #[cfg(feature = "impl_priority_queue")]
 mod priority_queue;
+
+#[cfg(feature = "impl_priority_queue")]
+mod double_priority_queue;
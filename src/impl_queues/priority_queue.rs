@@ -19,6 +19,11 @@ where
         priority_queue::PriorityQueue::capacity(self)
     }
 
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(2)
+    }
+
     #[inline(always)]
     fn peek(&self) -> Option<Self::NodeKey<'_>> {
         priority_queue::PriorityQueue::peek(self)
@@ -75,7 +80,12 @@ where
         priority_queue::PriorityQueue::get(self, node).map(|x| x.1.clone())
     }
 
-    fn decrease_key(&mut self, node: &N, decreased_key: K) {
+    #[inline(always)]
+    fn key_ref(&self, node: &N) -> Option<&K> {
+        priority_queue::PriorityQueue::get(self, node).map(|x| x.1)
+    }
+
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K {
         let old_key =
             priority_queue::PriorityQueue::change_priority(self, node, decreased_key.clone())
                 .expect("Failed to update key of the node, it is not present in the queue");
@@ -85,6 +95,7 @@ where
             None
         })
         .expect("Failed to decrease the key of the node, received a greater key");
+        old_key
     }
 
     fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
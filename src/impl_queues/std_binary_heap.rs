@@ -18,6 +18,11 @@ where
         alloc::collections::BinaryHeap::capacity(self)
     }
 
+    #[inline(always)]
+    fn arity(&self) -> Option<usize> {
+        Some(2)
+    }
+
     #[inline(always)]
     fn peek(&self) -> Option<&(N, K)> {
         alloc::collections::BinaryHeap::peek(self)
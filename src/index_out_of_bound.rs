@@ -0,0 +1,26 @@
+use core::fmt::{Display, Formatter};
+
+/// Error returned when a node with an index greater than or equal to the index bound of a heap
+/// is attempted to be pushed, such as by [`DaryHeapOfIndices::try_push`].
+///
+/// [`DaryHeapOfIndices::try_push`]: crate::DaryHeapOfIndices::try_push
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBound {
+    /// Index of the node which is attempted to be pushed.
+    pub index: usize,
+    /// Index bound of the heap; i.e., indices of nodes must be strictly less than the bound.
+    pub bound: usize,
+}
+
+impl Display for IndexOutOfBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "index of the node {} is out of the index bound {} of the heap",
+            self.index, self.bound
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOutOfBound {}
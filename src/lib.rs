@@ -14,13 +14,15 @@
 //!
 //! See [DecreaseKey](https://github.com/orxfun/orx-priority-queue/blob/main/docs/DecreaseKey.md) section for a discussion on when decrease-key operations are required and why they are important.
 //!
+//! Since `PriorityQueue<N, K>` has generic associated types, it cannot be used as a trait object. The object-safe **`DynPriorityQueue<N, K>`** is automatically implemented for all priority queues with cloneable nodes and keys, and allows to choose the queue implementation at runtime, such as `Box<dyn DynPriorityQueue<N, K>>`.
+//!
 //! ## B. d-ary Heap Implementations
 //!
 //! d-ary implementations are generalizations of the binary heap; i.e., binary heap is a special case where `D=2`. It is advantageous to have a parametrized d; as for instance, in the benchmarks defined here, `D=4` outperforms `D=2`.
 //! * With a large d: number of per level comparisons increases while the tree depth becomes smaller.
 //! * With a small d: each level requires fewer comparisons while the tree with the same number of nodes is deeper.
 //!
-//! Further, three categories of d-ary heap implementations are introduced.
+//! Further, six categories of d-ary heap implementations are introduced.
 //!
 //! ### 1. DaryHeap (PriorityQueue)
 //!
@@ -45,6 +47,34 @@
 //!
 //! This is the most general decrease-key queue that provides the open-set flexibility and fits to almost all cases.
 //!
+//! The hasher of the positions map can be customized by the `S: BuildHasher` type parameter, which defaults to `RandomState`, with `with_hasher` or `with_capacity_and_hasher`. A faster hasher such as `FxBuildHasher` noticeably speeds up the decrease-key operations with integer nodes.
+//!
+//! Alternatively, `DaryHeapWithSortedVec` keeps the positions in a vector of (node, position) pairs sorted by the nodes, requiring only `Ord + Clone` nodes. Compared to the `BTreeMap` used when no-std, it trades *O(n)* insertions and removals of nodes for faster *O(log n)* lookups and a smaller memory footprint; hence, it fits small to medium numbers of nodes.
+//!
+//! ### 4. DaryHeapOfHandles (PriorityQueue)
+//!
+//! This is a d-ary heap whose `push` method returns a stable `Handle` to the pushed node. The handle can later be used to access, decrease or update the key of, or remove the node, as long as the node is in the queue.
+//!
+//! It neither requires the nodes to implement `HasIndex` nor `Hash + Eq`; in return, nodes are addressed by their handles rather than by themselves.
+//!
+//! ### 5. LazyDecKeyHeap (PriorityQueue + PriorityQueueDecKey)
+//!
+//! This is a plain `DaryHeap` paired up with a map of nodes to their current keys, which implements `PriorityQueueDecKey` by lazy deletion. Key updates push the node again with its new key, and the stale entries are skipped when they reach the root.
+//!
+//! It avoids tracking the positions of the nodes on every swap; and hence, it might outperform `DaryHeapWithMap` in workloads with many decrease-key operations.
+//!
+//! ### 6. StableDaryHeap (PriorityQueue)
+//!
+//! This is a `DaryHeap` which breaks the ties among equal keys by the insertion order, so that the elements with equal keys are popped in first-in-first-out order. This leads to reproducible results for the algorithms sensitive to the order of the elements, at the cost of an additional `usize` sequence number per element.
+//!
+//! ### 7. BoundedDaryHeap
+//!
+//! This is a max-heap of at most `bound` elements built on a `DaryHeap`, which keeps the `bound` elements with the smallest keys among all elements pushed to it. It is the streaming primitive for the running k smallest elements: once full, a pushed element replaces the element with the largest key if its key is smaller, and it is discarded otherwise.
+//!
+//! ### 8. DaryMinMaxHeap
+//!
+//! This is a double-ended d-ary heap where the levels of the tree alternate between min and max levels. Both the element with the smallest key and the element with the largest key are peeked in constant time and popped in *O(log n)* time by `peek_min`, `peek_max`, `pop_min` and `pop_max`; while `PriorityQueue` is implemented by its min side.
+//!
 //! ### Other Queues
 //!
 //! In addition, queue implementations are provided in this crate for the following external data structures:
@@ -52,9 +82,31 @@
 //! * `std::collections::BinaryHeap<(N, K)>` implements only `PriorityQueue<N, K>`,
 //! * `priority_queue:PriorityQueue<N, K>` implements both `PriorityQueue<N, K>` and `PriorityQueueDecKey<N, K>`
 //!   * requires `--features impl_priority_queue`
+//! * `priority_queue:DoublePriorityQueue<N, K>` implements both `PriorityQueue<N, K>` and `PriorityQueueDecKey<N, K>` on its min side
+//!   * requires `--features impl_priority_queue`
+//!
+//! Further, `UnsortedQueue<N, K>` is a thin wrapper around a `Vec<(N, K)>` implementing both traits by linear scans, with a constant time `push`. It is often faster than the heaps for tiny queues; in the `unsorted_queue` benchmark, it outperforms the binary heaps for queues of up to around 64 elements.
+//!
+//! With `--features smallvec`, `SmallDaryHeap<N, K, D, INLINE>` is a `DaryHeap` storing its tree in a `smallvec::SmallVec` which stays inline until the tree exceeds `INLINE` elements. It avoids the allocation of the tree for many short lived tiny queues; in the `small_queue` benchmark, it is around 30% faster than `DaryHeap` for queues of 4 elements, and on par for queues of 16 elements.
+//!
+//! With `--features arrayvec`, `ArrayDaryHeap<N, K, D, CAP>` is a `DaryHeap` storing its tree in an `arrayvec::ArrayVec` with the compile time capacity `CAP`, which never allocates. Since it cannot grow, its `try_push` hands the rejected (node, key) pair back when the heap is full. Note that `CAP` includes the `D - 1` padding cells of the tree when `D` is a power of two; `ArrayDaryHeap::max_len` returns the number of elements the heap can hold.
 //!
 //! This allows to use all the queue implementations interchangeably and pick the one fitting best to the use case.
 //!
+//! ### Float Keys
+//!
+//! The queues require the keys to be `PartialOrd`, and hence, accept `f64` keys. However, a NaN key is not comparable to any key; a heap holding a raw `f64` NaN key silently violates its order, after which its behavior is unspecified. `FloatKey` is a thin wrapper around `f64` with a total order, where NaN is greater than all other values and `-0.0` is equal to `0.0`. It is recommended to wrap the float keys in `FloatKey` whenever a NaN might sneak in, such as the edge weights of a Dijkstra's shortest path computation.
+//!
+//! Alternatively, with `--features debug_nan_check`, the d-ary heaps assert in debug builds that the keys given to `push`, `decrease_key`, `update_key` and alike are comparable to themselves, and panic immediately on a NaN key. The check is compiled out of release builds.
+//!
+//! ### Serialization
+//!
+//! With `--features serde`, d-ary heaps implement `Serialize` and `Deserialize`. Heaps are serialized as the sequence of their (node, key) pairs, together with the `index_bound` in the case of `DaryHeapOfIndices`; and they are rebuilt with a single bottom-up heapify on deserialization.
+//!
+//! ### Parallel Construction
+//!
+//! With `--features rayon`, `DaryHeap::par_from_vec` builds a heap from a vector by heapifying the independent subtrees of the heap in parallel before heapifying the few levels above them sequentially. The resulting heap is identical to the one built by `DaryHeap::from(vec)`; parallelization pays off for vectors of millions of elements, which can be measured by the `par_from_vec` benchmark.
+//!
 //! ### Performance & Benchmarks
 //!
 //! *You may find the details of the benchmarks at [benches](https://github.com/orxfun/orx-priority-queue/blob/main/benches) folder.*
@@ -329,20 +381,66 @@ extern crate std;
 extern crate alloc;
 
 mod dary;
+mod dyn_priority_queue;
+mod float_key;
 mod has_index;
+mod heap_error;
 mod impl_queues;
+mod index_out_of_bound;
 mod node_key_ref;
 mod positions;
 mod priority_queue;
 mod priority_queue_deckey;
+mod rename_error;
+mod unsorted_queue;
 
 pub use crate::priority_queue::PriorityQueue;
-pub use dary::daryheap::{BinaryHeap, DaryHeap, QuaternaryHeap};
-pub use dary::daryheap_index::{BinaryHeapOfIndices, DaryHeapOfIndices, QuaternaryHeapOfIndices};
-pub use dary::daryheap_map::{BinaryHeapWithMap, DaryHeapWithMap, QuaternaryHeapWithMap};
+pub use dary::daryheap::{BinaryHeap, DaryHeap, OctaryHeap, QuaternaryHeap, TernaryHeap};
+#[cfg(feature = "arrayvec")]
+pub use dary::daryheap_array::ArrayDaryHeap;
+pub use dary::daryheap_bounded::BoundedDaryHeap;
+pub use dary::daryheap_const_helpers::heap_offset;
+pub use dary::daryheap_handle::{
+    BinaryHeapOfHandles, DaryHeapOfHandles, IterHandles, QuaternaryHeapOfHandles,
+};
+pub use dary::daryheap_index::{
+    BinaryHeapOfIndices, DaryHeapOfIndices, OctaryHeapOfIndices, QuaternaryHeapOfIndices,
+    TernaryHeapOfIndices,
+};
+pub use dary::daryheap_lazy::{IterLazy, LazyDecKeyHeap};
+pub use dary::daryheap_map::{
+    BinaryHeapWithMap, DaryHeapWithMap, OctaryHeapWithMap, QuaternaryHeapWithMap,
+    TernaryHeapWithMap,
+};
+pub use dary::daryheap_minmax::DaryMinMaxHeap;
+#[cfg(feature = "smallvec")]
+pub use dary::daryheap_small::SmallDaryHeap;
+pub use dary::daryheap_sorted_vec::{
+    BinaryHeapWithSortedVec, DaryHeapWithSortedVec, QuaternaryHeapWithSortedVec,
+};
+pub use dary::daryheap_stable::{IterStable, StableDaryHeap};
+pub use dary::drain::Drain;
+pub use dary::drain_sorted::DrainSorted;
+pub use dary::entry::{Entry, OccupiedEntry, VacantEntry};
+pub use dary::into_iter_sorted::IntoIterSorted;
+pub use dary::iter_mut::IterMut;
+pub use dary::iter_sorted::IterSorted;
+pub use dary::peek_mut::PeekMut;
+pub use dyn_priority_queue::DynPriorityQueue;
+pub use float_key::FloatKey;
 pub use has_index::HasIndex;
+pub use heap_error::HeapError;
+pub use index_out_of_bound::IndexOutOfBound;
 pub use node_key_ref::NodeKeyRef;
+#[cfg(feature = "derive")]
+pub use orx_priority_queue_derive::HasIndex;
+pub use positions::handles::Handle;
+pub use positions::has_index::HeapPositionsHasIndex;
+pub use positions::map::{DefaultHashBuilder, HeapPositionsMap};
+pub use positions::none::HeapPositionsNone;
 pub use priority_queue_deckey::{
     PriorityQueueDecKey, ResDecreaseKeyOrPush, ResTryDecreaseKey, ResTryDecreaseKeyOrPush,
-    ResUpdateKey, ResUpdateKeyOrPush,
+    ResTryIncreaseKey, ResUpdateKey, ResUpdateKeyOrPush,
 };
+pub use rename_error::RenameError;
+pub use unsorted_queue::UnsortedQueue;
@@ -9,6 +9,25 @@ where
 
     /// Returns a reference to the key/priority of the node.
     fn key(&self) -> &'a K;
+
+    /// Returns references to the node and its key as a tuple,
+    /// which allows destructuring the pair in one step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push('a', 42);
+    /// queue.push('b', 7);
+    ///
+    /// let (node, key) = queue.peek().unwrap().as_tuple();
+    /// assert_eq!((&'b', &7), (node, key));
+    /// ```
+    fn as_tuple(&self) -> (&'a N, &'a K) {
+        (self.node(), self.key())
+    }
 }
 
 impl<'a, N, K> NodeKeyRef<'a, N, K> for &'a (N, K)
@@ -0,0 +1,144 @@
+use super::heap_positions::{HeapPositions, HeapPositionsDecKey};
+use crate::HeapError;
+use alloc::vec::Vec;
+
+/// using usize::MAX as None
+const NONE: usize = usize::MAX;
+
+/// A stable handle to a node pushed to a [`DaryHeapOfHandles`], which is returned by its `push` method.
+///
+/// A handle is a generational index into the slots of the heap.
+/// It remains valid while its node is in the queue, regardless of the movements of the node on the heap.
+/// Once the node leaves the queue by `pop` or `remove`, the handle is invalidated;
+/// and it is never valid again, even when its slot is reused by a node pushed later.
+///
+/// [`DaryHeapOfHandles`]: crate::DaryHeapOfHandles
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Handle {
+    index: usize,
+    generation: usize,
+}
+
+/// A node of the heap together with its handle.
+#[derive(Clone, Debug)]
+pub(crate) struct HandleNode<N> {
+    pub(crate) handle: Handle,
+    pub(crate) node: N,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Slot {
+    generation: usize,
+    position: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HeapPositionsHandles {
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+}
+
+impl HeapPositionsHandles {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+        }
+    }
+
+    /// Allocates a slot for a node which is about to be pushed, and returns its handle.
+    pub fn allocate(&mut self) -> Handle {
+        match self.free.pop() {
+            Some(index) => Handle {
+                index,
+                generation: self.slots[index].generation,
+            },
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    position: NONE,
+                });
+                Handle {
+                    index: self.slots.len() - 1,
+                    generation: 0,
+                }
+            }
+        }
+    }
+
+    /// Returns the position of the node with the given handle; None if the handle is invalidated.
+    pub fn position_of_handle(&self, handle: &Handle) -> Option<usize> {
+        self.slots
+            .get(handle.index)
+            .filter(|slot| slot.generation == handle.generation && slot.position != NONE)
+            .map(|slot| slot.position)
+    }
+
+    fn release(&mut self, index: usize) {
+        let slot = &mut self.slots[index];
+        slot.position = NONE;
+        slot.generation += 1;
+        self.free.push(index);
+    }
+}
+
+impl<N> HeapPositions<HandleNode<N>> for HeapPositionsHandles {
+    fn clear(&mut self) {
+        for index in 0..self.slots.len() {
+            if self.slots[index].position != NONE {
+                self.release(index);
+            }
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let num_new_slots = additional.saturating_sub(self.free.len());
+        self.slots.reserve(num_new_slots);
+    }
+
+    #[inline(always)]
+    fn contains(&self, node: &HandleNode<N>) -> bool {
+        self.position_of_handle(&node.handle).is_some()
+    }
+
+    #[inline(always)]
+    fn position_of(&self, node: &HandleNode<N>) -> Option<usize> {
+        self.position_of_handle(&node.handle)
+    }
+
+    fn insert(&mut self, node: &HandleNode<N>, position: usize) {
+        let slot = &mut self.slots[node.handle.index];
+        debug_assert_eq!(slot.generation, node.handle.generation);
+        debug_assert_eq!(slot.position, NONE, "re-inserting already added node");
+        slot.position = position;
+    }
+
+    fn remove(&mut self, node: &HandleNode<N>) {
+        debug_assert!(self.contains(node), "removing an absent node");
+        self.release(node.handle.index);
+    }
+
+    fn update_position_of(&mut self, node: &HandleNode<N>, position: usize) {
+        debug_assert!(self.contains(node), "updating position of an absent node");
+        self.slots[node.handle.index].position = position;
+    }
+
+    fn validate<K>(&self, offset: usize, tree: &[(HandleNode<N>, K)]) -> Result<(), HeapError> {
+        let len = tree.len().saturating_sub(offset);
+        let num_positions = self.slots.iter().filter(|s| s.position != NONE).count();
+        if num_positions != len {
+            return Err(HeapError::PositionsLength { num_positions, len });
+        }
+
+        for (position, (node, _)) in tree.iter().enumerate().skip(offset) {
+            if self.position_of_handle(&node.handle) != Some(position) {
+                return Err(HeapError::PositionMismatch {
+                    position: position - offset,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N> HeapPositionsDecKey<HandleNode<N>> for HeapPositionsHandles {}
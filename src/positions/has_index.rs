@@ -1,5 +1,5 @@
 use super::heap_positions::{HeapPositions, HeapPositionsDecKey};
-use crate::HasIndex;
+use crate::{HasIndex, HeapError};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
@@ -7,7 +7,10 @@ use core::marker::PhantomData;
 /// using usize::MAX as None
 const NONE: usize = usize::MAX;
 
-#[derive(Clone, Debug)]
+/// Positions of the nodes of a [`DaryHeapOfIndices`](crate::DaryHeapOfIndices) stored in an array
+/// of length index bound, indexed by the indices of the nodes;
+/// obtained from [`DaryHeapOfIndices::into_parts`](crate::DaryHeapOfIndices::into_parts).
+#[derive(Debug)]
 pub struct HeapPositionsHasIndex<N>
 where
     N: HasIndex,
@@ -15,11 +18,26 @@ where
     positions: Vec<usize>,
     ph: PhantomData<N>,
 }
+impl<N> Clone for HeapPositionsHasIndex<N>
+where
+    N: HasIndex,
+{
+    fn clone(&self) -> Self {
+        Self {
+            positions: self.positions.clone(),
+            ph: PhantomData,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.positions.clone_from(&source.positions);
+    }
+}
 impl<N> HeapPositionsHasIndex<N>
 where
     N: HasIndex,
 {
-    pub fn with_index_bound(index_bound: usize) -> Self {
+    pub(crate) fn with_index_bound(index_bound: usize) -> Self {
         Self {
             positions: vec![NONE; index_bound],
             ph: PhantomData,
@@ -28,6 +46,28 @@ where
     pub(crate) fn index_bound(&self) -> usize {
         self.positions.len()
     }
+    /// Returns the position of the node with the given `index`; None if the node is not on the heap
+    /// or the `index` is out of bounds.
+    pub(crate) fn position_of_index(&self, index: usize) -> Option<usize> {
+        match self.positions.get(index) {
+            None | Some(&NONE) => None,
+            Some(&position) => Some(position),
+        }
+    }
+    pub(crate) fn grow_index_bound(&mut self, new_bound: usize) {
+        if new_bound > self.positions.len() {
+            self.positions.resize(new_bound, NONE);
+        }
+    }
+    /// Shrinks the positions to the `new_bound` and releases the excess memory;
+    /// the caller guarantees that no node with an index greater than or equal to `new_bound` is on the heap.
+    pub(crate) fn shrink_index_bound(&mut self, new_bound: usize) {
+        if new_bound < self.positions.len() {
+            debug_assert!(self.positions[new_bound..].iter().all(|&p| p == NONE));
+            self.positions.truncate(new_bound);
+            self.positions.shrink_to_fit();
+        }
+    }
 }
 impl<N> HeapPositions<N> for HeapPositionsHasIndex<N>
 where
@@ -65,18 +105,21 @@ where
         self.positions[node.index()] = position;
     }
 
-    #[cfg(test)]
-    fn is_valid<K>(&self, offset: usize, tree: &[(N, K)]) -> bool {
-        let mut count = 0;
-        for (node, &pos) in self.positions.iter().enumerate() {
-            if pos != NONE {
-                count += 1;
-                if tree[pos].0.index() != node {
-                    return false;
-                }
+    fn validate<K>(&self, offset: usize, tree: &[(N, K)]) -> Result<(), HeapError> {
+        let len = tree.len().saturating_sub(offset);
+        let num_positions = self.positions.iter().filter(|&&p| p != NONE).count();
+        if num_positions != len {
+            return Err(HeapError::PositionsLength { num_positions, len });
+        }
+
+        for (position, (node, _)) in tree.iter().enumerate().skip(offset) {
+            if self.positions.get(node.index()) != Some(&position) {
+                return Err(HeapError::PositionMismatch {
+                    position: position - offset,
+                });
             }
         }
-        count == tree.len() - offset
+        Ok(())
     }
 }
 
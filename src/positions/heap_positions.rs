@@ -1,3 +1,5 @@
+use crate::HeapError;
+
 pub(crate) trait HeapPositions<N>: Clone {
     fn contains(&self, node: &N) -> bool;
 
@@ -11,8 +13,10 @@ pub(crate) trait HeapPositions<N>: Clone {
 
     fn update_position_of(&mut self, node: &N, position: usize);
 
-    #[cfg(test)]
-    fn is_valid<K>(&self, offset: usize, tree: &[(N, K)]) -> bool;
+    fn validate<K>(&self, offset: usize, tree: &[(N, K)]) -> Result<(), HeapError>;
+
+    /// Reserves capacity for at least `additional` more nodes; a no-op for the positions without a growable storage.
+    fn reserve(&mut self, _additional: usize) {}
 }
 
 pub(crate) trait HeapPositionsDecKey<N>: HeapPositions<N> {}
@@ -1,9 +1,16 @@
 use super::heap_positions::{HeapPositions, HeapPositionsDecKey};
+use crate::HeapError;
+use core::borrow::Borrow;
 
 #[cfg(not(feature = "std"))]
 use alloc::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use core::marker::PhantomData;
 #[cfg(feature = "std")]
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hash},
+};
 
 #[cfg(not(feature = "std"))]
 pub trait Index: Eq + Clone + Ord {}
@@ -14,48 +21,156 @@ pub trait Index: Eq + Clone + Hash {}
 #[cfg(feature = "std")]
 impl<T> Index for T where T: Eq + Clone + Hash {}
 
+#[cfg(not(feature = "std"))]
+pub trait Lookup: Ord {}
+#[cfg(not(feature = "std"))]
+impl<T> Lookup for T where T: Ord + ?Sized {}
+#[cfg(feature = "std")]
+pub trait Lookup: Eq + Hash {}
+#[cfg(feature = "std")]
+impl<T> Lookup for T where T: Eq + Hash + ?Sized {}
+
+#[cfg(not(feature = "std"))]
+pub trait HashBuilder: Clone {}
+#[cfg(not(feature = "std"))]
+impl<T> HashBuilder for T where T: Clone {}
+#[cfg(feature = "std")]
+pub trait HashBuilder: BuildHasher + Clone {}
+#[cfg(feature = "std")]
+impl<T> HashBuilder for T where T: BuildHasher + Clone {}
+
+/// Default builder of the hashers of the positions map;
+/// it is not used when no-std since the positions are then stored in a `BTreeMap`.
+#[cfg(not(feature = "std"))]
+pub type DefaultHashBuilder = ();
+/// Default builder of the hashers of the positions map.
+#[cfg(feature = "std")]
+pub type DefaultHashBuilder = RandomState;
+
 #[cfg(not(feature = "std"))]
 type Map<N> = BTreeMap<N, usize>;
 #[cfg(feature = "std")]
-type Map<N> = HashMap<N, usize>;
+type Map<N, S> = HashMap<N, usize, S>;
 
-#[derive(Clone, Debug)]
-pub struct HeapPositionsMap<N>
+/// Positions of the nodes of a [`DaryHeapWithMap`](crate::DaryHeapWithMap) stored in a map from nodes to positions;
+/// obtained from [`DaryHeapWithMap::into_parts`](crate::DaryHeapWithMap::into_parts).
+#[derive(Debug)]
+pub struct HeapPositionsMap<N, S = DefaultHashBuilder>
 where
     N: Index,
+    S: HashBuilder,
 {
+    #[cfg(not(feature = "std"))]
     map: Map<N>,
+    #[cfg(not(feature = "std"))]
+    hash_builder: PhantomData<S>,
+    #[cfg(feature = "std")]
+    map: Map<N, S>,
 }
-impl<N> Default for HeapPositionsMap<N>
+impl<N, S> Clone for HeapPositionsMap<N, S>
 where
     N: Index,
+    S: HashBuilder,
+{
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            #[cfg(not(feature = "std"))]
+            hash_builder: PhantomData,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.map.clone_from(&source.map);
+    }
+}
+impl<N, S> Default for HeapPositionsMap<N, S>
+where
+    N: Index,
+    S: HashBuilder + Default,
 {
     fn default() -> Self {
-        Self { map: Map::new() }
+        Self::with_capacity_and_hasher(0, S::default())
     }
 }
-impl<N> HeapPositionsMap<N>
+impl<N, S> HeapPositionsMap<N, S>
 where
     N: Index,
+    S: HashBuilder,
 {
     #[allow(unused)]
-    pub fn with_capacity(capacity: usize) -> Self {
+    pub(crate) fn with_capacity(capacity: usize) -> Self
+    where
+        S: Default,
+    {
+        Self::with_capacity_and_hasher(capacity, S::default())
+    }
+
+    pub(crate) fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
         Self {
             #[cfg(not(feature = "std"))]
             map: Map::new(),
+            #[cfg(not(feature = "std"))]
+            hash_builder: {
+                let _ = (capacity, hash_builder);
+                PhantomData
+            },
             #[cfg(feature = "std")]
-            map: Map::with_capacity(capacity),
+            map: Map::with_capacity_and_hasher(capacity, hash_builder),
         }
     }
+
+    /// Returns the position of the node which is equal to the borrowed form of the node `node`.
+    pub(crate) fn position_of_borrowed<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Lookup + ?Sized,
+    {
+        self.map.get(node).copied()
+    }
+
+    /// Creates empty positions with the given `capacity` which hash the nodes by the same hash builder as this.
+    pub(crate) fn empty_with_same_hasher(&self, capacity: usize) -> Self {
+        #[cfg(not(feature = "std"))]
+        return Self {
+            map: Map::new(),
+            hash_builder: {
+                let _ = capacity;
+                PhantomData
+            },
+        };
+        #[cfg(feature = "std")]
+        return Self::with_capacity_and_hasher(capacity, self.map.hasher().clone());
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        #[cfg(feature = "std")]
+        self.map.shrink_to_fit();
+    }
+
+    pub(crate) fn shrink_to(&mut self, min_capacity: usize) {
+        #[cfg(not(feature = "std"))]
+        let _ = min_capacity;
+        #[cfg(feature = "std")]
+        self.map.shrink_to(min_capacity);
+    }
 }
-impl<N> HeapPositions<N> for HeapPositionsMap<N>
+impl<N, S> HeapPositions<N> for HeapPositionsMap<N, S>
 where
     N: Index,
+    S: HashBuilder,
 {
     fn clear(&mut self) {
         self.map.clear();
     }
 
+    fn reserve(&mut self, additional: usize) {
+        #[cfg(not(feature = "std"))]
+        let _ = additional;
+        #[cfg(feature = "std")]
+        self.map.reserve(additional);
+    }
+
     #[inline(always)]
     fn contains(&self, node: &N) -> bool {
         self.map.contains_key(node)
@@ -79,23 +194,29 @@ where
         *self.map.get_mut(node).expect("node must exist") = position;
     }
 
-    #[cfg(test)]
-    fn is_valid<K>(&self, offset: usize, tree: &[(N, K)]) -> bool {
-        if self.map.len() != tree.len() - offset {
-            false
-        } else {
-            for (node, &position) in &self.map {
-                if let Some(tree_node) = tree.get(position) {
-                    if node != &tree_node.0 {
-                        return false;
-                    }
-                } else {
-                    return false;
-                }
+    fn validate<K>(&self, offset: usize, tree: &[(N, K)]) -> Result<(), HeapError> {
+        let len = tree.len().saturating_sub(offset);
+        if self.map.len() != len {
+            return Err(HeapError::PositionsLength {
+                num_positions: self.map.len(),
+                len,
+            });
+        }
+
+        for (position, (node, _)) in tree.iter().enumerate().skip(offset) {
+            if self.map.get(node) != Some(&position) {
+                return Err(HeapError::PositionMismatch {
+                    position: position - offset,
+                });
             }
-            true
         }
+        Ok(())
     }
 }
 
-impl<N> HeapPositionsDecKey<N> for HeapPositionsMap<N> where N: Index {}
+impl<N, S> HeapPositionsDecKey<N> for HeapPositionsMap<N, S>
+where
+    N: Index,
+    S: HashBuilder,
+{
+}
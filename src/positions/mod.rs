@@ -1,4 +1,6 @@
+pub(crate) mod handles;
 pub(crate) mod has_index;
 pub(crate) mod heap_positions;
 pub(crate) mod map;
 pub(crate) mod none;
+pub(crate) mod sorted_vec;
@@ -1,7 +1,10 @@
 use super::heap_positions::HeapPositions;
+use crate::HeapError;
 
+/// Positions of the nodes of a [`DaryHeap`](crate::DaryHeap), which does not track the positions of its nodes;
+/// obtained from [`DaryHeap::into_parts`](crate::DaryHeap::into_parts).
 #[derive(Clone, Debug)]
-pub(crate) struct HeapPositionsNone;
+pub struct HeapPositionsNone;
 
 impl<N> HeapPositions<N> for HeapPositionsNone {
     #[inline(always)]
@@ -26,8 +29,8 @@ impl<N> HeapPositions<N> for HeapPositionsNone {
     #[inline(always)]
     fn update_position_of(&mut self, _node: &N, _pos: usize) {}
 
-    #[cfg(test)]
-    fn is_valid<K>(&self, _offset: usize, _tree: &[(N, K)]) -> bool {
-        true
+    #[inline(always)]
+    fn validate<K>(&self, _offset: usize, _tree: &[(N, K)]) -> Result<(), HeapError> {
+        Ok(())
     }
 }
@@ -0,0 +1,97 @@
+use super::heap_positions::{HeapPositions, HeapPositionsDecKey};
+use crate::HeapError;
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+pub struct HeapPositionsSortedVec<N>
+where
+    N: Ord + Clone,
+{
+    positions: Vec<(N, usize)>,
+}
+impl<N> Default for HeapPositionsSortedVec<N>
+where
+    N: Ord + Clone,
+{
+    fn default() -> Self {
+        Self {
+            positions: Vec::new(),
+        }
+    }
+}
+impl<N> HeapPositionsSortedVec<N>
+where
+    N: Ord + Clone,
+{
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            positions: Vec::with_capacity(capacity),
+        }
+    }
+
+    #[inline(always)]
+    fn search(&self, node: &N) -> Result<usize, usize> {
+        self.positions.binary_search_by(|x| x.0.cmp(node))
+    }
+}
+impl<N> HeapPositions<N> for HeapPositionsSortedVec<N>
+where
+    N: Ord + Clone,
+{
+    fn clear(&mut self) {
+        self.positions.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.positions.reserve(additional);
+    }
+
+    #[inline(always)]
+    fn contains(&self, node: &N) -> bool {
+        self.search(node).is_ok()
+    }
+
+    fn position_of(&self, node: &N) -> Option<usize> {
+        self.search(node).ok().map(|i| self.positions[i].1)
+    }
+
+    fn insert(&mut self, node: &N, position: usize) {
+        match self.search(node) {
+            Ok(_) => debug_assert!(false, "re-inserting already added node"),
+            Err(i) => self.positions.insert(i, (node.clone(), position)),
+        }
+    }
+
+    fn remove(&mut self, node: &N) {
+        match self.search(node) {
+            Ok(i) => _ = self.positions.remove(i),
+            Err(_) => debug_assert!(false, "removing an absent node"),
+        }
+    }
+
+    fn update_position_of(&mut self, node: &N, position: usize) {
+        let i = self.search(node).expect("node must exist");
+        self.positions[i].1 = position;
+    }
+
+    fn validate<K>(&self, offset: usize, tree: &[(N, K)]) -> Result<(), HeapError> {
+        let len = tree.len().saturating_sub(offset);
+        if self.positions.len() != len {
+            return Err(HeapError::PositionsLength {
+                num_positions: self.positions.len(),
+                len,
+            });
+        }
+
+        for (position, (node, _)) in tree.iter().enumerate().skip(offset) {
+            if self.position_of(node) != Some(position) {
+                return Err(HeapError::PositionMismatch {
+                    position: position - offset,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N> HeapPositionsDecKey<N> for HeapPositionsSortedVec<N> where N: Ord + Clone {}
@@ -14,7 +14,11 @@ where
         K: 'a;
 
     /// An iterator over the (node, key) pairs on the priority queue in an arbitrary order.
-    type Iter<'a>: Iterator<Item = Self::NodeKey<'a>>
+    ///
+    /// The iterator is double-ended so that generic code can traverse it backwards by `rev`.
+    /// Note that the reverse of an arbitrary order is arbitrary as well;
+    /// `rev` does not yield the elements in descending order of their keys.
+    type Iter<'a>: DoubleEndedIterator<Item = Self::NodeKey<'a>>
     where
         Self: 'a,
         N: 'a,
@@ -41,6 +45,28 @@ where
     /// Capacity of the heap.
     fn capacity(&self) -> usize;
 
+    /// Returns the arity of the underlying heap, i.e., the maximum number of children of each node on the heap,
+    /// if the queue is a heap with a known arity; returns None otherwise.
+    ///
+    /// This is useful in generic code, such as benchmarks or logs, which needs to know which arity it is running against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// fn arity_of<P: PriorityQueue<usize, f64>>(queue: &P) -> Option<usize> {
+    ///     queue.arity()
+    /// }
+    ///
+    /// assert_eq!(Some(2), arity_of(&BinaryHeap::default()));
+    /// assert_eq!(Some(4), arity_of(&QuaternaryHeapWithMap::default()));
+    /// assert_eq!(Some(8), arity_of(&DaryHeapOfIndices::<_, _, 8>::with_index_bound(4)));
+    /// ```
+    fn arity(&self) -> Option<usize> {
+        None
+    }
+
     /// Returns whether he queue is empty or not.
     ///
     /// # Examples
@@ -76,6 +102,77 @@ where
     /// ```
     fn peek(&self) -> Option<Self::NodeKey<'_>>;
 
+    /// Returns, without popping, a reference to the node of the foremost element of the queue;
+    /// returns None if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeap::default();
+    /// assert_eq!(None, queue.peek_node());
+    ///
+    /// queue.push(0, 12.0);
+    /// queue.push(42, 1.0);
+    /// queue.push(21, 5.0);
+    /// assert_eq!(Some(&42), queue.peek_node());
+    /// ```
+    fn peek_node<'a>(&'a self) -> Option<&'a N>
+    where
+        N: 'a,
+        K: 'a,
+    {
+        self.peek().map(|x| x.node())
+    }
+
+    /// Returns, without popping, a reference to the key of the foremost element of the queue;
+    /// returns None if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeap::default();
+    /// assert_eq!(None, queue.peek_key());
+    ///
+    /// queue.push(0, 12.0);
+    /// queue.push(42, 1.0);
+    /// queue.push(21, 5.0);
+    /// assert_eq!(Some(&1.0), queue.peek_key());
+    /// ```
+    fn peek_key<'a>(&'a self) -> Option<&'a K>
+    where
+        N: 'a,
+        K: 'a,
+    {
+        self.peek().map(|x| x.key())
+    }
+
+    /// Returns, without popping, a reference to the key of the foremost element of the queue;
+    /// returns the given `default` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeap::default();
+    /// assert_eq!(&f64::INFINITY, queue.peek_key_or(&f64::INFINITY));
+    ///
+    /// queue.push(0, 12.0);
+    /// queue.push(42, 1.0);
+    /// assert_eq!(&1.0, queue.peek_key_or(&f64::INFINITY));
+    /// ```
+    fn peek_key_or<'a>(&'a self, default: &'a K) -> &'a K
+    where
+        N: 'a,
+        K: 'a,
+    {
+        self.peek_key().unwrap_or(default)
+    }
+
     /// Clears the queue.
     ///
     /// # Examples
@@ -125,6 +222,27 @@ where
     /// ```
     fn pop(&mut self) -> Option<(N, K)>;
 
+    /// Removes and returns the (node, key) pair with the lowest key in the queue;
+    /// returns the given `default` pair if the queue is empty.
+    ///
+    /// The `default` is returned only when the queue is empty, and is dropped otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeap::default();
+    /// queue.push(42, 1.0);
+    ///
+    /// assert_eq!((42, 1.0), queue.pop_or((0, f64::INFINITY)));
+    /// assert_eq!((0, f64::INFINITY), queue.pop_or((0, f64::INFINITY)));
+    /// assert!(queue.is_empty());
+    /// ```
+    fn pop_or(&mut self, default: (N, K)) -> (N, K) {
+        self.pop().unwrap_or(default)
+    }
+
     /// Removes and returns the node with the lowest key in the queue;
     /// returns None if the queue is empty.
     ///
@@ -246,5 +364,62 @@ where
     fn push_then_pop(&mut self, node: N, key: K) -> (N, K);
 
     /// Returns an iterator visiting all values on the heap in arbitrary order.
+    ///
+    /// Neither the forward nor the reverse traversal of the iterator follows the order of the keys,
+    /// except that some implementations might happen to yield the foremost element first.
+    /// See `iter_sorted` or `into_iter_sorted` of the d-ary heaps for traversals in ascending order of the keys.
     fn iter(&self) -> Self::Iter<'_>;
+
+    /// Returns an iterator visiting the nodes of all elements on the heap in arbitrary order,
+    /// which is the same order as [`iter`].
+    ///
+    /// [`iter`]: PriorityQueue::iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push('a', 42);
+    /// queue.push('b', 7);
+    /// queue.push('c', 99);
+    ///
+    /// let mut nodes: Vec<_> = queue.nodes().copied().collect();
+    /// nodes.sort();
+    /// assert_eq!(nodes, ['a', 'b', 'c']);
+    /// ```
+    fn nodes<'a>(&'a self) -> impl Iterator<Item = &'a N>
+    where
+        N: 'a,
+        K: 'a,
+    {
+        self.iter().map(|x| x.node())
+    }
+
+    /// Returns an iterator visiting the keys of all elements on the heap in arbitrary order,
+    /// which is the same order as [`iter`].
+    ///
+    /// [`iter`]: PriorityQueue::iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = QuaternaryHeap::default();
+    /// queue.push('a', 42);
+    /// queue.push('b', 7);
+    /// queue.push('c', 99);
+    ///
+    /// let sum: i32 = queue.keys().sum();
+    /// assert_eq!(148, sum);
+    /// ```
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        N: 'a,
+        K: 'a,
+    {
+        self.iter().map(|x| x.key())
+    }
 }
@@ -1,4 +1,5 @@
-use crate::PriorityQueue;
+use crate::{NodeKeyRef, PriorityQueue};
+use core::ops::Sub;
 
 /// A [PriorityQueueDecKey] is a more advanced [PriorityQueue] with additional features
 /// mainly related to accessing or modifying already pushed nodes such as:
@@ -54,7 +55,59 @@ where
     /// ```
     fn key_of(&self, node: &N) -> Option<K>;
 
-    /// Decreases key of the `node` which is already in the queue to the given `decreased_key`.
+    /// Returns a reference to the key of the given `node` if it is in the queue;
+    /// returns None otherwise.
+    ///
+    /// Unlike [`key_of`], this method does not clone the key.
+    ///
+    /// [`key_of`]: PriorityQueueDecKey::key_of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapWithMap::default();
+    /// queue.push('a', String::from("42"));
+    ///
+    /// assert_eq!(Some(&String::from("42")), queue.key_ref(&'a'));
+    /// assert_eq!(None, queue.key_ref(&'x'));
+    /// ```
+    fn key_ref(&self, node: &N) -> Option<&K>;
+
+    /// Returns an iterator visiting the nodes in the queue together with their keys in arbitrary order,
+    /// which is the same order as [`iter`].
+    ///
+    /// Since the queue behaves as a set, each node appears exactly once;
+    /// hence, this is a snapshot of the set of nodes in the queue together with the keys of its members.
+    ///
+    /// [`iter`]: PriorityQueue::iter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapWithMap::default();
+    /// queue.push('a', 42);
+    /// queue.push('b', 7);
+    /// queue.push('c', 99);
+    /// queue.decrease_key(&'c', 3);
+    ///
+    /// let mut entries: Vec<_> = queue.entries().collect();
+    /// entries.sort();
+    /// assert_eq!(entries, [(&'a', &42), (&'b', &7), (&'c', &3)]);
+    /// ```
+    fn entries<'a>(&'a self) -> impl Iterator<Item = (&'a N, &'a K)>
+    where
+        N: 'a,
+        K: 'a,
+    {
+        self.iter().map(|x| (x.node(), x.key()))
+    }
+
+    /// Decreases key of the `node` which is already in the queue to the given `decreased_key`;
+    /// and returns the previous key of the `node`.
     ///
     /// This method is commonly used to increase priority of a node putting it closer to the peek of the queue;
     /// alternative to inserting the same node multiple times with different keys.
@@ -81,14 +134,210 @@ where
     /// queue.push(7usize, 42.0);
     /// assert_eq!(Some(42.0), queue.key_of(&7));
     ///
-    /// queue.decrease_key(&7, 21.0);
+    /// let old_key = queue.decrease_key(&7, 21.0);
+    /// assert_eq!(42.0, old_key);
     /// assert_eq!(Some(21.0), queue.key_of(&7));
     ///
     /// // the following lines would've panicked:
     /// // queue.decrease_key(&10, 21.0); // due to absent node
     /// // queue.decrease_key(&7, 100.0); // due to greater new key
     /// ```
-    fn decrease_key(&mut self, node: &N, decreased_key: K);
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K;
+
+    /// Decreases key of the `node` which is already in the queue to a clone of the given `decreased_key`,
+    /// and returns its previous key.
+    ///
+    /// This is equivalent to [`decrease_key`], except that the key is taken by reference and cloned only after
+    /// the node is found in the queue; hence, an absent node never leads to a clone of the key.
+    /// This is useful for large keys such as vectors, while [`decrease_key`] is convenient for `Copy` keys.
+    ///
+    /// # Panics
+    /// This method panics:
+    /// * if the `node` is not in the queue; or
+    /// * if `decreased_key` is strictly larger than key of the `node` in the queue.
+    ///
+    /// [`decrease_key`]: PriorityQueueDecKey::decrease_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapOfIndices::with_index_bound(12);
+    ///
+    /// queue.push(7usize, vec![4, 2]);
+    ///
+    /// let decreased_key = vec![2, 1];
+    /// let old_key = queue.decrease_key_ref(&7, &decreased_key);
+    /// assert_eq!(vec![4, 2], old_key);
+    /// assert_eq!(Some(&decreased_key), queue.key_ref(&7));
+    /// ```
+    fn decrease_key_ref(&mut self, node: &N, decreased_key: &K) -> K {
+        assert!(
+            self.contains(node),
+            "cannot decrease key of a node that is not on the queue"
+        );
+        self.decrease_key(node, decreased_key.clone())
+    }
+
+    /// Decreases key of the `node` which is already in the queue to the key computed by `decrease`
+    /// from a reference to its current key, and returns its previous key.
+    ///
+    /// This is the general form of [`decrease_key_by`]; the current key is neither cloned nor looked up twice
+    /// by the d-ary heaps of this crate.
+    ///
+    /// # Panics
+    /// This method panics:
+    /// * if the `node` is not in the queue; or
+    /// * if the computed key is strictly larger than key of the `node` in the queue.
+    ///
+    /// [`decrease_key_by`]: PriorityQueueDecKey::decrease_key_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapOfIndices::with_index_bound(12);
+    ///
+    /// queue.push(7usize, 42.0);
+    /// queue.push(3, 21.0);
+    ///
+    /// let old_key = queue.decrease_key_with(&7, |key| key / 4.0);
+    /// assert_eq!(42.0, old_key);
+    /// assert_eq!(Some((7, 10.5)), queue.pop());
+    /// ```
+    fn decrease_key_with<F>(&mut self, node: &N, decrease: F) -> K
+    where
+        F: FnOnce(&K) -> K,
+    {
+        let decreased_key = decrease(
+            self.key_ref(node)
+                .expect("cannot decrease key of a node that is not on the queue"),
+        );
+        self.decrease_key(node, decreased_key)
+    }
+
+    /// Decreases key of the `node` which is already in the queue by the given `delta`, such that its new key
+    /// becomes `old_key - delta`, and returns its previous key.
+    ///
+    /// This is convenient for relaxations where the improvement rather than the new key is known,
+    /// and is equivalent to [`decrease_key_with`] with a closure subtracting the `delta`.
+    ///
+    /// # Panics
+    /// This method panics:
+    /// * if the `node` is not in the queue; or
+    /// * if `old_key - delta` is strictly larger than `old_key`, such as when the `delta` is negative.
+    ///
+    /// [`decrease_key_with`]: PriorityQueueDecKey::decrease_key_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapWithMap::default();
+    ///
+    /// queue.push('a', 42);
+    /// queue.push('b', 21);
+    ///
+    /// let old_key = queue.decrease_key_by(&'a', 30);
+    /// assert_eq!(42, old_key);
+    /// assert_eq!(Some(12), queue.key_of(&'a'));
+    /// assert_eq!(Some(('a', 12)), queue.pop());
+    ///
+    /// // the following lines would've panicked:
+    /// // queue.decrease_key_by(&'x', 1); // due to absent node
+    /// // queue.decrease_key_by(&'b', -1); // due to greater new key
+    /// ```
+    fn decrease_key_by(&mut self, node: &N, delta: K) -> K
+    where
+        K: Sub<Output = K>,
+    {
+        self.decrease_key_with(node, |key| key.clone() - delta)
+    }
+
+    /// Decreases keys of all nodes of the `updates`, which are already in the queue, to the corresponding decreased keys.
+    ///
+    /// The result is equivalent to calling [`decrease_key`] for each (node, decreased_key) pair in order;
+    /// however, implementations may defer re-arranging the queue until all keys are decreased,
+    /// which is more efficient when the number of updates is large relative to the length of the queue.
+    /// The d-ary heaps of this crate rebuild the heap once when the `size_hint` of the updates indicates
+    /// at least half as many updates as the length of the queue.
+    ///
+    /// # Panics
+    /// This method panics:
+    /// * if any of the nodes is not in the queue; or
+    /// * if any of the decreased keys is strictly larger than key of the corresponding node in the queue.
+    ///
+    /// [`decrease_key`]: PriorityQueueDecKey::decrease_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapOfIndices::with_index_bound(12);
+    ///
+    /// queue.push(7usize, 42.0);
+    /// queue.push(3, 21.0);
+    /// queue.push(5, 33.0);
+    ///
+    /// queue.decrease_key_all([(7, 7.0), (5, 14.0)]);
+    ///
+    /// assert_eq!(Some((7, 7.0)), queue.pop());
+    /// assert_eq!(Some((5, 14.0)), queue.pop());
+    /// assert_eq!(Some((3, 21.0)), queue.pop());
+    /// ```
+    fn decrease_key_all<I>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (N, K)>,
+    {
+        for (node, decreased_key) in updates {
+            self.decrease_key(&node, decreased_key);
+        }
+    }
+
+    /// Increases key of the `node` which is already in the queue to the given `increased_key`.
+    ///
+    /// This method is useful in algorithms which monotonically increase priorities of nodes,
+    /// since it only requires moving the node downwards in the queue.
+    ///
+    /// # Panics
+    /// This method panics:
+    /// * if the `node` is not in the queue; or
+    /// * if `increased_key` is strictly smaller than key of the `node` in the queue
+    ///   (see also [`try_increase_key`]).
+    ///
+    /// [`try_increase_key`]: PriorityQueueDecKey::try_increase_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapOfIndices::with_index_bound(12);
+    ///
+    /// queue.push(7usize, 42.0);
+    /// assert_eq!(Some(42.0), queue.key_of(&7));
+    ///
+    /// queue.increase_key(&7, 84.0);
+    /// assert_eq!(Some(84.0), queue.key_of(&7));
+    ///
+    /// // the following lines would've panicked:
+    /// // queue.increase_key(&10, 100.0); // due to absent node
+    /// // queue.increase_key(&7, 21.0); // due to smaller new key
+    /// ```
+    fn increase_key(&mut self, node: &N, increased_key: K) {
+        let old_key = self
+            .key_of(node)
+            .expect("cannot increase key of a node that is not on the queue");
+        assert!(
+            increased_key >= old_key,
+            "increase_key is called with a smaller key"
+        );
+        self.update_key(node, increased_key);
+    }
 
     /// Updates key of the `node` which is already in the queue as the given `new_key`;
     /// and returns the result of the operation:
@@ -169,6 +418,103 @@ where
         }
     }
 
+    /// Tries to increase the key of the `node` which is already in the queue if its prior key is strictly smaller than the `new_key`;
+    /// otherwise, it does nothing leaving the queue unchanged.
+    ///
+    /// Returns the result of the operation:
+    ///
+    /// * `ResTryIncreaseKey::Increased` if the prior key was strictly smaller than the `new_key`;
+    /// * `ResTryIncreaseKey::Unchanged` if the prior key was greater than or equal to the `new_key`.
+    ///
+    /// # Panics
+    /// This method panics if:
+    /// * the `node` is not in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapWithMap::default();
+    ///
+    /// queue.push(7usize, 42.0);
+    /// assert_eq!(Some(42.0), queue.key_of(&7));
+    ///
+    /// let result = queue.try_increase_key(&7, 84.0);
+    /// assert_eq!(Some(84.0), queue.key_of(&7));
+    /// assert!(matches!(result, ResTryIncreaseKey::Increased));
+    ///
+    /// let result = queue.try_increase_key(&7, 21.0);
+    /// assert_eq!(Some(84.0), queue.key_of(&7));
+    /// assert!(matches!(result, ResTryIncreaseKey::Unchanged));
+    ///
+    /// // the following line would've panicked:
+    /// // queue.try_increase_key(&10, 100.0); // due to absent node
+    /// ```
+    #[inline(always)]
+    fn try_increase_key(&mut self, node: &N, new_key: K) -> ResTryIncreaseKey {
+        let old_key = self.key_of(node).expect("node must exist on the heap.");
+        if new_key > old_key {
+            self.increase_key(node, new_key);
+            ResTryIncreaseKey::Increased
+        } else {
+            ResTryIncreaseKey::Unchanged
+        }
+    }
+
+    /// Updates the key of the `node` which is already in the queue to the given `new_key`
+    /// only if `should_update(old_key, new_key)` returns true;
+    /// otherwise, it does nothing leaving the queue unchanged.
+    ///
+    /// This generalizes [`try_decrease_key`] and [`try_increase_key`] to custom update policies,
+    /// such as updating only if the improvement exceeds a threshold.
+    /// The key can be moved in either direction; the queue is restored accordingly.
+    ///
+    /// Returns whether or not the key of the `node` is updated.
+    ///
+    /// [`try_decrease_key`]: PriorityQueueDecKey::try_decrease_key
+    /// [`try_increase_key`]: PriorityQueueDecKey::try_increase_key
+    ///
+    /// # Panics
+    /// This method panics if:
+    /// * the `node` is not in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapWithMap::default();
+    ///
+    /// queue.push(7usize, 42.0);
+    ///
+    /// let improves_by_more_than_one = |old: &f64, new: &f64| *new < *old - 1.0;
+    ///
+    /// let updated = queue.update_key_if(&7, 41.5, improves_by_more_than_one);
+    /// assert!(!updated);
+    /// assert_eq!(Some(42.0), queue.key_of(&7));
+    ///
+    /// let updated = queue.update_key_if(&7, 21.0, improves_by_more_than_one);
+    /// assert!(updated);
+    /// assert_eq!(Some(21.0), queue.key_of(&7));
+    ///
+    /// // the following line would've panicked:
+    /// // queue.update_key_if(&10, 1.0, |_, _| true); // due to absent node
+    /// ```
+    fn update_key_if<F>(&mut self, node: &N, new_key: K, should_update: F) -> bool
+    where
+        F: FnOnce(&K, &K) -> bool,
+    {
+        let old_key = self.key_ref(node).expect("node must exist on the heap.");
+        match should_update(old_key, &new_key) {
+            true => {
+                self.update_key(node, new_key);
+                true
+            }
+            false => false,
+        }
+    }
+
     /// If the `node` is present in the queue:
     /// * decreases key of the `node` to the given `decreased_key`; `decreased_key` is expected to be less than or equal
     ///   to the prior key;
@@ -317,6 +663,39 @@ where
         }
     }
 
+    /// Pushes the (`node`, `key`) pair to the queue if the `node` is absent, or decreases its key to the given `key` if it is lower than its current key;
+    /// returns whether or not the queue is changed.
+    ///
+    /// This is equivalent to [`try_decrease_key_or_push`] for the callers which do not need to distinguish pushes from decreases,
+    /// such as the relaxation of the edges in Dijkstra's shortest path algorithm.
+    ///
+    /// [`try_decrease_key_or_push`]: PriorityQueueDecKey::try_decrease_key_or_push
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapOfIndices::with_index_bound(12);
+    ///
+    /// assert!(queue.push_if_lower(&7usize, 42.0));
+    /// assert_eq!(Some(42.0), queue.key_of(&7));
+    ///
+    /// assert!(queue.push_if_lower(&7, 21.0));
+    /// assert_eq!(Some(21.0), queue.key_of(&7));
+    ///
+    /// assert!(!queue.push_if_lower(&7, 21.0));
+    /// assert!(!queue.push_if_lower(&7, 200.0));
+    /// assert_eq!(Some(21.0), queue.key_of(&7));
+    /// ```
+    #[inline(always)]
+    fn push_if_lower(&mut self, node: &N, key: K) -> bool {
+        !matches!(
+            self.try_decrease_key_or_push(node, key),
+            ResTryDecreaseKeyOrPush::Unchanged
+        )
+    }
+
     /// Removes the `node` from the queue; and returns its current key.
     ///
     /// # Panics
@@ -341,6 +720,37 @@ where
     /// // let key = queue.remove(&7);
     /// ```
     fn remove(&mut self, node: &N) -> K;
+
+    /// Removes the `node` from the queue if it is present, and returns the removed (node, key) pair;
+    /// returns None and leaves the queue unchanged if the `node` is not in the queue.
+    ///
+    /// This is the non-panicking counterpart of [`remove`] which also returns the owned node.
+    ///
+    /// [`remove`]: PriorityQueueDecKey::remove
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = BinaryHeapWithMap::default();
+    ///
+    /// queue.push(7usize, 42.0);
+    /// queue.push(3usize, 21.0);
+    ///
+    /// assert_eq!(Some((7, 42.0)), queue.take(&7));
+    /// assert_eq!(None, queue.take(&7));
+    /// assert_eq!(1, queue.len());
+    /// ```
+    fn take(&mut self, node: &N) -> Option<(N, K)> {
+        match self.contains(node) {
+            true => {
+                let key = self.remove(node);
+                Some((node.clone(), key))
+            }
+            false => None,
+        }
+    }
 }
 
 /// Result of `queue.update_key(node, new_key)` operation : [`PriorityQueueDecKey::update_key`].
@@ -361,6 +771,15 @@ pub enum ResTryDecreaseKey {
     Unchanged,
 }
 
+/// Result of `queue.try_increase_key(node, new_key)` operation : [`PriorityQueueDecKey::try_increase_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResTryIncreaseKey {
+    /// Existing key of the `node` was lower; and hence, increased to the `new_key`.
+    Increased,
+    /// Existing key of the `node` was higher; and hence, the queue is not changed.
+    Unchanged,
+}
+
 /// Result of `queue.decrease_key_or_push(node, key)` operation : [`PriorityQueueDecKey::decrease_key_or_push`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ResDecreaseKeyOrPush {
@@ -0,0 +1,24 @@
+use core::fmt::{Display, Formatter};
+
+/// Error returned when a node of a heap cannot be renamed, such as by [`DaryHeapWithMap::rename`].
+///
+/// [`DaryHeapWithMap::rename`]: crate::DaryHeapWithMap::rename
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameError {
+    /// The node to be renamed is not in the heap.
+    NodeNotFound,
+    /// The new name of the node is already in the heap.
+    NodeAlreadyExists,
+}
+
+impl Display for RenameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NodeNotFound => write!(f, "the node to be renamed is not in the heap"),
+            Self::NodeAlreadyExists => write!(f, "the new node is already in the heap"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RenameError {}
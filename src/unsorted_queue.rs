@@ -0,0 +1,301 @@
+use crate::{PriorityQueue, PriorityQueueDecKey, ResUpdateKey};
+use alloc::vec::Vec;
+
+/// A priority queue which keeps its (node, key) pairs in an unsorted vector,
+/// and implements both `PriorityQueue` and `PriorityQueueDecKey`.
+///
+/// See [`PriorityQueueDecKey`] for additional functionalities.
+///
+/// # Linear Scan vs Heap
+///
+/// * `push` is a constant time append to the vector;
+/// * `peek` and `pop` scan all elements to find the one with the minimum key, requiring *O(n)* time;
+/// * `contains`, `decrease_key` and other node-based operations scan all elements to find the node, requiring *O(n)* time;
+///   whereas, after the node is found, updating its key requires constant time.
+///
+/// The nodes are only required to implement `Eq + Clone` for `PriorityQueueDecKey`; there is neither an index bound nor a map of positions.
+///
+/// Due to its minimal overhead, it is often faster than the heaps when the queue holds only a few elements.
+/// Since it implements the same traits, it can be swapped with the heaps without changing the call sites of
+/// generic algorithms; for instance, when the queues are known to be small.
+/// In the `unsorted_queue` benchmark which repeatedly pops and pushes while keeping the length of the queue fixed,
+/// it outperforms the binary heaps for queues of up to around 64 elements, and falls behind for larger queues.
+///
+/// # Examples
+///
+/// ```
+/// use orx_priority_queue::*;
+///
+/// let mut queue = UnsortedQueue::new();
+///
+/// queue.push('a', 42);
+/// queue.push('b', 17);
+/// queue.push('c', 99);
+///
+/// queue.decrease_key(&'a', 7);
+/// assert_eq!(Some(&7), queue.key_ref(&'a'));
+///
+/// assert_eq!(ResUpdateKey::Increased, queue.update_key(&'b', 100));
+///
+/// assert_eq!(Some(('a', 7)), queue.pop());
+/// assert_eq!(Some(('c', 99)), queue.pop());
+/// assert_eq!(Some(('b', 100)), queue.pop());
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnsortedQueue<N, K>(Vec<(N, K)>)
+where
+    K: PartialOrd;
+
+impl<N, K> Default for UnsortedQueue<N, K>
+where
+    K: PartialOrd,
+{
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<N, K> From<Vec<(N, K)>> for UnsortedQueue<N, K>
+where
+    K: PartialOrd,
+{
+    /// Creates the queue from the given (node, key) pairs in constant time.
+    ///
+    /// Since the nodes of the queue form a set, each node must appear at most once among the elements.
+    fn from(elements: Vec<(N, K)>) -> Self {
+        Self(elements)
+    }
+}
+
+impl<N, K> UnsortedQueue<N, K>
+where
+    K: PartialOrd,
+{
+    /// Creates a new empty unsorted queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = UnsortedQueue::new();
+    ///
+    /// queue.push('a', 4);
+    /// queue.push('b', 42);
+    ///
+    /// assert_eq!(Some('a'), queue.pop_node());
+    /// assert_eq!(Some('b'), queue.pop_node());
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new unsorted queue with the given initial `capacity` on the number of nodes to simultaneously exist on the queue.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Returns the nodes and keys currently in the queue as a slice, in an arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_priority_queue::*;
+    ///
+    /// let mut queue = UnsortedQueue::new();
+    /// queue.push("x", 42);
+    /// queue.push("y", 7);
+    ///
+    /// assert_eq!(&[("x", 42), ("y", 7)], queue.as_slice());
+    /// ```
+    pub fn as_slice(&self) -> &[(N, K)] {
+        &self.0
+    }
+
+    /// Consumes the queue and returns the underlying vector of (node, key) pairs in an arbitrary order.
+    pub fn into_inner(self) -> Vec<(N, K)> {
+        self.0
+    }
+
+    /// Returns the position of the element with the minimum key; the first one in case of ties.
+    fn position_of_min(&self) -> Option<usize> {
+        let mut elements = self.0.iter().enumerate();
+        let (mut min_position, mut min) = elements.next().map(|(i, x)| (i, &x.1))?;
+        for (i, (_, key)) in elements {
+            if key < min {
+                min_position = i;
+                min = key;
+            }
+        }
+        Some(min_position)
+    }
+}
+
+impl<N, K> UnsortedQueue<N, K>
+where
+    N: Eq,
+    K: PartialOrd,
+{
+    fn position_of(&self, node: &N) -> Option<usize> {
+        self.0.iter().position(|x| x.0 == *node)
+    }
+}
+
+impl<N, K> PriorityQueue<N, K> for UnsortedQueue<N, K>
+where
+    K: PartialOrd,
+{
+    type NodeKey<'a>
+        = &'a (N, K)
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+    type Iter<'a>
+        = core::slice::Iter<'a, (N, K)>
+    where
+        Self: 'a,
+        N: 'a,
+        K: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    fn peek(&self) -> Option<&(N, K)> {
+        self.position_of_min().map(|i| &self.0[i])
+    }
+
+    fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    fn pop(&mut self) -> Option<(N, K)> {
+        self.position_of_min().map(|i| self.0.swap_remove(i))
+    }
+
+    #[inline(always)]
+    fn pop_node(&mut self) -> Option<N> {
+        self.pop().map(|x| x.0)
+    }
+
+    #[inline(always)]
+    fn pop_key(&mut self) -> Option<K> {
+        self.pop().map(|x| x.1)
+    }
+
+    #[inline(always)]
+    fn push(&mut self, node: N, key: K) {
+        self.0.push((node, key))
+    }
+
+    fn push_then_pop(&mut self, node: N, key: K) -> (N, K) {
+        match self.position_of_min() {
+            Some(i) if self.0[i].1 < key => core::mem::replace(&mut self.0[i], (node, key)),
+            _ => (node, key),
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.0.iter()
+    }
+}
+
+impl<N, K> Extend<(N, K)> for UnsortedQueue<N, K>
+where
+    K: PartialOrd,
+{
+    fn extend<I: IntoIterator<Item = (N, K)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<N, K> PriorityQueueDecKey<N, K> for UnsortedQueue<N, K>
+where
+    N: Eq + Clone,
+    K: PartialOrd + Clone,
+{
+    #[inline(always)]
+    fn contains(&self, node: &N) -> bool {
+        self.position_of(node).is_some()
+    }
+
+    fn key_of(&self, node: &N) -> Option<K> {
+        self.key_ref(node).cloned()
+    }
+
+    fn key_ref(&self, node: &N) -> Option<&K> {
+        self.position_of(node).map(|i| &self.0[i].1)
+    }
+
+    fn decrease_key(&mut self, node: &N, decreased_key: K) -> K {
+        let position = self
+            .position_of(node)
+            .expect("cannot decrease key of a node that is not on the queue");
+        assert!(
+            decreased_key <= self.0[position].1,
+            "decrease_key is called with a greater key"
+        );
+        core::mem::replace(&mut self.0[position].1, decreased_key)
+    }
+
+    fn increase_key(&mut self, node: &N, increased_key: K) {
+        let position = self
+            .position_of(node)
+            .expect("cannot increase key of a node that is not on the queue");
+        assert!(
+            increased_key >= self.0[position].1,
+            "increase_key is called with a smaller key"
+        );
+        self.0[position].1 = increased_key;
+    }
+
+    fn update_key(&mut self, node: &N, new_key: K) -> ResUpdateKey {
+        let position = self
+            .position_of(node)
+            .expect("cannot update key of a node that is not on the queue");
+        let key = &mut self.0[position].1;
+        let result = match new_key < *key {
+            true => ResUpdateKey::Decreased,
+            false => ResUpdateKey::Increased,
+        };
+        *key = new_key;
+        result
+    }
+
+    fn remove(&mut self, node: &N) -> K {
+        let position = self
+            .position_of(node)
+            .expect("cannot remove a node that is not on the queue");
+        self.0.swap_remove(position).1
+    }
+
+    fn take(&mut self, node: &N) -> Option<(N, K)> {
+        self.position_of(node).map(|i| self.0.swap_remove(i))
+    }
+
+    fn update_key_if<F>(&mut self, node: &N, new_key: K, should_update: F) -> bool
+    where
+        F: FnOnce(&K, &K) -> bool,
+    {
+        let position = self
+            .position_of(node)
+            .expect("cannot update key of a node that is not on the queue");
+        let key = &mut self.0[position].1;
+        match should_update(key, &new_key) {
+            true => {
+                *key = new_key;
+                true
+            }
+            false => false,
+        }
+    }
+}
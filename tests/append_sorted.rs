@@ -0,0 +1,126 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+
+const N: usize = 200;
+
+fn assert_pops_sorted<P>(mut pq: P, expected_len: usize)
+where
+    P: PriorityQueue<usize, f64>,
+{
+    let mut prev = f64::NEG_INFINITY;
+    let mut count = 0;
+    while let Some((_, key)) = pq.pop() {
+        assert!(prev <= key);
+        prev = key;
+        count += 1;
+    }
+    assert_eq!(expected_len, count);
+}
+
+fn test_dary<const D: usize>(len_self: usize, len_other: usize) {
+    let mut rng = rand::thread_rng();
+
+    let mut pq = DaryHeap::<usize, f64, D>::default();
+    let mut other = DaryHeap::<usize, f64, D>::default();
+    for _ in 0..len_self {
+        pq.push(rng.gen_range(0..N), rng.gen());
+    }
+    for _ in 0..len_other {
+        other.push(rng.gen_range(0..N), rng.gen());
+    }
+
+    pq.append_sorted(other);
+    assert_eq!(len_self + len_other, pq.len());
+    assert_eq!(Ok(()), pq.validate());
+
+    assert_pops_sorted(pq, len_self + len_other);
+}
+
+fn test_deckey<P>(
+    mut pq: P,
+    mut other: P,
+    len_self: usize,
+    len_other: usize,
+    append_sorted: fn(&mut P, P),
+    validate: fn(&P) -> Result<(), HeapError>,
+) where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+    let mut expected = HashMap::new();
+
+    for _ in 0..len_self {
+        let (node, key) = (rng.gen_range(0..N), rng.gen());
+        pq.update_key_or_push(&node, key);
+        expected.insert(node, key);
+    }
+    let mut other_nodes = HashMap::new();
+    for _ in 0..len_other {
+        let (node, key) = (rng.gen_range(0..N), rng.gen());
+        other.update_key_or_push(&node, key);
+        other_nodes.insert(node, key);
+    }
+    for (node, key) in other_nodes {
+        let min = expected.get(&node).map_or(key, |&k: &f64| k.min(key));
+        expected.insert(node, min);
+    }
+
+    append_sorted(&mut pq, other);
+    assert_eq!(Ok(()), validate(&pq));
+    assert_eq!(expected.len(), pq.len());
+    for (node, key) in &expected {
+        assert_eq!(Some(*key), pq.key_of(node));
+    }
+
+    assert_pops_sorted(pq, expected.len());
+}
+
+fn test_for<const D: usize>() {
+    let lengths = [(0, 0), (0, 50), (50, 0), (100, 5), (5, 100), (80, 90)];
+    for (len_self, len_other) in lengths {
+        test_dary::<D>(len_self, len_other);
+        test_deckey(
+            DaryHeapWithMap::<usize, f64, D>::default(),
+            DaryHeapWithMap::<usize, f64, D>::default(),
+            len_self,
+            len_other,
+            DaryHeapWithMap::append_sorted,
+            DaryHeapWithMap::validate,
+        );
+        test_deckey(
+            DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+            DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+            len_self,
+            len_other,
+            DaryHeapOfIndices::append_sorted,
+            DaryHeapOfIndices::validate,
+        );
+    }
+}
+
+#[test]
+fn append_sorted() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn append_sorted_k_way_merge() {
+    let runs = [vec![1, 4, 7, 10], vec![2, 5, 8], vec![0, 3, 6, 9, 11]];
+
+    let mut merged = QuaternaryHeap::default();
+    for run in &runs {
+        let mut heap = QuaternaryHeap::default();
+        for &key in run {
+            heap.push(key, key);
+        }
+        merged.append_sorted(heap);
+    }
+
+    let keys: Vec<_> = merged.into_iter_sorted().map(|(_, key)| key).collect();
+    assert_eq!(keys, (0..12).collect::<Vec<_>>());
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "arrayvec")]
+
+mod priority_queue_tests;
+
+use orx_priority_queue::{ArrayDaryHeap, DaryHeap, PriorityQueue};
+use priority_queue_tests::*;
+use rand::prelude::*;
+
+#[test]
+fn test_array_dary_forall() {
+    test_array_dary_for::<2, 256>();
+    test_array_dary_for::<3, 256>();
+    test_array_dary_for::<4, 256>();
+    test_array_dary_for::<8, 256>();
+    test_array_dary_for::<13, 256>();
+}
+
+fn test_array_dary_for<const D: usize, const CAP: usize>() {
+    let new_heap = ArrayDaryHeap::<usize, f64, D, CAP>::default;
+
+    test_len(new_heap());
+    test_is_empty(new_heap());
+    test_peek(new_heap());
+    test_or_default(new_heap());
+    test_clear(new_heap());
+    test_push_pop(new_heap());
+    test_push_pop_randomized(new_heap());
+    test_push_then_pop(new_heap());
+    test_push_then_pop_randomized(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
+}
+
+fn offset<const D: usize>() -> usize {
+    match D.is_power_of_two() && D <= 64 {
+        true => D - 1,
+        false => 0,
+    }
+}
+
+fn test_capacity_for<const D: usize, const CAP: usize>() {
+    let mut rng = rand::thread_rng();
+    let max_len = CAP.saturating_sub(offset::<D>());
+    assert_eq!(max_len, ArrayDaryHeap::<usize, u32, D, CAP>::max_len());
+
+    let mut pq = ArrayDaryHeap::<usize, u32, D, CAP>::new();
+    assert_eq!(max_len, pq.capacity());
+
+    for node in 0..max_len {
+        assert!(!pq.is_full());
+        assert_eq!(Ok(()), pq.try_push(node, rng.gen_range(0..100)));
+        assert_eq!(Ok(()), pq.validate());
+    }
+    assert!(pq.is_full());
+
+    let slice_before = pq.as_slice().to_vec();
+    assert_eq!(Err((max_len, 0)), pq.try_push(max_len, 0));
+    assert_eq!(slice_before.as_slice(), pq.as_slice());
+
+    // push_then_pop never grows the heap
+    let (_, key) = pq.push_then_pop(max_len, 50);
+    assert!(key <= 50);
+    assert_eq!(max_len, pq.len());
+
+    let mut prev = None;
+    while let Some((_, key)) = pq.pop() {
+        if let Some(prev) = prev {
+            assert!(prev <= key);
+        }
+        prev = Some(key);
+    }
+    assert!(!pq.is_full() || max_len == 0);
+}
+
+#[test]
+fn array_dary_heap_capacity() {
+    test_capacity_for::<2, 1>();
+    test_capacity_for::<2, 16>();
+    test_capacity_for::<3, 16>();
+    test_capacity_for::<4, 3>();
+    test_capacity_for::<4, 16>();
+    test_capacity_for::<8, 32>();
+    test_capacity_for::<13, 8>();
+}
+
+#[test]
+#[should_panic(expected = "ArrayDaryHeap is full")]
+fn array_dary_heap_push_when_full() {
+    let mut pq = ArrayDaryHeap::<usize, u32, 4, 5>::new();
+    pq.push(0, 0);
+    pq.push(1, 1);
+    pq.push(2, 2);
+}
+
+#[test]
+fn array_dary_heap_same_as_dary_heap() {
+    let mut rng = rand::thread_rng();
+    let mut array = ArrayDaryHeap::<usize, u32, 4, 64>::new();
+    let mut dary = DaryHeap::<usize, u32, 4>::new();
+
+    for node in 0..1000 {
+        let key = rng.gen_range(0..100);
+        match array.try_push(node, key) {
+            Ok(()) => dary.push(node, key),
+            Err(_) => assert_eq!(61, dary.len()),
+        }
+        if rng.gen_bool(0.4) {
+            assert_eq!(dary.pop(), array.pop());
+        }
+        assert_eq!(dary.as_slice(), array.as_slice());
+    }
+    while let Some(x) = dary.pop() {
+        assert_eq!(Some(x), array.pop());
+    }
+    assert!(array.is_empty());
+}
+
+#[test]
+fn array_dary_heap_try_push_returns_unmodified_pair() {
+    let mut pq = ArrayDaryHeap::<String, Vec<u32>, 2, 3>::new();
+    assert_eq!(Ok(()), pq.try_push("a".to_string(), vec![3, 1]));
+    assert_eq!(Ok(()), pq.try_push("b".to_string(), vec![1, 4]));
+
+    let rejected = pq.try_push("c".to_string(), vec![0, 9]);
+    assert_eq!(Err(("c".to_string(), vec![0, 9])), rejected);
+    assert_eq!(2, pq.len());
+
+    // make room for the rejected pair
+    let (node, key) = rejected.expect_err("heap is full");
+    assert_eq!(Some(("b".to_string(), vec![1, 4])), pq.pop());
+    assert_eq!(Ok(()), pq.try_push(node, key));
+    assert_eq!(Some(("c".to_string(), vec![0, 9])), pq.pop());
+}
@@ -0,0 +1,74 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const LEN: usize = 100;
+
+fn name(node: usize) -> String {
+    format!("node-{}", node)
+}
+
+fn test_borrowed_lookup<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut pq = DaryHeapWithMap::<String, u32, D>::default();
+    let mut keys: Vec<Option<u32>> = vec![None; LEN];
+
+    for _ in 0..(4 * LEN) {
+        let node = rng.gen_range(0..LEN);
+        let key = rng.gen_range(0..1000);
+        let name = name(node);
+        let name = name.as_str();
+
+        assert_eq!(keys[node].is_some(), pq.contains(name));
+        assert_eq!(keys[node], pq.key_of(name));
+        assert_eq!(keys[node].as_ref(), pq.key_ref(name));
+
+        match (keys[node], rng.gen_range(0..4)) {
+            (None, _) => {
+                pq.push(name.to_string(), key);
+                keys[node] = Some(key);
+            }
+            (Some(old), 0) => {
+                assert_eq!(old, pq.remove(name));
+                keys[node] = None;
+            }
+            (Some(old), 1) => {
+                assert_eq!(Some((name.to_string(), old)), pq.take(name));
+                keys[node] = None;
+            }
+            (Some(old), 2) if key <= old => {
+                assert_eq!(old, pq.decrease_key(name, key));
+                keys[node] = Some(key);
+            }
+            (Some(old), _) => {
+                let expected = match key < old {
+                    true => ResUpdateKey::Decreased,
+                    false => ResUpdateKey::Increased,
+                };
+                assert_eq!(expected, pq.update_key(name, key));
+                keys[node] = Some(key);
+            }
+        }
+
+        if let Some(position) = pq.position_of(name) {
+            assert_eq!(name, pq.as_slice()[position].0);
+        }
+        assert!(pq.validate().is_ok());
+    }
+
+    let mut prev_key = 0;
+    while let Some((node, key)) = pq.pop() {
+        let node: usize = node["node-".len()..].parse().expect("valid node");
+        assert_eq!(keys[node], Some(key));
+        assert!(prev_key <= key);
+        prev_key = key;
+    }
+}
+
+#[test]
+fn borrowed_lookup() {
+    test_borrowed_lookup::<2>();
+    test_borrowed_lookup::<3>();
+    test_borrowed_lookup::<4>();
+    test_borrowed_lookup::<8>();
+    test_borrowed_lookup::<13>();
+}
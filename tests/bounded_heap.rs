@@ -0,0 +1,83 @@
+use orx_priority_queue::BoundedDaryHeap;
+use rand::prelude::*;
+
+#[test]
+fn bounded_heap_keeps_smallest_forall() {
+    bounded_heap_keeps_smallest::<2>();
+    bounded_heap_keeps_smallest::<3>();
+    bounded_heap_keeps_smallest::<4>();
+    bounded_heap_keeps_smallest::<8>();
+    bounded_heap_keeps_smallest::<13>();
+}
+
+fn bounded_heap_keeps_smallest<const D: usize>() {
+    let mut rng = rand::thread_rng();
+
+    for bound in [1, 2, 5, 17, 100] {
+        let keys: Vec<u32> = (0..400).map(|_| rng.gen_range(0..1000)).collect();
+
+        let mut smallest = BoundedDaryHeap::<usize, u32, D>::with_bound(bound);
+        for (node, key) in keys.iter().copied().enumerate() {
+            let was_full = smallest.is_full();
+            let max = smallest.peek_max().map(|(_, k)| *k);
+
+            match smallest.push(node, key) {
+                None => assert!(!was_full),
+                Some((out_node, out_key)) => {
+                    assert!(was_full);
+                    match out_node == node {
+                        true => assert!(key >= max.expect("full heap has a max")),
+                        false => assert_eq!(max, Some(out_key)),
+                    }
+                }
+            }
+
+            assert!(smallest.len() <= bound);
+            assert_eq!(Ok(()), smallest.validate());
+        }
+
+        assert!(smallest.is_full());
+        assert_eq!(bound, smallest.bound());
+
+        let mut expected = keys.clone();
+        expected.sort();
+        expected.truncate(bound);
+        assert_eq!(expected.last(), smallest.peek_max().map(|(_, k)| k));
+
+        let sorted = smallest.into_sorted_vec();
+        let sorted_keys: Vec<_> = sorted.iter().map(|(_, k)| *k).collect();
+        assert_eq!(expected, sorted_keys);
+        assert!(sorted.iter().all(|(node, key)| keys[*node] == *key));
+    }
+}
+
+#[test]
+fn bounded_heap_zero_bound_rejects_all() {
+    let mut smallest = BoundedDaryHeap::<char, i32, 4>::with_bound(0);
+    assert!(smallest.is_full());
+
+    for (node, key) in [('a', 3), ('b', -1), ('c', 0)] {
+        assert_eq!(Some((node, key)), smallest.push(node, key));
+    }
+
+    assert!(smallest.is_empty());
+    assert_eq!(None, smallest.peek_max());
+    assert!(smallest.into_sorted_vec().is_empty());
+}
+
+#[test]
+fn bounded_heap_clear_keeps_bound() {
+    let mut smallest = BoundedDaryHeap::<char, i32>::with_bound(2);
+    smallest.push('a', 3);
+    smallest.push('b', 1);
+    smallest.push('c', 2);
+
+    smallest.clear();
+    assert!(smallest.is_empty());
+    assert_eq!(2, smallest.bound());
+
+    assert_eq!(None, smallest.push('d', 9));
+    assert_eq!(None, smallest.push('e', 8));
+    assert_eq!(Some(('d', 9)), smallest.push('f', 7));
+    assert_eq!(vec![('f', 7), ('e', 8)], smallest.into_sorted_vec());
+}
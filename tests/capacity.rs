@@ -0,0 +1,75 @@
+use orx_priority_queue::*;
+
+const CAPACITIES: [usize; 6] = [0, 1, 2, 7, 16, 100];
+
+fn test_capacity_and_len<P>(mut pq: P, capacity: usize)
+where
+    P: PriorityQueue<usize, u64>,
+{
+    assert!(pq.capacity() >= capacity);
+    assert_eq!(0, pq.len());
+    assert!(pq.is_empty());
+    assert_eq!(0, pq.iter().count());
+    assert!(pq.peek().is_none());
+
+    for node in 0..capacity {
+        pq.push(node, node as u64);
+        assert_eq!(node + 1, pq.len());
+        assert!(pq.capacity() >= pq.len());
+    }
+    assert_eq!(capacity, pq.iter().count());
+
+    for node in 0..capacity {
+        assert_eq!(Some(node), pq.pop_node());
+        assert_eq!(capacity - node - 1, pq.len());
+    }
+    assert!(pq.is_empty());
+    assert_eq!(0, pq.iter().count());
+
+    pq.clear();
+    assert_eq!(0, pq.len());
+}
+
+fn test_for<const D: usize>() {
+    // fresh heaps without any allocation
+    assert_eq!(0, DaryHeap::<usize, u64, D>::new().capacity());
+    assert_eq!(0, DaryHeapWithMap::<usize, u64, D>::new().capacity());
+    assert_eq!(
+        0,
+        DaryHeapOfIndices::<usize, u64, D>::with_index_bound(8).capacity()
+    );
+    assert_eq!(
+        0,
+        DaryHeapOfIndices::<usize, u64, D>::with_index_bound_and_capacity(8, 0).capacity()
+    );
+
+    for capacity in CAPACITIES {
+        test_capacity_and_len(DaryHeap::<usize, u64, D>::with_capacity(capacity), capacity);
+        test_capacity_and_len(
+            DaryHeapWithMap::<usize, u64, D>::with_capacity(capacity),
+            capacity,
+        );
+
+        let mut pq = DaryHeapOfIndices::<usize, u64, D>::with_index_bound(capacity);
+        pq.reserve(capacity);
+        test_capacity_and_len(pq, capacity);
+
+        let pq = DaryHeapOfIndices::<usize, u64, D>::with_index_bound_and_capacity(
+            2 * capacity,
+            capacity,
+        );
+        assert_eq!(2 * capacity, pq.index_bound());
+        test_capacity_and_len(pq, capacity);
+    }
+}
+
+#[test]
+fn capacity_and_len() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<16>();
+    test_for::<32>();
+    test_for::<64>();
+}
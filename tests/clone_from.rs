@@ -0,0 +1,100 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn test_clone_from<P>(new: impl Fn() -> P, slice_ptr: impl Fn(&P) -> *const (usize, u32))
+where
+    P: PriorityQueue<usize, u32> + PartialEq + Clone,
+{
+    let mut rng = rand::thread_rng();
+
+    let mut source = new();
+    for node in 0..N {
+        source.push(node, rng.gen_range(0..1000));
+    }
+
+    // target large enough to hold the source
+    let mut target = new();
+    for node in 0..N {
+        target.push(node, rng.gen_range(0..1000));
+    }
+    target.clear();
+    let capacity_before = target.capacity();
+    let ptr_before = slice_ptr(&target);
+
+    target.clone_from(&source);
+    assert!(target == source);
+    assert_eq!(capacity_before, target.capacity());
+    assert_eq!(ptr_before, slice_ptr(&target));
+
+    // target with elements
+    let mut target = new();
+    for node in (0..N).step_by(3) {
+        target.push(node, 0);
+    }
+    target.clone_from(&source);
+    assert!(target == source);
+
+    // empty source
+    target.clone_from(&new());
+    assert!(target.is_empty());
+
+    // clone_from is independent of the source
+    target.clone_from(&source);
+    let mut prev = None;
+    while let Some((_, key)) = target.pop() {
+        if let Some(prev) = prev {
+            assert!(prev <= key);
+        }
+        prev = Some(key);
+    }
+    assert_eq!(N, source.len());
+}
+
+fn test_for<const D: usize>() {
+    test_clone_from(DaryHeap::<usize, u32, D>::default, |x| {
+        x.as_slice().as_ptr()
+    });
+    test_clone_from(DaryHeapWithMap::<usize, u32, D>::default, |x| {
+        x.as_slice().as_ptr()
+    });
+    test_clone_from(
+        || DaryHeapOfIndices::<usize, u32, D>::with_index_bound(N),
+        |x| x.as_slice().as_ptr(),
+    );
+
+    // validity of the positions
+    let mut source = DaryHeapWithMap::<usize, u32, D>::default();
+    let mut target = DaryHeapOfIndices::<usize, u32, D>::with_index_bound(N);
+    for node in 0..N {
+        source.push(node, (node as u32 * 7) % 31);
+        target.push(node, (node as u32 * 7) % 31);
+    }
+    let mut other = source.clone();
+    other.clear();
+    other.push(42, 42);
+    other.clone_from(&source);
+    assert_eq!(Ok(()), other.validate());
+    for node in 0..N {
+        assert_eq!(source.key_of(&node), other.key_of(&node));
+    }
+
+    let mut indices = DaryHeapOfIndices::<usize, u32, D>::with_index_bound(2 * N);
+    indices.push(N + 1, 0);
+    indices.clone_from(&target);
+    assert_eq!(Ok(()), indices.validate());
+    assert_eq!(N, indices.index_bound());
+    for node in 0..N {
+        assert_eq!(target.key_of(&node), indices.key_of(&node));
+    }
+}
+
+#[test]
+fn clone_from() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,42 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 50;
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut pq = DaryHeap::<usize, f64, D>::default();
+    let mut counts = [0; N];
+
+    for node in 0..N {
+        assert!(!pq.contains(&node));
+    }
+
+    for _ in 0..500 {
+        match rng.gen_bool(0.6) {
+            true => {
+                let node = rng.gen_range(0..N);
+                pq.push(node, rng.gen());
+                counts[node] += 1;
+            }
+            false => {
+                if let Some(node) = pq.pop_node() {
+                    counts[node] -= 1;
+                }
+            }
+        }
+
+        for (node, count) in counts.iter().enumerate() {
+            assert_eq!(*count > 0, pq.contains(&node));
+        }
+    }
+}
+
+#[test]
+fn dary_contains() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -23,9 +23,14 @@ fn test_dary_for<const D: usize>() {
     test_is_empty(new_heap());
     test_as_slice(new_heap());
     test_peek(new_heap());
+    test_or_default(new_heap());
     test_clear(new_heap());
     test_push_pop(new_heap());
     test_push_pop_randomized(new_heap());
     test_push_then_pop(new_heap());
     test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
 }
@@ -29,6 +29,11 @@ fn peek() {
     test_peek(new_heap())
 }
 
+#[test]
+fn or_default() {
+    test_or_default(new_heap())
+}
+
 #[test]
 fn clear() {
     test_clear(new_heap())
@@ -52,3 +57,18 @@ fn push_then_pop() {
 fn push_then_pop_randomized() {
     test_push_then_pop_randomized(new_heap())
 }
+
+#[test]
+fn arity() {
+    test_arity(new_heap(), Some(D))
+}
+
+#[test]
+fn nodes_keys() {
+    test_nodes_keys(new_heap())
+}
+
+#[test]
+fn iter_rev() {
+    test_iter_rev(new_heap())
+}
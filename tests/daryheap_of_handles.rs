@@ -0,0 +1,128 @@
+mod priority_queue_tests;
+
+use orx_priority_queue::{DaryHeapOfHandles, Handle, PriorityQueue, ResUpdateKey};
+use priority_queue_tests::*;
+use rand::prelude::*;
+
+#[test]
+fn test_dary_of_handles_forall() {
+    test_dary_of_handles_for::<2>();
+    test_dary_of_handles_for::<3>();
+    test_dary_of_handles_for::<4>();
+    test_dary_of_handles_for::<8>();
+    test_dary_of_handles_for::<13>();
+}
+
+fn test_dary_of_handles_for<const D: usize>() {
+    let new_heap = DaryHeapOfHandles::<usize, f64, D>::default;
+
+    test_len(new_heap());
+    test_is_empty(new_heap());
+    test_peek(new_heap());
+    test_or_default(new_heap());
+    test_clear(new_heap());
+    test_push_pop(new_heap());
+    test_push_pop_randomized(new_heap());
+    test_push_then_pop(new_heap());
+    test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
+
+    test_handles_randomized::<D>();
+    test_handles_invalidated::<D>();
+}
+
+fn test_handles_randomized<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut pq = DaryHeapOfHandles::<usize, usize, D>::default();
+    let mut alive: Vec<(Handle, usize, usize)> = vec![];
+    let mut dead: Vec<Handle> = vec![];
+
+    for i in 0..500 {
+        match rng.gen_range(0..5) {
+            0 | 1 => {
+                let key = rng.gen_range(0..1000);
+                let handle = pq.push(i, key);
+                alive.push((handle, i, key));
+            }
+            2 if !alive.is_empty() => {
+                let idx = rng.gen_range(0..alive.len());
+                let (handle, _, key) = &mut alive[idx];
+                let decreased = *key / 2;
+                pq.decrease_key(handle, decreased);
+                *key = decreased;
+            }
+            3 if !alive.is_empty() => {
+                let idx = rng.gen_range(0..alive.len());
+                let (handle, _, key) = &mut alive[idx];
+                let new_key = rng.gen_range(0..1000);
+                let expected = match new_key < *key {
+                    true => ResUpdateKey::Decreased,
+                    false => ResUpdateKey::Increased,
+                };
+                assert_eq!(expected, pq.update_key(handle, new_key));
+                *key = new_key;
+            }
+            4 if !alive.is_empty() => {
+                let idx = rng.gen_range(0..alive.len());
+                let (handle, node, key) = alive.swap_remove(idx);
+                assert_eq!((node, key), pq.remove(&handle));
+                dead.push(handle);
+            }
+            _ => {}
+        }
+
+        assert_eq!(Ok(()), pq.validate());
+        assert_eq!(alive.len(), pq.len());
+        for (handle, node, key) in &alive {
+            assert!(pq.contains(handle));
+            assert_eq!(Some(node), pq.node_of(handle));
+            assert_eq!(Some(key), pq.key_of(handle));
+        }
+        for handle in &dead {
+            assert!(!pq.contains(handle));
+            assert_eq!(None, pq.key_of(handle));
+        }
+    }
+
+    let mut prev_key = 0;
+    while let Some((_, key)) = pq.pop() {
+        assert!(prev_key <= key);
+        prev_key = key;
+    }
+    for (handle, _, _) in &alive {
+        assert!(!pq.contains(handle));
+    }
+}
+
+fn test_handles_invalidated<const D: usize>() {
+    let mut pq = DaryHeapOfHandles::<char, usize, D>::default();
+
+    let a = pq.push('a', 1);
+    let b = pq.push('b', 2);
+    assert_eq!(Some(('a', 1)), pq.pop());
+    assert!(!pq.contains(&a));
+
+    // the slot of 'a' is reused; yet, its handle remains invalid
+    let c = pq.push('c', 3);
+    assert!(!pq.contains(&a));
+    assert!(pq.contains(&b));
+    assert!(pq.contains(&c));
+    assert_eq!(Some(&'c'), pq.node_of(&c));
+
+    pq.clear();
+    assert!(!pq.contains(&b));
+    assert!(!pq.contains(&c));
+
+    let d = pq.push('d', 4);
+    assert_eq!(
+        (0, 4),
+        (pq.push_then_pop('x', 0).1, *pq.key_of(&d).unwrap())
+    );
+    assert_eq!(('d', 4), pq.push_then_pop('y', 5));
+    assert!(!pq.contains(&d));
+    assert_eq!(1, pq.len());
+    assert_eq!(Ok(()), pq.validate());
+}
@@ -30,19 +30,33 @@ fn test_dary_for<const D: usize>() {
     test_len(new_heap());
     test_is_empty(new_heap());
     test_peek(new_heap());
+    test_or_default(new_heap());
     test_clear(new_heap());
     test_push_pop(new_heap());
     test_push_pop_randomized(new_heap());
     test_push_then_pop(new_heap());
     test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_extend_duplicates(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
 
     test_contains(new_heap());
     test_key_of(new_heap());
+    test_entries(new_heap());
     change_key
         .iter()
         .for_each(|change_key_method| test_change_key(new_heap(), *change_key_method));
+    test_decrease_key_all(new_heap());
+    test_increase_key(new_heap());
     test_remove(new_heap());
     test_mixed(new_heap());
+    test_decrease_key_ref(DaryHeapOfIndices::<_, _, D>::with_index_bound(125));
+    test_decrease_key_by(new_heap());
+    test_update_key_if(new_heap());
+    test_push_if_lower(new_heap());
+    test_take(new_heap());
 
     change_key
         .iter()
@@ -26,6 +26,11 @@ fn peek() {
     test_peek(new_heap())
 }
 
+#[test]
+fn or_default() {
+    test_or_default(new_heap())
+}
+
 #[test]
 fn clear() {
     test_clear(new_heap())
@@ -50,6 +55,21 @@ fn push_then_pop_randomized() {
     test_push_then_pop_randomized(new_heap())
 }
 
+#[test]
+fn arity() {
+    test_arity(new_heap(), Some(D))
+}
+
+#[test]
+fn nodes_keys() {
+    test_nodes_keys(new_heap())
+}
+
+#[test]
+fn iter_rev() {
+    test_iter_rev(new_heap())
+}
+
 #[test]
 fn contains() {
     test_contains(new_heap());
@@ -58,6 +78,7 @@ fn contains() {
 #[test]
 fn key_of() {
     test_key_of(new_heap());
+    test_entries(new_heap());
 }
 
 #[test]
@@ -75,6 +96,16 @@ fn try_decrease_key() {
     test_change_key(new_heap(), ChangeKeyMethod::TryDecrease);
 }
 
+#[test]
+fn decrease_key_all() {
+    test_decrease_key_all(new_heap());
+}
+
+#[test]
+fn increase_key() {
+    test_increase_key(new_heap());
+}
+
 #[test]
 fn remove() {
     test_remove(new_heap());
@@ -85,6 +116,31 @@ fn mixed() {
     test_mixed(new_heap());
 }
 
+#[test]
+fn decrease_key_ref() {
+    test_decrease_key_ref(DaryHeapOfIndices::<_, _, D>::with_index_bound(125));
+}
+
+#[test]
+fn decrease_key_by() {
+    test_decrease_key_by(new_heap());
+}
+
+#[test]
+fn update_key_if() {
+    test_update_key_if(new_heap());
+}
+
+#[test]
+fn push_if_lower() {
+    test_push_if_lower(new_heap());
+}
+
+#[test]
+fn take() {
+    test_take(new_heap());
+}
+
 #[test]
 fn decrease_key_or_push() {
     test_change_key_or_push(new_heap(), ChangeKeyMethod::Decrease);
@@ -4,6 +4,8 @@ mod priority_queue_tests;
 use orx_priority_queue::DaryHeapWithMap;
 use priority_queue_deckey_tests::*;
 use priority_queue_tests::*;
+#[cfg(feature = "std")]
+use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
 
 #[test]
 fn test_dary_forall() {
@@ -30,21 +32,68 @@ fn test_dary_for<const D: usize>() {
     test_len(new_heap());
     test_is_empty(new_heap());
     test_peek(new_heap());
+    test_or_default(new_heap());
     test_clear(new_heap());
     test_push_pop(new_heap());
     test_push_pop_randomized(new_heap());
     test_push_then_pop(new_heap());
     test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_extend_duplicates(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
 
     test_contains(new_heap());
     test_key_of(new_heap());
+    test_entries(new_heap());
     change_key
         .iter()
         .for_each(|change_key_method| test_change_key(new_heap(), *change_key_method));
+    test_decrease_key_all(new_heap());
+    test_increase_key(new_heap());
     test_remove(new_heap());
     test_mixed(new_heap());
+    test_decrease_key_ref(DaryHeapWithMap::<_, _, D>::default());
+    test_decrease_key_by(new_heap());
+    test_update_key_if(new_heap());
+    test_push_if_lower(new_heap());
+    test_take(new_heap());
 
     change_key
         .iter()
         .for_each(|change_key_method| test_change_key_or_push(new_heap(), *change_key_method));
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dary_with_hasher_forall() {
+    test_dary_with_hasher_for::<2>();
+    test_dary_with_hasher_for::<4>();
+    test_dary_with_hasher_for::<8>();
+}
+
+#[cfg(feature = "std")]
+fn test_dary_with_hasher_for<const D: usize>() {
+    type Hasher = BuildHasherDefault<DefaultHasher>;
+    let new_heap = || DaryHeapWithMap::<usize, f64, D, Hasher>::with_hasher(Hasher::default());
+
+    test_len(new_heap());
+    test_push_pop_randomized(new_heap());
+    test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_extend_duplicates(new_heap());
+
+    test_contains(new_heap());
+    test_key_of(new_heap());
+    test_entries(new_heap());
+    test_decrease_key_all(new_heap());
+    test_increase_key(new_heap());
+    test_remove(new_heap());
+    test_mixed(new_heap());
+    test_change_key_or_push(new_heap(), ChangeKeyMethod::Update);
+
+    let with_capacity = DaryHeapWithMap::<usize, f64, D, Hasher>::with_capacity_and_hasher;
+    test_mixed(with_capacity(16, Hasher::default()));
+    test_mixed(DaryHeapWithMap::<usize, f64, D, Hasher>::default());
+}
@@ -0,0 +1,84 @@
+#![cfg(all(feature = "debug_nan_check", debug_assertions))]
+
+use orx_priority_queue::*;
+
+#[test]
+#[should_panic(expected = "push is called with a key which is not comparable to itself")]
+fn push_nan() {
+    let mut queue = QuaternaryHeap::default();
+    queue.push('a', 1.0);
+    queue.push('b', f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "push is called with a key which is not comparable to itself")]
+fn push_nan_to_empty() {
+    let mut queue = BinaryHeapWithMap::default();
+    queue.push('a', f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "push_then_pop is called with a key which is not comparable to itself")]
+fn push_then_pop_nan() {
+    let mut queue = BinaryHeap::default();
+    queue.push('a', 1.0);
+    queue.push_then_pop('b', f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "push is called with a key which is not comparable to itself")]
+fn extend_nan() {
+    let mut queue = DaryHeap::<char, f64, 4>::default();
+    queue.extend([('a', 1.0), ('b', f64::NAN)]);
+}
+
+#[test]
+#[should_panic(expected = "decrease_key is called with a key which is not comparable to itself")]
+fn decrease_key_nan() {
+    let mut queue = BinaryHeapOfIndices::with_index_bound(4);
+    queue.push(0usize, 1.0);
+    queue.decrease_key(&0, f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "decrease_key is called with a key which is not comparable to itself")]
+fn decrease_key_all_nan() {
+    let mut queue = QuaternaryHeapWithMap::default();
+    queue.push('a', 1.0);
+    queue.push('b', 2.0);
+    queue.decrease_key_all([('a', 0.5), ('b', f64::NAN)]);
+}
+
+#[test]
+#[should_panic(expected = "increase_key is called with a key which is not comparable to itself")]
+fn increase_key_nan() {
+    let mut queue = BinaryHeapWithMap::default();
+    queue.push('a', 1.0);
+    queue.increase_key(&'a', f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "update_key is called with a key which is not comparable to itself")]
+fn update_key_nan() {
+    let mut queue = BinaryHeapWithMap::default();
+    queue.push('a', 1.0);
+    queue.push('b', 2.0);
+    queue.update_key(&'b', f64::NAN);
+}
+
+#[test]
+fn comparable_keys_pass() {
+    let mut queue = QuaternaryHeapWithMap::default();
+    queue.push('a', 3.0);
+    queue.push('b', 2.0);
+    queue.decrease_key(&'a', 1.0);
+    queue.update_key(&'b', 0.5);
+    queue.increase_key(&'b', f64::INFINITY);
+
+    let mut floats = BinaryHeap::default();
+    floats.push('x', FloatKey::new(f64::NAN));
+    floats.push('y', FloatKey::new(1.0));
+
+    assert_eq!(Some(('a', 1.0)), queue.pop());
+    assert_eq!(Some('y'), floats.pop().map(|(node, _)| node));
+}
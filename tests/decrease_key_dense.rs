@@ -0,0 +1,105 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+const LEN: usize = 100;
+
+fn test_decrease_key_dense<const D: usize>() {
+    let mut rng = ChaCha8Rng::seed_from_u64(5612);
+    let mut pq = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(LEN + 10);
+    let mut keys: Vec<Option<f64>> = vec![None; LEN + 10];
+
+    for node in (0..LEN).filter(|node| node % 3 != 0) {
+        let key = rng.gen();
+        pq.push(node, key);
+        keys[node] = Some(key);
+    }
+
+    // sparse and dense passes, including a slice shorter than the index bound
+    for (num_updates, slice_len) in [
+        (0, LEN),
+        (1, LEN),
+        (3, LEN / 2),
+        (10, LEN),
+        (60, LEN),
+        (100, LEN + 10),
+    ] {
+        let mut new_keys = vec![None; slice_len];
+        for _ in 0..num_updates {
+            let node = rng.gen_range(0..slice_len);
+            if let Some(key) = keys[node] {
+                let decreased_key = key * rng.gen::<f64>();
+                new_keys[node] = Some(decreased_key);
+                keys[node] = Some(decreased_key);
+            }
+        }
+
+        pq.decrease_key_dense(&new_keys);
+        assert_eq!(Ok(()), pq.validate());
+        for (node, key) in keys.iter().enumerate() {
+            assert_eq!(*key, pq.key_of(&node));
+        }
+    }
+
+    let mut expected: Vec<_> = keys
+        .iter()
+        .enumerate()
+        .filter_map(|(node, key)| key.map(|key| (node, key)))
+        .collect();
+    expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let popped: Vec<_> = pq.into_iter_sorted().collect();
+    assert_eq!(expected, popped);
+}
+
+#[test]
+fn decrease_key_dense() {
+    test_decrease_key_dense::<2>();
+    test_decrease_key_dense::<3>();
+    test_decrease_key_dense::<4>();
+    test_decrease_key_dense::<8>();
+    test_decrease_key_dense::<13>();
+}
+
+#[test]
+#[should_panic(expected = "cannot decrease key of a node that is not on the queue")]
+fn decrease_key_dense_absent_node() {
+    let mut pq = BinaryHeapOfIndices::with_index_bound(4);
+    pq.push(0usize, 10);
+    pq.push(1, 20);
+    pq.decrease_key_dense(&[None, None, Some(5)]);
+}
+
+#[test]
+#[should_panic(expected = "cannot decrease key of a node that is not on the queue")]
+fn decrease_key_dense_out_of_bound() {
+    let mut pq = BinaryHeapOfIndices::with_index_bound(2);
+    pq.push(0usize, 10);
+    pq.decrease_key_dense(&[None, None, Some(5)]);
+}
+
+#[test]
+#[should_panic(expected = "decrease_key is called with a greater key")]
+fn decrease_key_dense_greater_key() {
+    let mut pq = BinaryHeapOfIndices::with_index_bound(4);
+    pq.push(0usize, 10);
+    pq.push(1, 20);
+    pq.decrease_key_dense(&[Some(5), Some(25)]);
+}
+
+#[test]
+fn decrease_key_dense_leaves_valid_queue_on_panic() {
+    let mut pq = QuaternaryHeapOfIndices::with_index_bound(8);
+    for node in 0..8usize {
+        pq.push(node, 10 + node);
+    }
+
+    let new_keys = [Some(9), Some(1), Some(0), Some(30), Some(2)];
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        pq.decrease_key_dense(&new_keys)
+    }));
+    assert!(result.is_err());
+    assert_eq!(Ok(()), pq.validate());
+    assert_eq!(Some((2, 0)), pq.pop());
+    assert_eq!(Some((1, 1)), pq.pop());
+    assert_eq!(Some((0, 9)), pq.pop());
+}
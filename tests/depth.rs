@@ -0,0 +1,76 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn expected_depth(d: usize, len: usize) -> usize {
+    let mut depth = 0;
+    let mut capacity = 0;
+    let mut level_size = 1;
+    while capacity < len {
+        capacity += level_size;
+        level_size *= d;
+        depth += 1;
+    }
+    depth
+}
+
+fn expected_level(d: usize, position: usize) -> usize {
+    let mut level = 0;
+    let mut position = position;
+    while position > 0 {
+        position = (position - 1) / d;
+        level += 1;
+    }
+    level
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let n = 300;
+
+    let mut dary = DaryHeap::<usize, u32, D>::default();
+    let mut map = DaryHeapWithMap::<usize, u32, D>::default();
+    let mut indices = DaryHeapOfIndices::<usize, u32, D>::with_index_bound(n);
+
+    assert_eq!(0, dary.depth());
+    assert_eq!(0, map.depth());
+    assert_eq!(0, indices.depth());
+
+    for node in 0..n {
+        let key = rng.gen_range(0..1000);
+        dary.push(node, key);
+        map.push(node, key);
+        indices.push(node, key);
+
+        let depth = expected_depth(D, node + 1);
+        assert_eq!(depth, dary.depth());
+        assert_eq!(depth, map.depth());
+        assert_eq!(depth, indices.depth());
+    }
+
+    for node in 0..n {
+        let level = map.level_of(&node).expect("node is on the heap");
+        assert_eq!(expected_level(D, map.position_of(&node).unwrap()), level);
+        assert!(level < map.depth());
+
+        let level = indices.level_of(&node).expect("node is on the heap");
+        assert_eq!(
+            expected_level(D, indices.position_of(&node).unwrap()),
+            level
+        );
+        assert!(level < indices.depth());
+    }
+
+    while let Some((node, _)) = map.pop() {
+        assert_eq!(None, map.level_of(&node));
+        assert_eq!(expected_depth(D, map.len()), map.depth());
+    }
+}
+
+#[test]
+fn depth_and_level_of() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
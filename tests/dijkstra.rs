@@ -82,3 +82,50 @@ fn dijkstra() {
     assert_eq!(shortest_path(&graph, 0, 4), Some(5));
     assert_eq!(shortest_path(&graph, 4, 0), None);
 }
+
+#[test]
+fn dijkstra_push_if_lower() {
+    use orx_priority_queue::*;
+
+    struct Edge {
+        node: usize,
+        cost: usize,
+    }
+
+    // Same as above, relaxing the edges by `push_if_lower`.
+    fn shortest_path(adj_list: &[Vec<Edge>], start: usize, goal: usize) -> Option<usize> {
+        let mut heap = QuaternaryHeapOfIndices::with_index_bound(adj_list.len());
+        heap.push(start, 0);
+
+        while let Some((position, cost)) = heap.pop() {
+            if position == goal {
+                return Some(cost);
+            }
+
+            for edge in &adj_list[position] {
+                heap.push_if_lower(&edge.node, cost + edge.cost);
+            }
+        }
+
+        None
+    }
+
+    // The same graph as above.
+    let graph = vec![
+        vec![Edge { node: 2, cost: 10 }, Edge { node: 1, cost: 1 }],
+        vec![Edge { node: 3, cost: 2 }],
+        vec![
+            Edge { node: 1, cost: 1 },
+            Edge { node: 3, cost: 3 },
+            Edge { node: 4, cost: 1 },
+        ],
+        vec![Edge { node: 0, cost: 7 }, Edge { node: 4, cost: 2 }],
+        vec![],
+    ];
+
+    assert_eq!(shortest_path(&graph, 0, 1), Some(1));
+    assert_eq!(shortest_path(&graph, 0, 3), Some(3));
+    assert_eq!(shortest_path(&graph, 3, 0), Some(7));
+    assert_eq!(shortest_path(&graph, 0, 4), Some(5));
+    assert_eq!(shortest_path(&graph, 4, 0), None);
+}
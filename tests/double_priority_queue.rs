@@ -0,0 +1,72 @@
+#![cfg(feature = "impl_priority_queue")]
+
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+const N: usize = 100;
+
+fn random_ops<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, u64>,
+{
+    let mut rng = ChaCha8Rng::seed_from_u64(9127);
+    let mut model: Vec<Option<u64>> = vec![None; N];
+
+    for _ in 0..1000 {
+        let node = rng.gen_range(0..N);
+        let key = rng.gen_range(0..1000);
+
+        match rng.gen_range(0..4) {
+            0 => {
+                if model[node].take().is_some() {
+                    pq.remove(&node);
+                }
+            }
+            1 => {
+                let popped = pq.pop();
+                let min = model.iter().flatten().min().copied();
+                assert_eq!(min, popped.map(|x| x.1));
+                if let Some((node, _)) = popped {
+                    model[node] = None;
+                }
+            }
+            _ => {
+                let expected = match model[node] {
+                    None => ResTryDecreaseKeyOrPush::Pushed,
+                    Some(old_key) if key < old_key => ResTryDecreaseKeyOrPush::Decreased,
+                    Some(_) => ResTryDecreaseKeyOrPush::Unchanged,
+                };
+                assert_eq!(expected, pq.try_decrease_key_or_push(&node, key));
+                model[node] = Some(model[node].map(|x| x.min(key)).unwrap_or(key));
+            }
+        }
+
+        assert_eq!(model[node], pq.key_of(&node));
+        assert_eq!(model.iter().flatten().count(), pq.len());
+        assert_eq!(model.iter().flatten().min(), pq.peek().map(|x| x.key()));
+    }
+
+    let mut expected: Vec<_> = model.iter().flatten().copied().collect();
+    expected.sort();
+    let popped: Vec<_> = core::iter::from_fn(|| pq.pop_key()).collect();
+    assert_eq!(expected, popped);
+}
+
+#[test]
+fn double_priority_queue_is_min_queue() {
+    random_ops(priority_queue::DoublePriorityQueue::<usize, u64>::new());
+}
+
+#[test]
+fn double_priority_queue_push_then_pop() {
+    let mut pq = priority_queue::DoublePriorityQueue::new();
+    PriorityQueue::push(&mut pq, 0, 42);
+    PriorityQueue::push(&mut pq, 1, 7);
+
+    assert_eq!((1, 7), PriorityQueue::push_then_pop(&mut pq, 2, 21));
+    assert_eq!((3, 3), PriorityQueue::push_then_pop(&mut pq, 3, 3));
+    assert_eq!(Some((2, 21)), PriorityQueue::pop(&mut pq));
+    assert_eq!(Some((0, 42)), PriorityQueue::pop(&mut pq));
+    assert_eq!(None, PriorityQueue::pop(&mut pq));
+}
@@ -0,0 +1,99 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let len = 100;
+    let keys = (0..len).map(|_| rng.gen::<u32>()).collect_vec();
+
+    let mut dary = DaryHeap::<usize, u32, D>::default();
+    let mut map = DaryHeapWithMap::<usize, u32, D>::default();
+    let mut indices = DaryHeapOfIndices::<usize, u32, D>::with_index_bound(len);
+
+    // drained heaps are empty when the iterator is dropped, even if not fully consumed
+    for num_drained in [len, 40, 0] {
+        for (node, key) in keys.iter().enumerate() {
+            dary.push(node, *key);
+            map.push(node, *key);
+            indices.push(node, *key);
+        }
+
+        let expected_order = dary
+            .as_slice()
+            .iter()
+            .take(num_drained)
+            .cloned()
+            .collect_vec();
+        let mut drain = dary.drain();
+        assert_eq!(len, drain.len());
+        let drained = drain.by_ref().take(num_drained).collect_vec();
+        assert_eq!(len - num_drained, drain.len());
+        drop(drain);
+        assert_eq!(expected_order, drained);
+        assert!(dary.is_empty());
+        assert_eq!(Ok(()), dary.validate());
+
+        let drained = map.drain().take(num_drained).collect_vec();
+        assert_eq!(num_drained, drained.len());
+        assert!(drained.iter().all(|(node, key)| keys[*node] == *key));
+        assert!(map.is_empty());
+        assert!((0..len).all(|node| !map.contains(&node)));
+        assert_eq!(Ok(()), map.validate());
+
+        let drained = indices.drain().take(num_drained).collect_vec();
+        assert_eq!(num_drained, drained.len());
+        assert!(drained.iter().all(|(node, key)| keys[*node] == *key));
+        assert!(indices.is_empty());
+        assert!((0..len).all(|node| !indices.contains(&node)));
+        assert_eq!(Ok(()), indices.validate());
+    }
+
+    // all elements are drained exactly once
+    for (node, key) in keys.iter().enumerate() {
+        map.push(node, *key);
+    }
+    let drained = map.drain().sorted().collect_vec();
+    let expected = keys.iter().copied().enumerate().collect_vec();
+    assert_eq!(expected, drained);
+
+    // drained heaps are reusable
+    dary.push(0, 1);
+    map.push(0, 1);
+    indices.push(0, 1);
+    assert_eq!(Some((0, 1)), dary.pop());
+    assert_eq!(Some((0, 1)), map.pop());
+    assert_eq!(Some((0, 1)), indices.pop());
+}
+
+#[test]
+fn drain() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn drain_empty() {
+    let mut queue = QuaternaryHeapWithMap::<char, i32>::new();
+    assert_eq!(0, queue.drain().count());
+    queue.push('a', 1);
+    queue.clear();
+    assert_eq!(0, queue.drain().count());
+    queue.push('b', 2);
+    assert_eq!(vec![('b', 2)], queue.drain().collect_vec());
+}
+
+#[test]
+fn drain_double_ended() {
+    let mut queue = BinaryHeap::default();
+    for node in 0..10 {
+        queue.push(node, node);
+    }
+    let expected = queue.as_slice().iter().rev().cloned().collect_vec();
+    let drained = queue.drain().rev().collect_vec();
+    assert_eq!(expected, drained);
+    assert!(queue.is_empty());
+}
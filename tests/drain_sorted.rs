@@ -0,0 +1,73 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn sorted(keys: &[f64]) -> Vec<f64> {
+    keys.iter()
+        .copied()
+        .sorted_by(|a, b| a.partial_cmp(b).expect("no nan"))
+        .collect()
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let len = 100;
+    let keys = (0..len).map(|_| rng.gen()).collect_vec();
+
+    let mut dary = DaryHeap::<usize, f64, D>::default();
+    let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+    let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+
+    for num_drained in [len, 40, 0] {
+        for (node, key) in keys.iter().enumerate() {
+            dary.push(node, *key);
+            map.push(node, *key);
+            indices.push(node, *key);
+        }
+
+        let expected = sorted(&keys).into_iter().take(num_drained).collect_vec();
+
+        let mut drain = dary.drain_sorted();
+        assert_eq!(len, drain.len());
+        let drained = drain.by_ref().take(num_drained).map(|x| x.1).collect_vec();
+        assert_eq!(len - num_drained, drain.len());
+        drop(drain);
+        assert_eq!(expected, drained);
+        assert!(dary.is_empty());
+
+        let drained = map
+            .drain_sorted()
+            .take(num_drained)
+            .map(|x| x.1)
+            .collect_vec();
+        assert_eq!(expected, drained);
+        assert!(map.is_empty());
+        assert!((0..len).all(|node| !map.contains(&node)));
+
+        let drained = indices
+            .drain_sorted()
+            .take(num_drained)
+            .map(|x| x.1)
+            .collect_vec();
+        assert_eq!(expected, drained);
+        assert!(indices.is_empty());
+        assert!((0..len).all(|node| !indices.contains(&node)));
+    }
+
+    // drained heaps are reusable
+    dary.push(0, 1.0);
+    map.push(0, 1.0);
+    indices.push(0, 1.0);
+    assert_eq!(Some((0, 1.0)), dary.pop());
+    assert_eq!(Some((0, 1.0)), map.pop());
+    assert_eq!(Some((0, 1.0)), indices.pop());
+}
+
+#[test]
+fn drain_sorted() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
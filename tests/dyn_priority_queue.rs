@@ -0,0 +1,71 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn test_dyn(queue: &mut dyn DynPriorityQueue<usize, u64>) {
+    let mut rng = rand::thread_rng();
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(None, queue.peek_cloned());
+
+    let mut expected: Vec<_> = (0..N).map(|node| (node, rng.gen_range(0..1000))).collect();
+    for (node, key) in &expected {
+        queue.push(*node, *key);
+    }
+    assert_eq!(N, queue.len());
+    expected.sort_by_key(|x| x.1);
+
+    let min_key = expected[0].1;
+    let peeked = queue.peek_cloned().expect("not empty");
+    assert_eq!(min_key, peeked.1);
+    assert_eq!(N, queue.len());
+
+    let popped = queue.pop().expect("not empty");
+    assert_eq!(min_key, popped.1);
+    assert_eq!(Some(expected[1].1), queue.pop_key());
+    assert!(queue.pop_node().is_some());
+    assert_eq!(N - 3, queue.len());
+
+    let mut prev_key = 0;
+    while let Some((_, key)) = queue.pop() {
+        assert!(prev_key <= key);
+        prev_key = key;
+    }
+    assert!(queue.is_empty());
+}
+
+fn new_queue(kind: usize) -> Box<dyn DynPriorityQueue<usize, u64>> {
+    match kind {
+        0 => Box::new(BinaryHeap::default()),
+        1 => Box::new(QuaternaryHeap::default()),
+        2 => Box::new(DaryHeap::<_, _, 13>::default()),
+        3 => Box::new(QuaternaryHeapWithMap::default()),
+        4 => Box::new(QuaternaryHeapOfIndices::with_index_bound(N)),
+        5 => Box::new(StableDaryHeap::<_, _, 4>::default()),
+        _ => Box::new(UnsortedQueue::default()),
+    }
+}
+
+#[test]
+fn dyn_priority_queue() {
+    let mut queues: Vec<_> = (0..7).map(new_queue).collect();
+    for queue in queues.iter_mut() {
+        test_dyn(queue.as_mut());
+        test_dyn(queue.as_mut());
+    }
+}
+
+#[test]
+fn dyn_priority_queue_methods_on_concrete_queues() {
+    let mut queue = QuaternaryHeap::default();
+    queue.dyn_push('a', 42);
+    queue.dyn_push('b', 7);
+
+    assert_eq!(2, queue.dyn_len());
+    assert_eq!(2, queue.len());
+    assert_eq!(Some(('b', 7)), queue.peek_cloned());
+    assert_eq!(Some(('b', 7)), queue.dyn_pop());
+    assert_eq!(Some(42), queue.dyn_pop_key());
+    assert!(queue.dyn_is_empty());
+}
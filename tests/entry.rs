@@ -0,0 +1,94 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn random_ops<P, E>(pq: &mut P, entry: E)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+    E: Fn(&mut P, usize) -> Entry<'_, usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+    let mut model: Vec<Option<f64>> = vec![None; N];
+
+    for _ in 0..1000 {
+        let node = rng.gen_range(0..N);
+        let key: f64 = rng.gen();
+
+        match entry(pq, node) {
+            Entry::Vacant(vacant) => {
+                assert_eq!(None, model[node]);
+                assert_eq!(&node, vacant.node());
+                vacant.push(key);
+                model[node] = Some(key);
+            }
+            Entry::Occupied(occupied) => {
+                let old_key = model[node].expect("occupied entry must exist in the model");
+                assert_eq!(&node, occupied.node());
+                assert_eq!(&old_key, occupied.key());
+                match rng.gen_range(0..3) {
+                    0 => {
+                        let decreased = old_key * key;
+                        occupied.decrease_key(decreased);
+                        model[node] = Some(decreased);
+                    }
+                    1 => {
+                        let res = occupied.update_key(key);
+                        assert_eq!(key < old_key, matches!(res, ResUpdateKey::Decreased));
+                        model[node] = Some(key);
+                    }
+                    _ => {
+                        assert_eq!(old_key, occupied.remove());
+                        model[node] = None;
+                    }
+                }
+            }
+        }
+
+        assert_eq!(model[node], pq.key_of(&node));
+        assert_eq!(model.iter().flatten().count(), pq.len());
+    }
+
+    let mut expected: Vec<_> = model
+        .iter()
+        .enumerate()
+        .filter_map(|(node, key)| key.map(|key| (node, key)))
+        .collect();
+    expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let popped: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+    assert_eq!(expected, popped);
+}
+
+fn test_for<const D: usize>() {
+    random_ops(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        DaryHeapWithMap::entry,
+    );
+    random_ops(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        DaryHeapOfIndices::entry,
+    );
+}
+
+#[test]
+fn entry() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn vacant_entry_into_node() {
+    let mut pq = QuaternaryHeapWithMap::<char, u32>::default();
+    pq.push('a', 3);
+
+    assert!(matches!(pq.entry('a'), Entry::Occupied(_)));
+    match pq.entry('b') {
+        Entry::Vacant(vacant) => assert_eq!('b', vacant.into_node()),
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_eq!(1, pq.len());
+    assert_eq!(None, pq.key_of(&'b'));
+}
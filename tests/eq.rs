@@ -0,0 +1,108 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn test_eq<P>(new: impl Fn() -> P)
+where
+    P: PriorityQueue<usize, u32> + PartialEq + Clone + core::fmt::Debug,
+{
+    let mut rng = rand::thread_rng();
+
+    // distinct nodes with many equal keys
+    let mut elements: Vec<(usize, u32)> = (0..N).map(|node| (node, rng.gen_range(0..10))).collect();
+
+    let (mut a, mut b) = (new(), new());
+    assert_eq!(a, b);
+
+    for (node, key) in &elements {
+        a.push(*node, *key);
+    }
+    elements.shuffle(&mut rng);
+    for (node, key) in &elements {
+        b.push(*node, *key);
+    }
+    assert_eq!(a, b);
+    assert_eq!(b, a);
+
+    // different key
+    let (node, key) = b.pop().expect("not empty");
+    assert_ne!(a, b);
+    b.push(node, key + 1);
+    assert_ne!(a, b);
+    assert_ne!(b, a);
+
+    // different node with the same key
+    let mut b = new();
+    for (node, key) in elements.iter().skip(1) {
+        b.push(*node, *key);
+    }
+    assert_ne!(a, b);
+    let (_, key) = elements[0];
+    b.push(N, key);
+    assert_ne!(a, b);
+    assert_ne!(b, a);
+
+    // popping the same number of elements keeps equality
+    let mut b = a.clone();
+    for _ in 0..N / 2 {
+        a.pop();
+        b.pop();
+        assert_eq!(a.len(), b.len());
+    }
+    let (a_keys, b_keys): (Vec<_>, Vec<_>) = (
+        core::iter::from_fn(|| a.pop_key()).collect(),
+        core::iter::from_fn(|| b.pop_key()).collect(),
+    );
+    assert_eq!(a_keys, b_keys);
+}
+
+fn test_for<const D: usize>() {
+    test_eq(DaryHeap::<usize, u32, D>::default);
+    test_eq(DaryHeapWithMap::<usize, u32, D>::default);
+    test_eq(|| DaryHeapOfIndices::<usize, u32, D>::with_index_bound(N + 1));
+}
+
+#[test]
+fn eq() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn eq_dary_heap_with_duplicates() {
+    let mut a = QuaternaryHeap::default();
+    let mut b = QuaternaryHeap::default();
+    for (node, key) in [('x', 1), ('y', 1), ('x', 1), ('z', 2)] {
+        a.push(node, key);
+    }
+    for (node, key) in [('z', 2), ('x', 1), ('y', 1), ('x', 1)] {
+        b.push(node, key);
+    }
+    assert_eq!(a, b);
+
+    // same nodes and keys, different multiplicities
+    let mut c = QuaternaryHeap::default();
+    for (node, key) in [('x', 1), ('y', 1), ('y', 1), ('z', 2)] {
+        c.push(node, key);
+    }
+    assert_ne!(a, c);
+    assert_ne!(c, a);
+}
+
+#[test]
+fn eq_heap_of_indices_with_different_index_bounds() {
+    let mut a = QuaternaryHeapOfIndices::with_index_bound(4);
+    let mut b = QuaternaryHeapOfIndices::with_index_bound(100);
+    a.push(3usize, 42);
+    b.push(3usize, 42);
+    assert_eq!(a, b);
+
+    b.pop();
+    b.push(99, 42);
+    assert_ne!(a, b);
+    assert_ne!(b, a);
+}
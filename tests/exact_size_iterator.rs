@@ -0,0 +1,46 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const LEN: usize = 100;
+
+fn assert_len_decreases<I: ExactSizeIterator>(mut iter: I, len: usize) {
+    for remaining in (0..len).rev() {
+        assert_eq!(remaining + 1, iter.len());
+        assert!(iter.next().is_some());
+        assert_eq!(remaining, iter.len());
+    }
+    assert!(iter.next().is_none());
+    assert_eq!(0, iter.len());
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let elements: Vec<(usize, u32)> = (0..LEN).map(|node| (node, rng.gen())).collect();
+
+    let mut pq = DaryHeap::<usize, u32, D>::from(elements.clone());
+    assert_len_decreases(pq.iter_sorted(), LEN);
+    assert_len_decreases(pq.clone().into_iter_sorted(), LEN);
+    assert_len_decreases(pq.drain_sorted(), LEN);
+    assert!(pq.is_empty());
+
+    let mut pq = DaryHeapWithMap::<usize, u32, D>::from(elements.clone());
+    assert_len_decreases(pq.clone().into_iter_sorted(), LEN);
+    assert_len_decreases(pq.drain_sorted(), LEN);
+
+    let mut pq = DaryHeapOfIndices::<usize, u32, D>::from(elements.clone());
+    assert_len_decreases(pq.clone().into_iter_sorted(), LEN);
+    assert_len_decreases(pq.drain_sorted(), LEN);
+
+    // exact size hint allows collect to preallocate
+    let pq = DaryHeap::<usize, u32, D>::from(elements);
+    assert_eq!((LEN, Some(LEN)), pq.into_iter_sorted().size_hint());
+}
+
+#[test]
+fn exact_size_iterator() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,83 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash_of(key: FloatKey) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn float_key_total_order() {
+    let nan = FloatKey::new(f64::NAN);
+    let neg_nan = FloatKey::new(-f64::NAN);
+    let inf = FloatKey::new(f64::INFINITY);
+    let neg_inf = FloatKey::new(f64::NEG_INFINITY);
+    let zero = FloatKey::new(0.0);
+    let neg_zero = FloatKey::new(-0.0);
+
+    assert!(neg_inf < FloatKey::new(-1.0));
+    assert!(FloatKey::new(-1.0) < neg_zero);
+    assert!(zero < FloatKey::new(1.0));
+    assert!(FloatKey::new(1.0) < inf);
+    assert!(inf < nan);
+
+    assert_eq!(zero, neg_zero);
+    assert_eq!(hash_of(zero), hash_of(neg_zero));
+
+    assert_eq!(nan, neg_nan);
+    assert_eq!(hash_of(nan), hash_of(neg_nan));
+    assert_eq!(core::cmp::Ordering::Equal, nan.cmp(&neg_nan));
+}
+
+#[test]
+fn float_key_conversions() {
+    let key = FloatKey::from(2.5);
+    assert_eq!(FloatKey::new(2.5), key);
+    assert_eq!(2.5, key.get());
+    assert_eq!(2.5, f64::from(key));
+
+    let value: f64 = FloatKey(4.0).into();
+    assert_eq!(4.0, value);
+    assert_eq!("4", FloatKey(4.0).to_string());
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+
+    let mut keys: Vec<f64> = (0..200).map(|_| rng.gen_range(-1.0..1.0)).collect();
+    keys.extend([
+        f64::NAN,
+        0.0,
+        -0.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ]);
+    keys.shuffle(&mut rng);
+
+    let mut queue = DaryHeap::<usize, FloatKey, D>::default();
+    for (node, key) in keys.iter().enumerate() {
+        queue.push(node, FloatKey::from(*key));
+        assert_eq!(Ok(()), queue.validate());
+    }
+
+    let popped: Vec<_> = core::iter::from_fn(|| queue.pop_key()).collect();
+    let mut expected: Vec<_> = keys.iter().copied().map(FloatKey::from).collect();
+    expected.sort();
+    assert_eq!(expected, popped);
+
+    assert!(popped[..popped.len() - 2].iter().all(|x| !x.get().is_nan()));
+    assert!(popped[popped.len() - 2..].iter().all(|x| x.get().is_nan()));
+}
+
+#[test]
+fn float_key_heap_with_nan() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
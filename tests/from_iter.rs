@@ -0,0 +1,83 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+
+const N: usize = 50;
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+
+    for len in [0, 1, 10, 100, 1000] {
+        let pairs: Vec<(usize, u64)> = (0..len)
+            .map(|_| (rng.gen_range(0..N), rng.gen_range(0..1000)))
+            .collect();
+
+        let mut expected = HashMap::new();
+        let mut reference = DaryHeapWithMap::<usize, u64, D>::default();
+        for (node, key) in &pairs {
+            let min = expected.entry(*node).or_insert(*key);
+            *min = (*min).min(*key);
+            reference.try_decrease_key_or_push(node, *key);
+        }
+
+        let queue: DaryHeapWithMap<usize, u64, D> = pairs.into_iter().collect();
+        assert_eq!(Ok(()), queue.validate());
+        assert_eq!(expected.len(), queue.len());
+        for (node, key) in &expected {
+            assert_eq!(Some(*key), queue.key_of(node));
+        }
+        assert_eq!(reference, queue);
+
+        let keys: Vec<_> = queue.into_iter_sorted().map(|x| x.1).collect();
+        assert!(keys.windows(2).all(|x| x[0] <= x[1]));
+    }
+}
+
+#[test]
+fn from_iter_keeps_min_key() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+fn test_reporting_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+
+    for len in [0, 1, 10, 100, 1000] {
+        let pairs: Vec<(usize, u64)> = (0..len)
+            .map(|_| (rng.gen_range(0..N), rng.gen_range(0..1000)))
+            .collect();
+
+        let mut min_keys: HashMap<usize, u64> = HashMap::new();
+        let mut expected_superseded = vec![];
+        for (node, key) in &pairs {
+            match min_keys.get_mut(node) {
+                Some(min) if key < min => {
+                    expected_superseded.push((*node, *min));
+                    *min = *key;
+                }
+                Some(_) => expected_superseded.push((*node, *key)),
+                None => _ = min_keys.insert(*node, *key),
+            }
+        }
+
+        let collected: DaryHeapWithMap<usize, u64, D> = pairs.iter().copied().collect();
+        let (queue, superseded) = DaryHeapWithMap::<usize, u64, D>::from_iter_reporting(pairs);
+
+        assert_eq!(Ok(()), queue.validate());
+        assert_eq!(collected.as_slice(), queue.as_slice());
+        assert_eq!(expected_superseded, superseded);
+        assert_eq!(len, queue.len() + superseded.len());
+    }
+}
+
+#[test]
+fn from_iter_reporting_superseded_pairs() {
+    test_reporting_for::<2>();
+    test_reporting_for::<3>();
+    test_reporting_for::<4>();
+    test_reporting_for::<8>();
+    test_reporting_for::<13>();
+}
@@ -0,0 +1,86 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+
+const N: usize = 50;
+
+fn random_pairs(len: usize) -> Vec<(usize, u64)> {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|_| (rng.gen_range(0..N), rng.gen_range(0..1000)))
+        .collect()
+}
+
+fn min_keys(pairs: &[(usize, u64)]) -> HashMap<usize, u64> {
+    let mut expected = HashMap::new();
+    for (node, key) in pairs {
+        let min = expected.entry(*node).or_insert(*key);
+        *min = (*min).min(*key);
+    }
+    expected
+}
+
+fn sorted_keys<P: PriorityQueue<usize, u64>>(mut pq: P) -> Vec<u64> {
+    core::iter::from_fn(|| pq.pop_key()).collect()
+}
+
+fn test_for<const D: usize>() {
+    for len in [0, 1, 10, 100, 1000] {
+        let pairs = random_pairs(len);
+        let expected = min_keys(&pairs);
+
+        let queue = DaryHeap::<usize, u64, D>::from(pairs.clone());
+        assert_eq!(Ok(()), queue.validate());
+        assert_eq!(pairs.len(), queue.len());
+        let mut keys: Vec<_> = pairs.iter().map(|x| x.1).collect();
+        keys.sort();
+        assert_eq!(keys, sorted_keys(queue));
+
+        let queue = DaryHeapWithMap::<usize, u64, D>::from(pairs.clone());
+        assert_eq!(Ok(()), queue.validate());
+        assert_eq!(expected.len(), queue.len());
+        for (node, key) in &expected {
+            assert_eq!(Some(key), queue.key_ref(node));
+        }
+
+        let queue = DaryHeapOfIndices::<usize, u64, D>::from(pairs.clone());
+        assert_eq!(Ok(()), queue.validate());
+        assert_eq!(expected.len(), queue.len());
+        let index_bound = pairs.iter().map(|x| x.0 + 1).max().unwrap_or(0);
+        assert_eq!(index_bound, queue.index_bound());
+        for (node, key) in &expected {
+            assert_eq!(Some(key), queue.key_ref(node));
+        }
+    }
+}
+
+#[test]
+fn from_vec() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn from_array() {
+    let elements = [(3usize, 5), (1, 2), (3, 4), (0, 9)];
+
+    let queue = QuaternaryHeap::from(elements);
+    assert_eq!(vec![2, 4, 5, 9], sorted_keys(queue));
+
+    let queue = QuaternaryHeapWithMap::from(elements);
+    assert_eq!(Some(&4), queue.key_ref(&3));
+    assert_eq!(vec![2, 4, 9], sorted_keys(queue));
+
+    let queue = QuaternaryHeapOfIndices::from(elements);
+    assert_eq!(4, queue.index_bound());
+    assert_eq!(Some(&4), queue.key_ref(&3));
+    assert_eq!(vec![2, 4, 9], sorted_keys(queue));
+
+    let empty: [(usize, u64); 0] = [];
+    let queue = QuaternaryHeapOfIndices::from(empty);
+    assert!(queue.is_empty());
+    assert_eq!(0, queue.index_bound());
+}
@@ -0,0 +1,56 @@
+use core::iter::FusedIterator;
+use orx_priority_queue::*;
+
+fn assert_fused<I: FusedIterator>(_: I) {}
+
+fn assert_exhausted_stays_exhausted<I: Iterator>(mut iter: I) {
+    while iter.next().is_some() {}
+    for _ in 0..4 {
+        assert!(iter.next().is_none());
+    }
+}
+
+fn queue<const D: usize>() -> DaryHeap<char, u32, D> {
+    DaryHeap::from([('a', 3), ('b', 1), ('c', 2)])
+}
+
+fn test_for<const D: usize>() {
+    let mut pq = queue::<D>();
+
+    assert_fused(pq.iter());
+    assert_fused(pq.iter_sorted());
+    assert_fused(pq.drain_sorted());
+    assert_fused(queue::<D>().into_iter_sorted());
+
+    let mut pq = queue::<D>();
+    assert_exhausted_stays_exhausted(pq.iter_sorted());
+    assert_exhausted_stays_exhausted(pq.drain_sorted());
+    assert_exhausted_stays_exhausted(queue::<D>().into_iter_sorted());
+
+    let mut pq = DaryHeapWithMap::<char, u32, D>::from([('a', 3), ('b', 1)]);
+    assert_fused(pq.iter_sorted());
+    assert_fused(pq.drain_sorted());
+
+    let mut pq = DaryHeapOfIndices::<usize, u32, D>::from([(0, 3), (1, 1)]);
+    assert_fused(pq.iter_sorted());
+    assert_fused(pq.drain_sorted());
+
+    let mut pq = StableDaryHeap::<char, u32, D>::default();
+    pq.push('a', 1);
+    assert_fused(pq.iter());
+    assert_exhausted_stays_exhausted(pq.iter());
+
+    let mut pq = DaryHeapOfHandles::<char, u32, D>::default();
+    pq.push('a', 1);
+    assert_fused(pq.iter());
+    assert_exhausted_stays_exhausted(pq.iter());
+}
+
+#[test]
+fn fused_iterator() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,80 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn random_ops<P, G>(pq: &mut P, get_or_insert_with: G)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+    G: for<'a> Fn(&'a mut P, usize, &mut dyn FnMut() -> f64) -> &'a f64,
+{
+    let mut rng = rand::thread_rng();
+    let mut model: Vec<Option<f64>> = vec![None; N];
+
+    for _ in 0..1000 {
+        let node = rng.gen_range(0..N);
+        let key: f64 = rng.gen();
+
+        match rng.gen_range(0..4) {
+            0 => {
+                if model[node].take().is_some() {
+                    pq.remove(&node);
+                }
+            }
+            _ => {
+                let mut num_calls = 0;
+                let returned = *get_or_insert_with(pq, node, &mut || {
+                    num_calls += 1;
+                    key
+                });
+                match model[node] {
+                    Some(old_key) => {
+                        assert_eq!(0, num_calls);
+                        assert_eq!(old_key, returned);
+                    }
+                    None => {
+                        assert_eq!(1, num_calls);
+                        assert_eq!(key, returned);
+                        model[node] = Some(key);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(model[node], pq.key_of(&node));
+        assert_eq!(model.iter().flatten().count(), pq.len());
+    }
+
+    let mut expected: Vec<_> = model
+        .iter()
+        .enumerate()
+        .filter_map(|(node, key)| key.map(|key| (node, key)))
+        .collect();
+    expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let popped: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+    assert_eq!(expected, popped);
+}
+
+fn test_for<const D: usize>() {
+    random_ops(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        |pq, node, default| pq.get_or_insert_with(node, default),
+    );
+    random_ops(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        |pq, node, default| pq.get_or_insert_with(node, default),
+    );
+    random_ops(
+        &mut DaryHeapWithSortedVec::<usize, f64, D>::default(),
+        |pq, node, default| pq.get_or_insert_with(node, default),
+    );
+}
+
+#[test]
+fn get_or_insert_with() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
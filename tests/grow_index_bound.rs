@@ -0,0 +1,51 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut pq = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(10);
+
+    for node in (0..10).step_by(2) {
+        pq.push(node, rng.gen());
+    }
+    let slice_before = pq.as_slice().to_vec();
+
+    pq.grow_index_bound(5);
+    assert_eq!(10, pq.index_bound());
+    pq.grow_index_bound(10);
+    assert_eq!(10, pq.index_bound());
+
+    let mut bound = 10;
+    for _ in 0..5 {
+        bound *= 2;
+        pq.grow_index_bound(bound);
+        assert_eq!(bound, pq.index_bound());
+        assert_eq!(slice_before.as_slice(), pq.as_slice());
+        assert_eq!(Ok(()), pq.validate());
+    }
+
+    for node in (0..bound).filter(|x| x % 2 == 1) {
+        assert!(!pq.contains(&node));
+        pq.push(node, rng.gen());
+    }
+    assert_eq!(Ok(()), pq.validate());
+
+    for (node, key) in &slice_before {
+        assert_eq!(Some(*key), pq.key_of(node));
+    }
+
+    let mut prev = f64::NEG_INFINITY;
+    while let Some((_, key)) = pq.pop() {
+        assert!(prev <= key);
+        prev = key;
+    }
+}
+
+#[test]
+fn grow_index_bound() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,116 @@
+use orx_priority_queue::*;
+use std::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+#[test]
+fn has_index_non_zero() {
+    assert_eq!(0, NonZeroUsize::MIN.index());
+    assert_eq!(0, NonZeroU64::MIN.index());
+    assert_eq!(0, NonZeroU32::MIN.index());
+    assert_eq!(0, NonZeroU16::MIN.index());
+    assert_eq!(0, NonZeroU8::MIN.index());
+
+    assert_eq!(41, NonZeroUsize::new(42).expect("non-zero").index());
+    assert_eq!(254, NonZeroU8::MAX.index());
+
+    let mut pq = QuaternaryHeapOfIndices::with_index_bound(4);
+    for n in 1..=4 {
+        pq.push(NonZeroU32::new(n).expect("non-zero"), 10 - n);
+    }
+    assert_eq!(Some(&NonZeroU32::new(4).expect("non-zero")), pq.peek_node());
+    pq.decrease_key(&NonZeroU32::MIN, 0);
+    assert_eq!(Some((NonZeroU32::MIN, 0)), pq.pop());
+}
+
+#[test]
+fn has_index_single_element_tuple() {
+    assert_eq!(7, (7usize,).index());
+    assert_eq!(7, (7u8,).index());
+
+    let mut pq = BinaryHeapOfIndices::with_index_bound(3);
+    pq.push((2u32,), 1.0);
+    pq.push((0u32,), 2.0);
+    pq.decrease_key(&(0,), 0.5);
+    assert_eq!(Some(((0,), 0.5)), pq.pop());
+    assert_eq!(Some(((2,), 1.0)), pq.pop());
+}
+
+#[test]
+fn has_index_char_and_bool() {
+    assert_eq!(97, 'a'.index());
+    assert_eq!(0x10FFFF, char::MAX.index());
+    assert_eq!(0, false.index());
+    assert_eq!(1, true.index());
+
+    let mut pq = BinaryHeapOfIndices::with_index_bound(128);
+    pq.push('x', 3);
+    pq.push('a', 5);
+    pq.push('z', 1);
+    pq.push('m', 4);
+    assert!(pq.contains(&'a'));
+    assert!(!pq.contains(&'b'));
+    pq.decrease_key(&'a', 2);
+    assert_eq!(Some(('z', 1)), pq.pop());
+    assert_eq!(Some(('a', 2)), pq.pop());
+    assert_eq!(Some(('x', 3)), pq.pop());
+    assert_eq!(Some(('m', 4)), pq.pop());
+    assert!(pq.is_empty());
+
+    let mut pq = BinaryHeapOfIndices::with_index_bound(2);
+    pq.push(true, 7);
+    pq.push(false, 9);
+    pq.update_key(&false, 1);
+    assert_eq!(Some((false, 1)), pq.pop());
+    assert_eq!(Some((true, 7)), pq.pop());
+}
+
+#[test]
+fn has_index_reference() {
+    let nodes: Vec<usize> = (0..4).collect();
+    assert_eq!(3, <&usize as HasIndex>::index(&&nodes[3]));
+    assert_eq!(3, <&&usize as HasIndex>::index(&&&nodes[3]));
+
+    let mut pq = QuaternaryHeapOfIndices::with_index_bound(nodes.len());
+    for node in &nodes {
+        pq.push(node, 10 - node);
+    }
+    assert!(pq.contains(&&nodes[2]));
+    assert_eq!(Some(&&nodes[3]), pq.peek_node());
+
+    pq.decrease_key(&&nodes[0], 0);
+    assert_eq!(Some((&nodes[0], 0)), pq.pop());
+    assert_eq!(Some((&nodes[3], 7)), pq.pop());
+    assert_eq!(Ok(()), pq.validate());
+}
+
+#[test]
+fn has_index_reference_to_struct() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct City {
+        id: u32,
+        name: String,
+    }
+
+    impl HasIndex for City {
+        fn index(&self) -> usize {
+            self.id.index()
+        }
+    }
+
+    let cities: Vec<_> = ["x", "y", "z"]
+        .into_iter()
+        .enumerate()
+        .map(|(id, name)| City {
+            id: id as u32,
+            name: name.to_string(),
+        })
+        .collect();
+
+    let mut pq = BinaryHeapOfIndices::with_index_bound(cities.len());
+    pq.push(&cities[2], 3.0);
+    pq.push(&cities[0], 2.0);
+    pq.push(&cities[1], 5.0);
+    pq.decrease_key(&&cities[2], 1.0);
+
+    let names: Vec<_> = pq.into_iter_sorted().map(|(city, _)| &city.name).collect();
+    assert_eq!(names, ["z", "x", "y"]);
+}
@@ -0,0 +1,98 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, RandomState};
+
+const N: usize = 100;
+
+fn hash_of<T: Hash>(value: &T, state: &RandomState) -> u64 {
+    state.hash_one(value)
+}
+
+fn test_hash<P>(new: impl Fn() -> P)
+where
+    P: PriorityQueue<usize, u32> + Eq + Hash + Clone,
+{
+    let mut rng = rand::thread_rng();
+    let state = RandomState::new();
+
+    // distinct nodes with many equal keys
+    let mut elements: Vec<(usize, u32)> = (0..N).map(|node| (node, rng.gen_range(0..10))).collect();
+
+    let (mut a, mut b) = (new(), new());
+    assert_eq!(hash_of(&a, &state), hash_of(&b, &state));
+
+    for (node, key) in &elements {
+        a.push(*node, *key);
+    }
+    elements.shuffle(&mut rng);
+    for (node, key) in &elements {
+        b.push(*node, *key);
+    }
+    assert!(a == b);
+    assert_eq!(hash_of(&a, &state), hash_of(&b, &state));
+
+    // equal after popping the same number of elements
+    let mut c = a.clone();
+    for _ in 0..N / 2 {
+        a.pop();
+        c.pop();
+    }
+    assert!(a == c);
+    assert_eq!(hash_of(&a, &state), hash_of(&c, &state));
+
+    // different key
+    let (node, key) = b.pop().expect("not empty");
+    b.push(node, key + 1);
+    assert!(a != b);
+    assert_ne!(hash_of(&a, &state), hash_of(&b, &state));
+
+    // usable as keys of a map
+    let mut memo = HashMap::new();
+    memo.insert(a.clone(), 1);
+    memo.insert(b.clone(), 2);
+    memo.insert(c.clone(), 3);
+    assert_eq!(2, memo.len());
+    assert_eq!(Some(&3), memo.get(&a));
+    assert_eq!(Some(&2), memo.get(&b));
+}
+
+fn test_for<const D: usize>() {
+    test_hash(DaryHeap::<usize, u32, D>::default);
+    test_hash(DaryHeapWithMap::<usize, u32, D>::default);
+    test_hash(|| DaryHeapOfIndices::<usize, u32, D>::with_index_bound(N + 1));
+}
+
+#[test]
+fn hash() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn hash_dary_heap_with_duplicates() {
+    let state = RandomState::new();
+    let a = QuaternaryHeap::from([('x', 1), ('y', 1), ('x', 1), ('z', 2)]);
+    let b = QuaternaryHeap::from([('z', 2), ('x', 1), ('y', 1), ('x', 1)]);
+    assert!(a == b);
+    assert_eq!(hash_of(&a, &state), hash_of(&b, &state));
+
+    // same nodes and keys, different multiplicities
+    let c = QuaternaryHeap::from([('x', 1), ('y', 1), ('y', 1), ('z', 2)]);
+    assert!(a != c);
+    assert_ne!(hash_of(&a, &state), hash_of(&c, &state));
+}
+
+#[test]
+fn hash_heap_of_indices_with_different_index_bounds() {
+    let state = RandomState::new();
+    let mut a = QuaternaryHeapOfIndices::with_index_bound(4);
+    let mut b = QuaternaryHeapOfIndices::with_index_bound(100);
+    a.push(3usize, 42);
+    b.push(3usize, 42);
+    assert!(a == b);
+    assert_eq!(hash_of(&a, &state), hash_of(&b, &state));
+}
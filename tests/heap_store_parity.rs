@@ -0,0 +1,85 @@
+#![cfg(feature = "smallvec")]
+
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const NUM_OPS: usize = 2000;
+
+fn assert_same<const D: usize, const INLINE: usize>(
+    vec: &DaryHeap<usize, u32, D>,
+    small: &SmallDaryHeap<usize, u32, D, INLINE>,
+) {
+    // identical sift logic leads to identical layouts of the trees
+    assert_eq!(vec.as_slice(), small.as_slice());
+    assert_eq!(vec.peek(), small.peek());
+    assert_eq!(Ok(()), small.validate());
+}
+
+fn test_parity_for<const D: usize, const INLINE: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut vec = DaryHeap::<usize, u32, D>::new();
+    let mut small = SmallDaryHeap::<usize, u32, D, INLINE>::new();
+
+    for i in 0..NUM_OPS {
+        match rng.gen_range(0..100) {
+            0..=44 => {
+                let key = rng.gen_range(0..100);
+                vec.push(i, key);
+                small.push(i, key);
+            }
+            45..=79 => assert_eq!(vec.pop(), small.pop()),
+            80..=89 => {
+                let key = rng.gen_range(0..100);
+                assert_eq!(vec.push_then_pop(i, key), small.push_then_pop(i, key));
+            }
+            90..=93 => {
+                let key = rng.gen_range(0..100);
+                if let Some(mut root) = vec.peek_mut() {
+                    *root.key_mut() = key;
+                }
+                if let Some(mut root) = small.peek_mut() {
+                    *root.key_mut() = key;
+                }
+            }
+            94..=96 => {
+                let elements: Vec<_> = (0..rng.gen_range(0..20))
+                    .map(|j| (NUM_OPS + j, rng.gen_range(0..100)))
+                    .collect();
+                vec.extend(elements.iter().copied());
+                small.extend(elements.iter().copied());
+            }
+            97 => {
+                for (_, key) in &mut vec.iter_mut() {
+                    *key = (*key * 7) % 100;
+                }
+                for (_, key) in &mut small.iter_mut() {
+                    *key = (*key * 7) % 100;
+                }
+            }
+            98 => {
+                vec.shrink_to_fit();
+                small.shrink_to_fit();
+            }
+            _ => {
+                vec.clear();
+                small.clear();
+            }
+        }
+        assert_same(&vec, &small);
+    }
+
+    while let Some(x) = vec.pop() {
+        assert_eq!(Some(x), small.pop());
+    }
+    assert!(small.is_empty());
+}
+
+#[test]
+fn heap_store_parity() {
+    test_parity_for::<2, 4>();
+    test_parity_for::<2, 64>();
+    test_parity_for::<3, 16>();
+    test_parity_for::<4, 16>();
+    test_parity_for::<8, 32>();
+    test_parity_for::<13, 8>();
+}
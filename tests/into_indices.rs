@@ -0,0 +1,90 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const LEN: usize = 100;
+
+fn random_elements() -> Vec<(usize, f64)> {
+    let mut rng = rand::thread_rng();
+    let mut nodes: Vec<_> = (0..LEN).collect();
+    nodes.shuffle(&mut rng);
+    nodes
+        .into_iter()
+        .take(LEN / 2)
+        .map(|node| (node, rng.gen()))
+        .collect()
+}
+
+fn pop_all<P: PriorityQueue<usize, f64>>(mut pq: P) -> Vec<(usize, f64)> {
+    core::iter::from_fn(|| pq.pop()).collect()
+}
+
+fn test_into_indices<const D: usize>() {
+    let elements = random_elements();
+    let max_index = elements.iter().map(|x| x.0).max().expect("not empty");
+
+    let mut with_map = DaryHeapWithMap::<usize, f64, D>::default();
+    for (node, key) in &elements {
+        with_map.push(*node, *key);
+    }
+
+    let error = with_map.clone().into_indices(max_index);
+    assert_eq!(
+        Some(IndexOutOfBound {
+            index: max_index,
+            bound: max_index
+        }),
+        error.err()
+    );
+
+    let of_indices = with_map.clone().into_indices(LEN).expect("within bound");
+    assert_eq!(Ok(()), of_indices.validate());
+    assert_eq!(LEN, of_indices.index_bound());
+    assert_eq!(with_map.as_slice(), of_indices.as_slice());
+    for (node, key) in &elements {
+        assert_eq!(Some(key), of_indices.key_ref(node));
+    }
+    assert_eq!(pop_all(with_map), pop_all(of_indices));
+}
+
+fn test_into_map<const D: usize>() {
+    let elements = random_elements();
+
+    let mut of_indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(LEN);
+    for (node, key) in &elements {
+        of_indices.push(*node, *key);
+    }
+
+    let with_map = of_indices.clone().into_map();
+    assert_eq!(Ok(()), with_map.validate());
+    assert_eq!(of_indices.as_slice(), with_map.as_slice());
+    for (node, key) in &elements {
+        assert_eq!(Some(key), with_map.key_ref(node));
+    }
+    assert_eq!(pop_all(of_indices), pop_all(with_map));
+}
+
+#[test]
+fn into_indices() {
+    test_into_indices::<2>();
+    test_into_indices::<3>();
+    test_into_indices::<4>();
+    test_into_indices::<8>();
+    test_into_indices::<13>();
+}
+
+#[test]
+fn into_indices_empty() {
+    let queue = QuaternaryHeapWithMap::<usize, u32>::default();
+    let queue = queue.into_indices(0).expect("no nodes");
+    assert!(queue.is_empty());
+    assert_eq!(0, queue.index_bound());
+}
+
+#[test]
+fn into_map() {
+    test_into_map::<2>();
+    test_into_map::<3>();
+    test_into_map::<4>();
+    test_into_map::<8>();
+    test_into_map::<13>();
+}
@@ -0,0 +1,89 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn assert_into_iter_sorted<I>(mut iter: I, mut expected_keys: Vec<f64>)
+where
+    I: Iterator<Item = (usize, f64)> + ExactSizeIterator,
+{
+    expected_keys.sort_by(|a, b| a.partial_cmp(b).expect("no nan"));
+    assert_eq!(expected_keys.len(), iter.len());
+
+    for (i, expected) in expected_keys.iter().enumerate() {
+        assert_eq!(Some(*expected), iter.next().map(|x| x.1));
+        assert_eq!(expected_keys.len() - i - 1, iter.len());
+    }
+    assert_eq!(None, iter.next());
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    for len in [0, 1, 2, 7, 100] {
+        let keys = (0..len).map(|_| rng.gen()).collect_vec();
+
+        let mut dary = DaryHeap::<usize, f64, D>::default();
+        let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+        let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+        for (node, key) in keys.iter().enumerate() {
+            dary.push(node, *key);
+            map.push(node, *key);
+            indices.push(node, *key);
+        }
+
+        assert_into_iter_sorted(dary.to_sorted_vec().into_iter(), keys.clone());
+        assert_into_iter_sorted(map.to_sorted_vec().into_iter(), keys.clone());
+        assert_into_iter_sorted(indices.to_sorted_vec().into_iter(), keys.clone());
+        assert_eq!(len, dary.len());
+        assert_eq!(Ok(()), map.validate());
+        assert_eq!(Ok(()), indices.validate());
+
+        assert_into_iter_sorted(dary.into_iter_sorted(), keys.clone());
+        assert_into_iter_sorted(map.into_iter_sorted(), keys.clone());
+        assert_into_iter_sorted(indices.into_iter_sorted(), keys.clone());
+    }
+}
+
+#[test]
+fn into_iter_sorted() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+fn test_k_smallest_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+
+    for len in [0, 1, 2, 7, 100] {
+        let keys: Vec<f64> = (0..len).map(|_| rng.gen()).collect();
+        let mut sorted = keys.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let mut dary = DaryHeap::<usize, f64, D>::default();
+        let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+        let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+        for (node, key) in keys.iter().enumerate() {
+            dary.push(node, *key);
+            map.push(node, *key);
+            indices.push(node, *key);
+        }
+
+        for k in [0, 1, 3, len / 2, len, len + 5] {
+            let expected = &sorted[..k.min(len)];
+            let keys = |x: Vec<(usize, f64)>| x.into_iter().map(|x| x.1).collect_vec();
+            assert_eq!(expected, keys(dary.clone().k_smallest(k)));
+            assert_eq!(expected, keys(map.clone().k_smallest(k)));
+            assert_eq!(expected, keys(indices.clone().k_smallest(k)));
+        }
+    }
+}
+
+#[test]
+fn k_smallest() {
+    test_k_smallest_for::<2>();
+    test_k_smallest_for::<3>();
+    test_k_smallest_for::<4>();
+    test_k_smallest_for::<8>();
+    test_k_smallest_for::<13>();
+}
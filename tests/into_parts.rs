@@ -0,0 +1,144 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn assert_sorted<P: PriorityQueue<usize, u32>>(mut pq: P) {
+    let mut prev = None;
+    while let Some((_, key)) = pq.pop() {
+        if let Some(prev) = prev {
+            assert!(prev <= key);
+        }
+        prev = Some(key);
+    }
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let elements: Vec<_> = (0..N).map(|i| (i, rng.gen_range(0..1000))).collect();
+
+    // dary
+    let pq = DaryHeap::<usize, u32, D>::from(elements.clone());
+    let slice = pq.as_slice().to_vec();
+    let (tree, positions) = pq.into_parts();
+    assert_eq!(heap_offset::<D>() + N, tree.len());
+    assert_eq!(slice.as_slice(), &tree[heap_offset::<D>()..]);
+    let pq = unsafe { DaryHeap::<usize, u32, D>::from_parts(tree, positions) };
+    assert_eq!(Ok(()), pq.validate());
+    assert_eq!(slice.as_slice(), pq.as_slice());
+    assert_sorted(pq);
+
+    // map
+    let pq = DaryHeapWithMap::<usize, u32, D>::from(elements.clone());
+    let slice = pq.as_slice().to_vec();
+    let (tree, positions) = pq.into_parts();
+    assert_eq!(heap_offset::<D>() + N, tree.len());
+    assert_eq!(slice.as_slice(), &tree[heap_offset::<D>()..]);
+    let pq = unsafe { DaryHeapWithMap::<usize, u32, D>::from_parts(tree, positions) };
+    assert_eq!(Ok(()), pq.validate());
+    for (node, key) in &elements {
+        assert_eq!(Some(key), pq.key_ref(node));
+    }
+    assert_sorted(pq);
+
+    // indices
+    let pq = DaryHeapOfIndices::<usize, u32, D>::from(elements.clone());
+    let slice = pq.as_slice().to_vec();
+    let (tree, positions) = pq.into_parts();
+    assert_eq!(heap_offset::<D>() + N, tree.len());
+    assert_eq!(slice.as_slice(), &tree[heap_offset::<D>()..]);
+    let pq = unsafe { DaryHeapOfIndices::<usize, u32, D>::from_parts(tree, positions) };
+    assert_eq!(Ok(()), pq.validate());
+    assert_eq!(N, pq.index_bound());
+    for (node, key) in &elements {
+        assert_eq!(Some(key), pq.key_ref(node));
+    }
+    assert_sorted(pq);
+}
+
+fn test_empty_for<const D: usize>() {
+    // never pushed
+    let (tree, positions) = DaryHeapWithMap::<usize, u32, D>::default().into_parts();
+    assert!(tree.is_empty());
+    let mut pq = unsafe { DaryHeapWithMap::<usize, u32, D>::from_parts(tree, positions) };
+    assert!(pq.is_empty());
+    pq.push(3, 3);
+    assert_eq!(Some((3, 3)), pq.pop());
+
+    // cleared
+    let mut pq = DaryHeapOfIndices::<usize, u32, D>::with_index_bound(N);
+    pq.push(7, 7);
+    pq.clear();
+    let (tree, positions) = pq.into_parts();
+    assert_eq!(heap_offset::<D>(), tree.len());
+    let mut pq = unsafe { DaryHeapOfIndices::<usize, u32, D>::from_parts(tree, positions) };
+    assert!(pq.is_empty());
+    pq.push(42, 42);
+    pq.push(4, 4);
+    assert_eq!(Ok(()), pq.validate());
+    assert_eq!(Some((4, 4)), pq.pop());
+
+    // externally allocated tree
+    let tree = Vec::with_capacity(N);
+    let ptr = tree.as_ptr();
+    let mut pq = unsafe { DaryHeap::<usize, u32, D>::from_parts(tree, HeapPositionsNone) };
+    for i in 0..(N - heap_offset::<D>()) {
+        pq.push(i, i as u32);
+    }
+    let (tree, _) = pq.into_parts();
+    assert_eq!(ptr, tree.as_ptr());
+}
+
+#[test]
+fn into_parts() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn into_parts_empty() {
+    test_empty_for::<2>();
+    test_empty_for::<3>();
+    test_empty_for::<4>();
+    test_empty_for::<8>();
+    test_empty_for::<13>();
+}
+
+fn expected_offset<const D: usize>() -> usize {
+    match D.is_power_of_two() && D <= 64 {
+        true => D - 1,
+        false => 0,
+    }
+}
+
+fn test_heap_offset_for<const D: usize>() {
+    assert_eq!(expected_offset::<D>(), heap_offset::<D>());
+
+    // an externally constructed tree with the offset padding is a valid heap
+    let mut elements: Vec<_> = (0..N).map(|i| (i, i as u32)).collect();
+    let mut tree = vec![(N, 0); heap_offset::<D>()];
+    tree.append(&mut elements);
+    let pq = unsafe { DaryHeap::<usize, u32, D>::from_parts(tree, HeapPositionsNone) };
+    assert_eq!(Ok(()), pq.validate());
+    assert_eq!(N, pq.len());
+    assert_sorted(pq);
+}
+
+#[test]
+fn heap_offset_layout() {
+    const OFFSET: usize = heap_offset::<8>();
+    assert_eq!(7, OFFSET);
+
+    test_heap_offset_for::<2>();
+    test_heap_offset_for::<3>();
+    test_heap_offset_for::<4>();
+    test_heap_offset_for::<5>();
+    test_heap_offset_for::<8>();
+    test_heap_offset_for::<13>();
+    test_heap_offset_for::<16>();
+    test_heap_offset_for::<64>();
+    test_heap_offset_for::<128>();
+}
@@ -0,0 +1,67 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+const LEN: usize = 100;
+
+type IntoSortedVec<P> = fn(P) -> Vec<(usize, u32)>;
+
+fn test_into_sorted_vec<P>(
+    mut pq: P,
+    into_sorted_vec: IntoSortedVec<P>,
+    into_sorted_vec_by: IntoSortedVec<P>,
+) where
+    P: PriorityQueue<usize, u32> + Clone,
+{
+    let mut rng = ChaCha8Rng::seed_from_u64(3287);
+    for node in 0..LEN {
+        pq.push(node, rng.gen_range(0..10));
+    }
+
+    let mut expected: Vec<_> = pq.iter().map(|x| (*x.node(), *x.key())).collect();
+    expected.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+    let sorted = into_sorted_vec(pq.clone());
+    let keys: Vec<_> = sorted.iter().map(|x| x.1).collect();
+    let expected_keys: Vec<_> = expected.iter().map(|x| x.1).collect();
+    assert_eq!(expected_keys, keys);
+
+    assert_eq!(expected, into_sorted_vec_by(pq));
+}
+
+fn test_for<const D: usize>() {
+    test_into_sorted_vec(
+        DaryHeap::<usize, u32, D>::default(),
+        DaryHeap::into_sorted_vec,
+        |pq| pq.into_sorted_vec_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0))),
+    );
+    test_into_sorted_vec(
+        DaryHeapWithMap::<usize, u32, D>::default(),
+        DaryHeapWithMap::into_sorted_vec,
+        |pq| pq.into_sorted_vec_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0))),
+    );
+    test_into_sorted_vec(
+        DaryHeapOfIndices::<usize, u32, D>::with_index_bound(LEN),
+        DaryHeapOfIndices::into_sorted_vec,
+        |pq| pq.into_sorted_vec_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0))),
+    );
+}
+
+#[test]
+fn into_sorted_vec() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn into_sorted_vec_by_reverse_order() {
+    let queue = QuaternaryHeap::from([('a', 3), ('b', 1), ('c', 2)]);
+    let sorted = queue.into_sorted_vec_by(|a, b| b.1.cmp(&a.1));
+    assert_eq!(vec![('a', 3), ('c', 2), ('b', 1)], sorted);
+
+    let queue = QuaternaryHeap::<char, i32>::default();
+    assert!(queue.into_sorted_vec_by(|a, b| a.1.cmp(&b.1)).is_empty());
+}
@@ -0,0 +1,96 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn pop_all<P: PriorityQueue<usize, f64>>(mut pq: P) -> Vec<f64> {
+    let mut keys = vec![];
+    while let Some(key) = pq.pop_key() {
+        keys.push(key);
+    }
+    keys
+}
+
+fn sorted(keys: &[f64]) -> Vec<f64> {
+    keys.iter()
+        .copied()
+        .sorted_by(|a, b| a.partial_cmp(b).expect("no nan"))
+        .collect()
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let len = 100;
+    let mut keys: Vec<f64> = (0..len).map(|_| rng.gen()).collect_vec();
+
+    let mut dary = DaryHeap::<usize, f64, D>::default();
+    let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+    let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+    for (node, key) in keys.iter().enumerate() {
+        dary.push(node, *key);
+        map.push(node, *key);
+        indices.push(node, *key);
+    }
+
+    // uniform shift
+    for key in keys.iter_mut() {
+        *key += 10.0;
+    }
+    for (_, key) in &mut dary.iter_mut() {
+        *key += 10.0;
+    }
+    for (_, key) in &mut map.iter_mut() {
+        *key += 10.0;
+    }
+    for (_, key) in &mut indices.iter_mut() {
+        *key += 10.0;
+    }
+    assert_eq!(Ok(()), dary.validate());
+    assert_eq!(Ok(()), map.validate());
+    assert_eq!(Ok(()), indices.validate());
+
+    // arbitrary mutation depending on the node
+    let deltas: Vec<f64> = (0..len).map(|_| rng.gen::<f64>() - 0.5).collect();
+    for (node, key) in keys.iter_mut().enumerate() {
+        *key += deltas[node];
+    }
+    for (node, key) in &mut dary.iter_mut() {
+        *key += deltas[*node];
+    }
+    for (node, key) in &mut map.iter_mut() {
+        *key += deltas[*node];
+    }
+    for (node, key) in &mut indices.iter_mut() {
+        *key += deltas[*node];
+    }
+    assert_eq!(Ok(()), dary.validate());
+    assert_eq!(Ok(()), map.validate());
+    assert_eq!(Ok(()), indices.validate());
+
+    for (node, key) in keys.iter().enumerate() {
+        assert_eq!(Some(*key), map.key_of(&node));
+        assert_eq!(Some(*key), indices.key_of(&node));
+    }
+
+    // guard without iteration leaves the heap unchanged
+    let peeked = *map.peek().expect("not empty");
+    drop(map.iter_mut());
+    assert_eq!(Some(&peeked), map.peek());
+
+    let expected = sorted(&keys);
+    assert_eq!(expected, pop_all(dary));
+    assert_eq!(expected, pop_all(map));
+    assert_eq!(expected, pop_all(indices));
+
+    let mut empty = DaryHeap::<usize, f64, D>::default();
+    assert_eq!(0, (&mut empty.iter_mut()).into_iter().count());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn iter_mut() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
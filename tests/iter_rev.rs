@@ -0,0 +1,35 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn test_inherent_iter_rev<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut dary = DaryHeap::<usize, u64, D>::default();
+    let mut map = DaryHeapWithMap::<usize, u64, D>::default();
+    let mut indices = DaryHeapOfIndices::<usize, u64, D>::with_index_bound(50);
+    for node in 0..50 {
+        let key = rng.gen_range(0..100);
+        dary.push(node, key);
+        map.push(node, key);
+        indices.push(node, key);
+    }
+
+    let expected: Vec<_> = dary.as_slice().iter().rev().collect();
+    assert_eq!(expected, dary.iter_rev().collect::<Vec<_>>());
+    assert!(dary.iter_rev().rev().eq(dary.iter()));
+
+    let expected: Vec<_> = map.as_slice().iter().rev().collect();
+    assert_eq!(expected, map.iter_rev().collect::<Vec<_>>());
+    assert!(map.iter_rev().rev().eq(map.iter()));
+
+    let expected: Vec<_> = indices.as_slice().iter().rev().collect();
+    assert_eq!(expected, indices.iter_rev().collect::<Vec<_>>());
+    assert!(indices.iter_rev().rev().eq(indices.iter()));
+}
+
+#[test]
+fn iter_rev_inherent() {
+    test_inherent_iter_rev::<2>();
+    test_inherent_iter_rev::<3>();
+    test_inherent_iter_rev::<4>();
+    test_inherent_iter_rev::<8>();
+}
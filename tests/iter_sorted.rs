@@ -0,0 +1,53 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn assert_iter_sorted<'a, I>(iter: I, mut expected_keys: Vec<f64>)
+where
+    I: Iterator<Item = &'a (usize, f64)> + ExactSizeIterator,
+{
+    expected_keys.sort_by(|a, b| a.partial_cmp(b).expect("no nan"));
+    assert_eq!(expected_keys.len(), iter.len());
+    let keys = iter.map(|x| x.1).collect_vec();
+    assert_eq!(expected_keys, keys);
+}
+
+fn random_keys(len: usize) -> Vec<f64> {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+fn test_for<const D: usize>() {
+    for len in [0, 1, 2, 7, 100] {
+        let keys = random_keys(len);
+
+        let mut dary = DaryHeap::<usize, f64, D>::default();
+        let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+        let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+        for (node, key) in keys.iter().enumerate() {
+            dary.push(node, *key);
+            map.push(node, *key);
+            indices.push(node, *key);
+        }
+
+        assert_iter_sorted(dary.iter_sorted(), keys.clone());
+        assert_iter_sorted(map.iter_sorted(), keys.clone());
+        assert_iter_sorted(indices.iter_sorted(), keys.clone());
+
+        // the queue is not mutated and pops in the same order
+        assert_eq!(len, dary.len());
+        let sorted = dary.iter_sorted().copied().collect_vec();
+        for x in sorted {
+            assert_eq!(Some(x.1), dary.pop_key());
+        }
+    }
+}
+
+#[test]
+fn iter_sorted() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
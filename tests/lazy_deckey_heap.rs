@@ -0,0 +1,121 @@
+mod priority_queue_deckey_tests;
+mod priority_queue_tests;
+
+use orx_priority_queue::{LazyDecKeyHeap, PriorityQueue, PriorityQueueDecKey};
+use priority_queue_deckey_tests::*;
+use priority_queue_tests::*;
+use rand::prelude::*;
+
+#[test]
+fn test_lazy_forall() {
+    test_lazy_for::<2>();
+    test_lazy_for::<3>();
+    test_lazy_for::<4>();
+    test_lazy_for::<8>();
+    test_lazy_for::<13>();
+}
+
+fn test_lazy_for<const D: usize>() {
+    let new_heap = LazyDecKeyHeap::<usize, f64, D>::default;
+
+    let change_key = [
+        ChangeKeyMethod::Decrease,
+        ChangeKeyMethod::Update,
+        ChangeKeyMethod::TryDecrease,
+    ];
+
+    test_len(new_heap());
+    test_is_empty(new_heap());
+    test_peek(new_heap());
+    test_or_default(new_heap());
+    test_clear(new_heap());
+    test_push_pop(new_heap());
+    test_push_pop_randomized(new_heap());
+    test_push_then_pop(new_heap());
+    test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
+
+    test_contains(new_heap());
+    test_key_of(new_heap());
+    test_entries(new_heap());
+    change_key
+        .iter()
+        .for_each(|change_key_method| test_change_key(new_heap(), *change_key_method));
+    test_decrease_key_all(new_heap());
+    test_increase_key(new_heap());
+    test_remove(new_heap());
+    test_mixed(new_heap());
+    test_decrease_key_ref(LazyDecKeyHeap::<_, _, D>::default());
+    test_decrease_key_by(new_heap());
+    test_update_key_if(new_heap());
+    test_push_if_lower(new_heap());
+    test_take(new_heap());
+
+    change_key
+        .iter()
+        .for_each(|change_key_method| test_change_key_or_push(new_heap(), *change_key_method));
+
+    test_stale_entries_bounded::<D>();
+    test_iter_skips_stale_entries::<D>();
+}
+
+fn test_stale_entries_bounded<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut pq = LazyDecKeyHeap::<usize, usize, D>::default();
+
+    let n = 100;
+    for node in 0..n {
+        pq.push(node, rng.gen_range(0..1000));
+    }
+
+    for _ in 0..10_000 {
+        let node = rng.gen_range(0..n);
+        let key = rng.gen_range(0..1000);
+        pq.update_key(&node, key);
+        assert_eq!(n, pq.len());
+        assert!(pq.heap_len() <= 2 * n);
+        assert_eq!(pq.iter().map(|x| *x.1).min().as_ref(), pq.peek_key());
+    }
+
+    let mut prev_key = 0;
+    let mut num_popped = 0;
+    while let Some((node, key)) = pq.pop() {
+        assert!(prev_key <= key);
+        assert!(!pq.contains(&node));
+        prev_key = key;
+        num_popped += 1;
+    }
+    assert_eq!(n, num_popped);
+    assert_eq!(0, pq.heap_len());
+}
+
+fn test_iter_skips_stale_entries<const D: usize>() {
+    let mut pq = LazyDecKeyHeap::<char, u32, D>::default();
+    pq.push('a', 5);
+    pq.push('b', 3);
+    pq.push('c', 9);
+
+    // stale entries of 'a' with the same key as its current key
+    pq.update_key(&'a', 7);
+    pq.update_key(&'a', 5);
+    pq.decrease_key(&'b', 1);
+    assert_eq!(6, pq.heap_len());
+
+    let mut entries: Vec<_> = pq.iter().collect();
+    entries.sort();
+    assert_eq!(vec![(&'a', &5), (&'b', &1), (&'c', &9)], entries);
+
+    let mut entries: Vec<_> = pq.iter().rev().collect();
+    entries.sort();
+    assert_eq!(vec![(&'a', &5), (&'b', &1), (&'c', &9)], entries);
+
+    assert_eq!(Some(('b', 1)), pq.pop());
+    assert_eq!(Some(('a', 5)), pq.pop());
+    assert_eq!(vec![(&'c', &9)], pq.iter().collect::<Vec<_>>());
+    assert_eq!(Some(('c', 9)), pq.pop());
+    assert_eq!(None, pq.pop());
+    assert_eq!(0, pq.iter().count());
+}
@@ -0,0 +1,83 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn pop_all<P: PriorityQueue<usize, i64>>(mut pq: P) -> Vec<(usize, i64)> {
+    let mut popped = vec![];
+    while let Some(x) = pq.pop() {
+        popped.push(x);
+    }
+    popped
+}
+
+fn expected_after<F: Fn(i64) -> i64>(keys: &[i64], f: F) -> Vec<(usize, i64)> {
+    keys.iter()
+        .enumerate()
+        .map(|(node, key)| (node, f(*key)))
+        .sorted_by_key(|x| x.1)
+        .collect()
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let len = 100;
+    // distinct keys to make the popping order deterministic
+    let mut keys: Vec<i64> = (0..len as i64).collect();
+    keys.shuffle(&mut rng);
+
+    let new_heaps = || {
+        let mut dary = DaryHeap::<usize, i64, D>::default();
+        let mut map = DaryHeapWithMap::<usize, i64, D>::default();
+        let mut indices = DaryHeapOfIndices::<usize, i64, D>::with_index_bound(len);
+        for (node, key) in keys.iter().enumerate() {
+            dary.push(node, *key);
+            map.push(node, *key);
+            indices.push(node, *key);
+        }
+        (dary, map, indices)
+    };
+
+    // non-monotonic, still distinct since 37 and 101 are coprime
+    let f = |key: i64| (key * 37) % 101;
+    let expected = expected_after(&keys, f);
+    let (dary, map, indices) = new_heaps();
+    let mapped = [dary.map_keys(f), map.map_keys(f), indices.map_keys(f)];
+    for heap in mapped {
+        assert_eq!(Ok(()), heap.validate());
+        assert_eq!(len, heap.len());
+        assert_eq!(expected, pop_all(heap));
+    }
+
+    // monotonic
+    let f = |key: i64| key * 1000 - 7;
+    let expected = expected_after(&keys, f);
+    let (dary, map, indices) = new_heaps();
+    let mapped = [
+        dary.map_keys_monotonic(f),
+        map.map_keys_monotonic(f),
+        indices.map_keys_monotonic(f),
+    ];
+    for heap in mapped {
+        assert_eq!(Ok(()), heap.validate());
+        assert_eq!(expected, pop_all(heap));
+    }
+
+    // key type conversion
+    let (dary, _, _) = new_heaps();
+    let mut mapped = dary.map_keys_monotonic(|key| key as f64 / 2.0);
+    assert_eq!(Some(0.0), mapped.pop_key());
+    assert_eq!(Some(0.5), mapped.pop_key());
+
+    let empty = DaryHeap::<usize, i64, D>::default().map_keys(f);
+    assert!(empty.is_empty());
+    assert_eq!(Ok(()), empty.validate());
+}
+
+#[test]
+fn map_keys() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,159 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+fn random_ops<const D: usize>(max_key: u32) {
+    let mut rng = ChaCha8Rng::seed_from_u64(7531);
+    let mut heap = DaryMinMaxHeap::<usize, u32, D>::default();
+    let mut model: Vec<(usize, u32)> = vec![];
+
+    for node in 0..2000 {
+        match rng.gen_range(0..5) {
+            0 => {
+                let expected = model.iter().map(|x| x.1).min();
+                let popped = heap.pop_min();
+                assert_eq!(expected, popped.map(|x| x.1));
+                if let Some(popped) = popped {
+                    let i = model
+                        .iter()
+                        .position(|x| *x == popped)
+                        .expect("popped exists");
+                    model.swap_remove(i);
+                }
+            }
+            1 => {
+                let expected = model.iter().map(|x| x.1).max();
+                let popped = heap.pop_max();
+                assert_eq!(expected, popped.map(|x| x.1));
+                if let Some(popped) = popped {
+                    let i = model
+                        .iter()
+                        .position(|x| *x == popped)
+                        .expect("popped exists");
+                    model.swap_remove(i);
+                }
+            }
+            2 => {
+                let key = rng.gen_range(0..max_key);
+                let expected = match model.iter().map(|x| x.1).min() {
+                    Some(min) if min < key => min,
+                    _ => key,
+                };
+                let popped = heap.push_then_pop(node, key);
+                assert_eq!(expected, popped.1);
+                if popped.0 != node {
+                    let i = model
+                        .iter()
+                        .position(|x| *x == popped)
+                        .expect("popped exists");
+                    model[i] = (node, key);
+                }
+            }
+            _ => {
+                let key = rng.gen_range(0..max_key);
+                heap.push(node, key);
+                model.push((node, key));
+            }
+        }
+
+        assert_eq!(Ok(()), heap.validate());
+        assert_eq!(model.len(), heap.len());
+        assert_eq!(
+            model.iter().map(|x| x.1).min(),
+            heap.peek_min().map(|x| x.1)
+        );
+        assert_eq!(
+            model.iter().map(|x| x.1).max(),
+            heap.peek_max().map(|x| x.1)
+        );
+    }
+}
+
+fn drain_both_ends<const D: usize>(len: usize, max_key: u32) {
+    let mut rng = ChaCha8Rng::seed_from_u64(len as u64);
+    let keys: Vec<u32> = (0..len).map(|_| rng.gen_range(0..max_key)).collect();
+
+    let mut ascending = keys.clone();
+    ascending.sort();
+    let descending: Vec<_> = ascending.iter().rev().copied().collect();
+
+    let mut heap = DaryMinMaxHeap::<usize, u32, D>::with_capacity(len);
+    for (node, key) in keys.iter().copied().enumerate() {
+        heap.push(node, key);
+    }
+    let mut clone = heap.clone();
+
+    let popped: Vec<_> = core::iter::from_fn(|| heap.pop_min()).collect();
+    assert_eq!(ascending, popped.iter().map(|x| x.1).collect::<Vec<_>>());
+    assert!(popped.iter().all(|(node, key)| keys[*node] == *key));
+
+    let popped: Vec<_> = core::iter::from_fn(|| clone.pop_max()).collect();
+    assert_eq!(descending, popped.iter().map(|x| x.1).collect::<Vec<_>>());
+    assert!(popped.iter().all(|(node, key)| keys[*node] == *key));
+
+    // alternating ends
+    let mut heap = DaryMinMaxHeap::<usize, u32, D>::default();
+    for (node, key) in keys.iter().copied().enumerate() {
+        heap.push(node, key);
+    }
+    let (mut lo, mut hi) = (0, len);
+    while !heap.is_empty() {
+        match (hi - lo).is_multiple_of(2) {
+            true => {
+                assert_eq!(Some(ascending[lo]), heap.pop_min().map(|x| x.1));
+                lo += 1;
+            }
+            false => {
+                hi -= 1;
+                assert_eq!(Some(ascending[hi]), heap.pop_max().map(|x| x.1));
+            }
+        }
+        assert_eq!(Ok(()), heap.validate());
+    }
+    assert_eq!(lo, hi);
+}
+
+fn test_for<const D: usize>() {
+    random_ops::<D>(10);
+    random_ops::<D>(1_000_000);
+
+    for len in [0, 1, 2, D, D + 1, D * D + 1, 1000] {
+        drain_both_ends::<D>(len, 10);
+        drain_both_ends::<D>(len, 1_000_000);
+    }
+}
+
+#[test]
+fn minmax_heap() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn minmax_heap_as_priority_queue() {
+    fn pop_all<P: PriorityQueue<char, i32>>(mut pq: P) -> Vec<(char, i32)> {
+        for (node, key) in [('a', 3), ('b', 1), ('c', 2)] {
+            pq.push(node, key);
+        }
+        assert_eq!(Some(4), pq.arity());
+        core::iter::from_fn(|| pq.pop()).collect()
+    }
+
+    let popped = pop_all(DaryMinMaxHeap::<_, _, 4>::default());
+    assert_eq!(vec![('b', 1), ('c', 2), ('a', 3)], popped);
+}
+
+#[test]
+fn minmax_heap_error_display() {
+    let error = HeapError::MinMaxOrder {
+        ancestor: 0,
+        descendant: 5,
+    };
+    assert_eq!(
+        "key of the element at position 5 violates the min-max order with its ancestor at position 0",
+        error.to_string()
+    );
+}
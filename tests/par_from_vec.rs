@@ -0,0 +1,37 @@
+#![cfg(feature = "rayon")]
+
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+fn test_for<const D: usize>() {
+    let mut rng = ChaCha8Rng::seed_from_u64(6548);
+
+    for len in [0, 1, 2, D, D + 1, 1000, 20_000, 100_003] {
+        for max_key in [4, 1_000_000] {
+            let elements: Vec<(usize, u32)> = (0..len)
+                .map(|node| (node, rng.gen_range(0..max_key)))
+                .collect();
+
+            let sequential = DaryHeap::<_, _, D>::from(elements.clone());
+            let parallel = DaryHeap::<_, _, D>::par_from_vec(elements.clone());
+
+            assert_eq!(Ok(()), parallel.validate());
+            assert_eq!(sequential.as_slice(), parallel.as_slice());
+
+            let mut expected: Vec<_> = elements.iter().map(|x| x.1).collect();
+            expected.sort();
+            let popped: Vec<_> = parallel.into_iter_sorted().map(|x| x.1).collect();
+            assert_eq!(expected, popped);
+        }
+    }
+}
+
+#[test]
+fn par_from_vec_matches_from_vec() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,65 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn sorted_keys(keys: &[f64]) -> Vec<f64> {
+    keys.iter()
+        .copied()
+        .sorted_by(|a, b| a.total_cmp(b))
+        .collect()
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+
+    for len in [0, 1, 2, 7, 100] {
+        let keys: Vec<f64> = (0..len).map(|_| rng.gen()).collect();
+        let sorted = sorted_keys(&keys);
+
+        let mut dary = DaryHeap::<usize, f64, D>::default();
+        let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+        let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+        for (node, key) in keys.iter().enumerate() {
+            dary.push(node, *key);
+            map.push(node, *key);
+            indices.push(node, *key);
+        }
+
+        for k in [0, 1, 3, len / 2, len, len + 5] {
+            let expected = &sorted[..k.min(len)];
+            assert_eq!(expected, dary.peek_k(k).map(|x| x.1).collect_vec());
+            assert_eq!(expected, map.peek_k(k).map(|x| x.1).collect_vec());
+            assert_eq!(expected, indices.peek_k(k).map(|x| x.1).collect_vec());
+        }
+
+        for n in 0..(len + 2) {
+            let expected = sorted.get(n).copied();
+            assert_eq!(expected, dary.peek_nth(n).map(|x| x.1));
+            assert_eq!(expected, map.peek_nth(n).map(|x| x.1));
+            assert_eq!(expected, indices.peek_nth(n).map(|x| x.1));
+        }
+        assert_eq!(dary.peek(), dary.peek_nth(0));
+
+        let second = sorted.get(1).copied();
+        assert_eq!(second, dary.peek_second().map(|x| x.1));
+        assert_eq!(second, map.peek_second().map(|x| x.1));
+        assert_eq!(second, indices.peek_second().map(|x| x.1));
+
+        // queues are not mutated
+        assert_eq!(len, dary.len());
+        assert_eq!(len, map.len());
+        assert_eq!(len, indices.len());
+        assert_eq!(Ok(()), map.validate());
+        assert_eq!(Ok(()), indices.validate());
+    }
+}
+
+#[test]
+fn peek_k() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+    test_for::<16>();
+}
@@ -0,0 +1,73 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn pop_all<P: PriorityQueue<usize, f64>>(mut pq: P) -> Vec<f64> {
+    let mut keys = vec![];
+    while let Some(key) = pq.pop_key() {
+        keys.push(key);
+    }
+    keys
+}
+
+fn sorted(keys: &[f64]) -> Vec<f64> {
+    keys.iter()
+        .copied()
+        .sorted_by(|a, b| a.partial_cmp(b).expect("no nan"))
+        .collect()
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let len = 100;
+    let mut keys: Vec<f64> = (0..len).map(|_| rng.gen()).collect_vec();
+
+    let mut dary = DaryHeap::<usize, f64, D>::default();
+    let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+    let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+    for (node, key) in keys.iter().enumerate() {
+        dary.push(node, *key);
+        map.push(node, *key);
+        indices.push(node, *key);
+    }
+
+    for _ in 0..200 {
+        let delta: f64 = rng.gen();
+
+        let (node, _) = *map.peek().expect("not empty");
+        keys[node] += delta;
+
+        *dary.peek_mut().expect("not empty").key_mut() += delta;
+        *map.peek_mut().expect("not empty").key_mut() += delta;
+        *indices.peek_mut().expect("not empty").key_mut() += delta;
+
+        assert_eq!(Some(keys[node]), map.key_of(&node));
+        assert_eq!(Some(keys[node]), indices.key_of(&node));
+    }
+
+    // guard without mutation leaves the heap unchanged
+    let peeked = *map.peek().expect("not empty");
+    assert_eq!(&peeked, &*map.peek_mut().expect("not empty"));
+    assert_eq!(Some(&peeked), map.peek());
+
+    for (node, key) in keys.iter().enumerate() {
+        assert_eq!(Some(*key), map.key_of(&node));
+        assert_eq!(Some(*key), indices.key_of(&node));
+    }
+
+    let expected = sorted(&keys);
+    assert_eq!(expected, pop_all(dary));
+    assert_eq!(expected, pop_all(map));
+    assert_eq!(expected, pop_all(indices));
+
+    assert!(DaryHeap::<usize, f64, D>::default().peek_mut().is_none());
+}
+
+#[test]
+fn peek_mut() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
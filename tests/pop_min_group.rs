@@ -0,0 +1,81 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+const LEN: usize = 200;
+
+type PopMinGroup<P> = fn(&mut P) -> Vec<(usize, u32)>;
+
+fn test_pop_min_group<P>(mut pq: P, pop_min_group: PopMinGroup<P>)
+where
+    P: PriorityQueue<usize, u32>,
+{
+    let mut rng = ChaCha8Rng::seed_from_u64(4123);
+    let mut keys = vec![];
+    for node in 0..LEN {
+        let key = rng.gen_range(0..20);
+        pq.push(node, key);
+        keys.push(key);
+    }
+
+    let mut distinct = keys.clone();
+    distinct.sort();
+    distinct.dedup();
+
+    for min in distinct {
+        let group = pop_min_group(&mut pq);
+
+        let mut nodes: Vec<_> = group.iter().map(|x| x.0).collect();
+        nodes.sort();
+        let expected: Vec<_> = (0..LEN).filter(|node| keys[*node] == min).collect();
+        assert_eq!(expected, nodes);
+        assert!(group.iter().all(|x| x.1 == min));
+
+        assert!(pq.peek_key().is_none_or(|key| *key > min));
+    }
+
+    assert!(pq.is_empty());
+    assert!(pop_min_group(&mut pq).is_empty());
+}
+
+fn test_for<const D: usize>() {
+    test_pop_min_group(
+        DaryHeap::<usize, u32, D>::default(),
+        DaryHeap::pop_min_group,
+    );
+    test_pop_min_group(
+        DaryHeapWithMap::<usize, u32, D>::default(),
+        DaryHeapWithMap::pop_min_group,
+    );
+    test_pop_min_group(
+        DaryHeapOfIndices::<usize, u32, D>::with_index_bound(LEN),
+        DaryHeapOfIndices::pop_min_group,
+    );
+}
+
+#[test]
+fn pop_min_group() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn pop_min_group_float_keys_exact_equality() {
+    let mut queue = QuaternaryHeap::default();
+    queue.push('a', 0.3);
+    queue.push('b', 0.1 + 0.2);
+    queue.push('c', -0.0);
+    queue.push('d', 0.0);
+
+    let mut group = queue.pop_min_group();
+    group.sort_by_key(|x| x.0);
+    assert_eq!(vec![('c', -0.0), ('d', 0.0)], group);
+
+    // 0.1 + 0.2 != 0.3
+    assert_eq!(vec![('a', 0.3)], queue.pop_min_group());
+    assert_eq!(1, queue.pop_min_group().len());
+    assert!(queue.is_empty());
+}
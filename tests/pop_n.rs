@@ -0,0 +1,59 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const LEN: usize = 100;
+
+type PopN<P> = fn(&mut P, usize) -> Vec<(usize, f64)>;
+
+fn test_pop_n<P>(mut pq: P, pop_n: PopN<P>, validate: fn(&P) -> Result<(), HeapError>)
+where
+    P: PriorityQueue<usize, f64> + Clone,
+{
+    let mut rng = rand::thread_rng();
+    for node in 0..LEN {
+        pq.push(node, rng.gen());
+    }
+
+    let mut remaining = LEN;
+    for n in [0, 1, 7, 16, 30, 100] {
+        let mut expected_pq = pq.clone();
+        let expected: Vec<_> = core::iter::from_fn(|| expected_pq.pop()).take(n).collect();
+
+        let batch = pop_n(&mut pq, n);
+        assert_eq!(expected, batch);
+        assert_eq!(n.min(remaining), batch.len());
+        remaining -= batch.len();
+        assert_eq!(remaining, pq.len());
+        assert_eq!(Ok(()), validate(&pq));
+    }
+
+    assert!(pq.is_empty());
+    assert!(pop_n(&mut pq, 4).is_empty());
+}
+
+fn test_for<const D: usize>() {
+    test_pop_n(
+        DaryHeap::<usize, f64, D>::default(),
+        DaryHeap::pop_n,
+        DaryHeap::validate,
+    );
+    test_pop_n(
+        DaryHeapWithMap::<usize, f64, D>::default(),
+        DaryHeapWithMap::pop_n,
+        DaryHeapWithMap::validate,
+    );
+    test_pop_n(
+        DaryHeapOfIndices::<usize, f64, D>::with_index_bound(LEN),
+        DaryHeapOfIndices::pop_n,
+        DaryHeapOfIndices::validate,
+    );
+}
+
+#[test]
+fn pop_n() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
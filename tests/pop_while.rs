@@ -0,0 +1,76 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+const LEN: usize = 200;
+
+type PopWhile<P> = fn(&mut P, fn(&usize, &u32) -> bool) -> Vec<(usize, u32)>;
+
+fn test_pop_while<P>(mut pq: P, pop_while: PopWhile<P>)
+where
+    P: PriorityQueue<usize, u32>,
+{
+    let mut rng = ChaCha8Rng::seed_from_u64(9871);
+    let mut keys = vec![];
+    for node in 0..LEN {
+        let key = rng.gen_range(0..100);
+        pq.push(node, key);
+        keys.push(key);
+    }
+
+    let popped = pop_while(&mut pq, |_, key| *key < 40);
+    assert!(popped.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert!(popped.iter().all(|x| x.1 < 40));
+    assert_eq!(keys.iter().filter(|key| **key < 40).count(), popped.len());
+    assert!(pq.peek_key().is_some_and(|key| *key >= 40));
+
+    // stops at the first failing element although later ones satisfy the predicate
+    let odd = pop_while(&mut pq, |_, key| *key % 2 == 1);
+    assert!(odd.iter().all(|x| x.1 % 2 == 1));
+    assert!(pq.peek_key().is_none_or(|key| *key % 2 == 0));
+
+    let none = pop_while(&mut pq, |_, _| false);
+    assert!(none.is_empty());
+
+    let remaining = pq.len();
+    let rest = pop_while(&mut pq, |_, _| true);
+    assert_eq!(remaining, rest.len());
+    assert_eq!(LEN, popped.len() + odd.len() + rest.len());
+    assert!(pq.is_empty());
+    assert!(pop_while(&mut pq, |_, _| true).is_empty());
+}
+
+fn test_for<const D: usize>() {
+    test_pop_while(DaryHeap::<usize, u32, D>::default(), DaryHeap::pop_while);
+    test_pop_while(
+        DaryHeapWithMap::<usize, u32, D>::default(),
+        DaryHeapWithMap::pop_while,
+    );
+    test_pop_while(
+        DaryHeapOfIndices::<usize, u32, D>::with_index_bound(LEN),
+        DaryHeapOfIndices::pop_while,
+    );
+}
+
+#[test]
+fn pop_while() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn pop_while_by_node() {
+    let mut queue = BinaryHeapWithMap::default();
+    queue.push('a', 1);
+    queue.push('b', 2);
+    queue.push('x', 3);
+    queue.push('c', 4);
+
+    let popped = queue.pop_while(|node, _| *node < 'x');
+    assert_eq!(vec![('a', 1), ('b', 2)], popped);
+    assert!(queue.contains(&'x'));
+    assert_eq!(Ok(()), queue.validate());
+}
@@ -0,0 +1,64 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn test_position_of<P>(
+    pq: &mut P,
+    position_of: fn(&P, &usize) -> Option<usize>,
+    as_slice: fn(&P) -> &[(usize, f64)],
+) where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+        let node = rng.gen_range(0..N);
+        match rng.gen_range(0..3) {
+            0 => _ = pq.pop(),
+            1 => _ = pq.update_key_or_push(&node, rng.gen()),
+            _ => _ = pq.try_decrease_key_or_push(&node, rng.gen()),
+        }
+
+        for node in 0..N {
+            match position_of(pq, &node) {
+                Some(position) => {
+                    assert!(pq.contains(&node));
+                    assert_eq!(node, as_slice(pq)[position].0);
+                }
+                None => assert!(!pq.contains(&node)),
+            }
+        }
+    }
+
+    pq.clear();
+    for node in 0..N {
+        assert_eq!(None, position_of(pq, &node));
+    }
+}
+
+fn test_for<const D: usize>() {
+    test_position_of(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        DaryHeapWithMap::position_of,
+        DaryHeapWithMap::as_slice,
+    );
+    test_position_of(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        DaryHeapOfIndices::position_of,
+        DaryHeapOfIndices::as_slice,
+    );
+
+    let pq = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N);
+    assert_eq!(None, pq.position_of(&N));
+    assert_eq!(None, pq.position_of(&usize::MAX));
+}
+
+#[test]
+fn position_of() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -43,7 +43,7 @@ where
 
         match change_key {
             ChangeKeyMethod::Decrease => {
-                pq.decrease_key(&node, new_key);
+                assert_eq!(old_key, pq.decrease_key(&node, new_key));
                 vec[node] = (node, new_key);
             }
             ChangeKeyMethod::Update => {
@@ -0,0 +1,58 @@
+use itertools::Itertools;
+use orx_priority_queue::PriorityQueueDecKey;
+use rand::prelude::*;
+use std::cmp::Ordering;
+
+pub fn test_decrease_key_all<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    const LEN: usize = 100;
+
+    pq.clear();
+    assert!(pq.is_empty());
+
+    let mut rng = rand::thread_rng();
+    let mut vec = Vec::new();
+
+    // push 100
+    for node in 0..LEN {
+        let priority = rng.gen();
+        pq.push(node, priority);
+        vec.push((node, priority));
+    }
+
+    // small and large batches, possibly with repeated nodes
+    for batch_len in [0, 1, 3, 10, 50, 150] {
+        let mut updates = vec![];
+        for _ in 0..batch_len {
+            let node = rng.gen_range(0..LEN);
+            let decreased_key = vec[node].1 * rng.gen::<f64>();
+            vec[node].1 = decreased_key;
+            updates.push((node, decreased_key));
+        }
+
+        pq.decrease_key_all(updates);
+
+        for (node, key) in &vec {
+            assert_eq!(Some(*key), pq.key_of(node));
+        }
+    }
+
+    // pop in correct order
+    let vec_sorted = vec
+        .into_iter()
+        .sorted_by(|x, y| {
+            if x.1 <= y.1 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .collect_vec();
+    for vec_popped in vec_sorted {
+        let pq_popped = pq.pop();
+        assert_eq!(Some(vec_popped), pq_popped);
+    }
+    assert!(pq.is_empty());
+}
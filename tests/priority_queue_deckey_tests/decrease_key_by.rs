@@ -0,0 +1,54 @@
+use orx_priority_queue::PriorityQueueDecKey;
+use rand::prelude::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+pub fn test_decrease_key_by<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    const LEN: usize = 100;
+
+    pq.clear();
+    assert!(pq.is_empty());
+
+    let mut rng = rand::thread_rng();
+    let mut keys: Vec<f64> = (0..LEN).map(|_| rng.gen()).collect();
+    for (node, key) in keys.iter().enumerate() {
+        pq.push(node, *key);
+    }
+
+    for _ in 0..(2 * LEN) {
+        let node = rng.gen_range(0..LEN);
+        let delta = rng.gen::<f64>() * 0.1;
+        let old_key = match rng.gen_bool(0.5) {
+            true => pq.decrease_key_by(&node, delta),
+            false => pq.decrease_key_with(&node, |key| key - delta),
+        };
+        assert_eq!(keys[node], old_key);
+        keys[node] -= delta;
+        assert_eq!(Some(keys[node]), pq.key_of(&node));
+    }
+
+    // absent node and negative delta
+    pq.remove(&0);
+    let absent = catch_unwind(AssertUnwindSafe(|| pq.decrease_key_by(&0, 0.5)));
+    assert!(absent.is_err());
+    let absent = catch_unwind(AssertUnwindSafe(|| {
+        pq.decrease_key_with(&0, |key| key - 0.5)
+    }));
+    assert!(absent.is_err());
+    let greater = catch_unwind(AssertUnwindSafe(|| pq.decrease_key_by(&1, -0.5)));
+    assert!(greater.is_err());
+    assert_eq!(Some(keys[1]), pq.key_of(&1));
+
+    let mut expected: Vec<_> = keys.into_iter().enumerate().skip(1).collect();
+    expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let mut popped = vec![];
+    while let Some(x) = pq.pop() {
+        popped.push(x);
+    }
+    assert_eq!(expected.len(), popped.len());
+    for (x, y) in expected.iter().zip(&popped) {
+        assert_eq!(x.1, y.1);
+    }
+}
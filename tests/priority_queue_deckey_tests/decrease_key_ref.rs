@@ -0,0 +1,86 @@
+use orx_priority_queue::PriorityQueueDecKey;
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+/// Key counting the number of times it is cloned.
+#[derive(Debug)]
+pub struct CountedKey {
+    value: Vec<u8>,
+    num_clones: Rc<Cell<usize>>,
+}
+
+impl Clone for CountedKey {
+    fn clone(&self) -> Self {
+        self.num_clones.set(self.num_clones.get() + 1);
+        Self {
+            value: self.value.clone(),
+            num_clones: self.num_clones.clone(),
+        }
+    }
+}
+
+impl PartialEq for CountedKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for CountedKey {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+pub fn test_decrease_key_ref<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, CountedKey>,
+{
+    const LEN: usize = 50;
+
+    pq.clear();
+    assert!(pq.is_empty());
+
+    let num_clones = Rc::new(Cell::new(0));
+    let key = |value: u8| CountedKey {
+        value: vec![value, value],
+        num_clones: num_clones.clone(),
+    };
+
+    for node in 0..LEN {
+        pq.push(node, key(100 + node as u8));
+    }
+
+    for node in (0..LEN).step_by(2) {
+        let decreased_key = key(node as u8);
+        let old_key = pq.decrease_key_ref(&node, &decreased_key);
+        assert_eq!(vec![100 + node as u8; 2], old_key.value);
+        assert_eq!(Some(&decreased_key), pq.key_ref(&node));
+    }
+
+    // absent node never clones
+    let decreased_key = key(0);
+    pq.remove(&1);
+    num_clones.set(0);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        pq.decrease_key_ref(&1, &decreased_key);
+    }));
+    assert!(result.is_err());
+    assert_eq!(0, num_clones.get());
+
+    // decreasing with a function of the current key does not clone it
+    let old_key = pq.decrease_key_with(&3, |current| CountedKey {
+        value: current.value[1..].to_vec(),
+        num_clones: num_clones.clone(),
+    });
+    assert_eq!(vec![103, 103], old_key.value);
+    assert_eq!(Some(&vec![103]), pq.key_ref(&3).map(|x| &x.value));
+
+    let mut prev = None;
+    while let Some((_, key)) = pq.pop() {
+        if let Some(prev) = prev {
+            assert!(prev <= key.value);
+        }
+        prev = Some(key.value);
+    }
+}
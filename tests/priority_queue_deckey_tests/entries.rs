@@ -0,0 +1,41 @@
+use orx_priority_queue::PriorityQueueDecKey;
+use rand::prelude::*;
+
+pub fn test_entries<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    pq.clear();
+    assert_eq!(0, pq.entries().count());
+
+    let mut rng = rand::thread_rng();
+    let mut vec = Vec::new();
+
+    for node in 0..100 {
+        let priority = rng.gen();
+        pq.push(node, priority);
+        vec.push(priority);
+    }
+
+    for node in (0..100).step_by(3) {
+        let decreased = vec[node] - 1.0;
+        pq.decrease_key(&node, decreased);
+        vec[node] = decreased;
+    }
+    for node in (0..100).step_by(7) {
+        pq.remove(&node);
+    }
+
+    let mut entries: Vec<_> = pq.entries().collect();
+    assert_eq!(pq.len(), entries.len());
+    entries.sort_by_key(|x| *x.0);
+
+    let expected: Vec<_> = vec.iter().enumerate().filter(|x| x.0 % 7 != 0).collect();
+    assert_eq!(expected.len(), entries.len());
+    for ((node, key), (expected_node, expected_key)) in entries.into_iter().zip(expected) {
+        assert_eq!(expected_node, *node);
+        assert_eq!(expected_key, key);
+        assert!(pq.contains(node));
+        assert_eq!(Some(key), pq.key_ref(node));
+    }
+}
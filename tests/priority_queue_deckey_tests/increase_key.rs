@@ -0,0 +1,69 @@
+use itertools::Itertools;
+use orx_priority_queue::{PriorityQueueDecKey, ResTryIncreaseKey};
+use rand::prelude::*;
+use std::cmp::Ordering;
+
+pub fn test_increase_key<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    const LEN: usize = 100;
+
+    pq.clear();
+    assert!(pq.is_empty());
+
+    let mut rng = rand::thread_rng();
+    let mut vec = Vec::new();
+
+    // push 100
+    for node in 0..LEN {
+        let priority = rng.gen();
+        pq.push(node, priority);
+        vec.push((node, priority));
+    }
+
+    // increase keys 100 times
+    for _ in 0..LEN {
+        let node = rng.gen_range(0..LEN);
+        let old_key = vec[node].1;
+        assert_eq!(Some(old_key), pq.key_of(&node));
+
+        pq.increase_key(&node, old_key + rng.gen::<f64>());
+        vec[node].1 = pq.key_of(&node).expect("node exists");
+        assert!(vec[node].1 >= old_key);
+    }
+
+    // try to increase keys 100 times
+    for _ in 0..LEN {
+        let node = rng.gen_range(0..LEN);
+        let old_key = vec[node].1;
+        let new_key = old_key + rng.gen_range(-1.0..1.0);
+
+        let res_try_inckey = pq.try_increase_key(&node, new_key);
+        assert_eq!(
+            new_key > old_key,
+            matches!(res_try_inckey, ResTryIncreaseKey::Increased)
+        );
+        if new_key > old_key {
+            vec[node].1 = new_key;
+        }
+        assert_eq!(Some(vec[node].1), pq.key_of(&node));
+    }
+
+    // pop in correct order
+    let vec_sorted = vec
+        .into_iter()
+        .sorted_by(|x, y| {
+            if x.1 <= y.1 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .collect_vec();
+    for vec_popped in vec_sorted {
+        let pq_popped = pq.pop();
+        assert_eq!(Some(vec_popped), pq_popped);
+    }
+    assert!(pq.is_empty());
+}
@@ -8,6 +8,7 @@ where
     pq.clear();
     assert!(pq.is_empty());
     assert_eq!(None, pq.key_of(&0));
+    assert_eq!(None, pq.key_ref(&0));
 
     let mut rng = rand::thread_rng();
     let mut vec = Vec::new();
@@ -22,6 +23,8 @@ where
 
     for (i, key) in vec.iter().enumerate() {
         assert_eq!(Some(*key), pq.key_of(&i));
+        assert_eq!(Some(key), pq.key_ref(&i));
     }
     assert_eq!(None, pq.key_of(&100));
+    assert_eq!(None, pq.key_ref(&100));
 }
@@ -1,13 +1,29 @@
 mod change_key;
 mod change_key_or_push;
 mod contains;
+mod decrease_key_all;
+mod decrease_key_by;
+mod decrease_key_ref;
+mod entries;
+mod increase_key;
 mod key_of;
 mod mixed;
+mod push_if_lower;
 mod remove;
+mod take;
+mod update_key_if;
 
 pub use change_key::{test_change_key, ChangeKeyMethod};
 pub use change_key_or_push::test_change_key_or_push;
 pub use contains::test_contains;
+pub use decrease_key_all::test_decrease_key_all;
+pub use decrease_key_by::test_decrease_key_by;
+pub use decrease_key_ref::test_decrease_key_ref;
+pub use entries::test_entries;
+pub use increase_key::test_increase_key;
 pub use key_of::test_key_of;
 pub use mixed::test_mixed;
+pub use push_if_lower::test_push_if_lower;
 pub use remove::test_remove;
+pub use take::test_take;
+pub use update_key_if::test_update_key_if;
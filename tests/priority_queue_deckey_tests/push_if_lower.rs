@@ -0,0 +1,34 @@
+use orx_priority_queue::PriorityQueueDecKey;
+use rand::prelude::*;
+
+pub fn test_push_if_lower<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    const LEN: usize = 100;
+
+    pq.clear();
+    assert!(pq.is_empty());
+
+    let mut rng = rand::thread_rng();
+    let mut keys: Vec<Option<f64>> = vec![None; LEN];
+
+    for _ in 0..(4 * LEN) {
+        let node = rng.gen_range(0..LEN);
+        let key = rng.gen();
+        let expected = keys[node].map(|old| key < old).unwrap_or(true);
+
+        assert_eq!(expected, pq.push_if_lower(&node, key));
+        if expected {
+            keys[node] = Some(key);
+        }
+        assert_eq!(keys[node].as_ref(), pq.key_ref(&node));
+    }
+
+    let mut prev_key = f64::NEG_INFINITY;
+    while let Some((node, key)) = pq.pop() {
+        assert_eq!(keys[node], Some(key));
+        assert!(prev_key <= key);
+        prev_key = key;
+    }
+}
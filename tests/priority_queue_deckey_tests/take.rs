@@ -0,0 +1,38 @@
+use orx_priority_queue::PriorityQueueDecKey;
+use rand::prelude::*;
+
+pub fn test_take<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    const LEN: usize = 100;
+
+    pq.clear();
+    assert!(pq.is_empty());
+
+    let mut rng = rand::thread_rng();
+    let mut keys: Vec<Option<f64>> = vec![];
+    for node in 0..LEN {
+        let key = rng.gen();
+        pq.push(node, key);
+        keys.push(Some(key));
+    }
+
+    for _ in 0..LEN {
+        let node = rng.gen_range(0..LEN);
+        let taken = pq.take(&node);
+        assert_eq!(keys[node].map(|key| (node, key)), taken);
+        keys[node] = None;
+
+        assert!(!pq.contains(&node));
+        assert_eq!(keys.iter().flatten().count(), pq.len());
+    }
+
+    let mut prev_key = f64::NEG_INFINITY;
+    while let Some((node, key)) = pq.pop() {
+        assert_eq!(keys[node], Some(key));
+        assert!(prev_key <= key);
+        prev_key = key;
+    }
+    assert_eq!(None, pq.take(&0));
+}
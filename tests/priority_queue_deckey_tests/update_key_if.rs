@@ -0,0 +1,45 @@
+use orx_priority_queue::PriorityQueueDecKey;
+use rand::prelude::*;
+
+pub fn test_update_key_if<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    const LEN: usize = 100;
+
+    pq.clear();
+    assert!(pq.is_empty());
+
+    let mut rng = rand::thread_rng();
+    let mut keys = vec![];
+    for node in 0..LEN {
+        let key = rng.gen();
+        pq.push(node, key);
+        keys.push(key);
+    }
+
+    let far_enough = |old: &f64, new: &f64| (new - old).abs() > 0.25;
+    for _ in 0..(4 * LEN) {
+        let node = rng.gen_range(0..LEN);
+        let new_key = rng.gen();
+        let expected = far_enough(&keys[node], &new_key);
+
+        let updated = pq.update_key_if(&node, new_key, far_enough);
+        assert_eq!(expected, updated);
+        if updated {
+            keys[node] = new_key;
+        }
+        assert_eq!(Some(&keys[node]), pq.key_ref(&node));
+    }
+
+    assert!(!pq.update_key_if(&0, -1.0, |_, _| false));
+    assert!(pq.update_key_if(&0, -1.0, |_, _| true));
+    assert_eq!(Some((0, -1.0)), pq.pop());
+
+    let mut prev_key = f64::NEG_INFINITY;
+    while let Some((node, key)) = pq.pop() {
+        assert_eq!(keys[node], key);
+        assert!(prev_key <= key);
+        prev_key = key;
+    }
+}
@@ -0,0 +1,8 @@
+use orx_priority_queue::PriorityQueue;
+
+pub fn test_arity<P>(pq: P, arity: Option<usize>)
+where
+    P: PriorityQueue<usize, f64>,
+{
+    assert_eq!(arity, pq.arity());
+}
@@ -0,0 +1,83 @@
+use itertools::Itertools;
+use orx_priority_queue::{PriorityQueue, PriorityQueueDecKey};
+use rand::prelude::*;
+use std::cmp::Ordering;
+
+#[allow(dead_code)]
+pub fn test_extend<P>(mut pq: P)
+where
+    P: PriorityQueue<usize, f64> + Extend<(usize, f64)>,
+{
+    let mut rng = rand::thread_rng();
+
+    pq.clear();
+    let mut vec = Vec::new();
+
+    // extend empty queue: bulk heapify
+    let first = (0..60).map(|node| (node, rng.gen())).collect_vec();
+    pq.extend(first.iter().copied());
+    vec.extend(first);
+    assert_eq!(60, pq.len());
+
+    // extend with a small batch: individual heapify-up's
+    let second = (60..65).map(|node| (node, rng.gen())).collect_vec();
+    pq.extend(second.iter().copied());
+    vec.extend(second);
+    assert_eq!(65, pq.len());
+
+    // extend with a large batch: rebuild
+    let third = (65..125).map(|node| (node, rng.gen())).collect_vec();
+    pq.extend(third.iter().copied());
+    vec.extend(third);
+    assert_eq!(125, pq.len());
+
+    // extend with an empty iterator
+    pq.extend(core::iter::empty());
+    assert_eq!(125, pq.len());
+
+    let sorted = vec
+        .into_iter()
+        .sorted_by(|x, y| {
+            if x.1 <= y.1 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .collect_vec();
+    for expected in sorted {
+        assert_eq!(Some(expected), pq.pop());
+    }
+    assert!(pq.is_empty());
+}
+
+#[allow(dead_code)]
+pub fn test_extend_duplicates<P>(mut pq: P)
+where
+    P: PriorityQueueDecKey<usize, f64> + Extend<(usize, f64)>,
+{
+    pq.clear();
+    pq.extend([(0, 5.0), (1, 3.0), (2, 8.0)]);
+    assert_eq!(3, pq.len());
+
+    // nodes already in the queue: 0 with a smaller key, 2 with a greater key
+    // node repeated within the iterator: 3
+    pq.extend([(0, 1.0), (2, 9.0), (3, 4.0), (3, 2.0), (3, 6.0), (4, 7.0)]);
+    assert_eq!(5, pq.len());
+    assert_eq!(Some(1.0), pq.key_of(&0));
+    assert_eq!(Some(3.0), pq.key_of(&1));
+    assert_eq!(Some(8.0), pq.key_of(&2));
+    assert_eq!(Some(2.0), pq.key_of(&3));
+    assert_eq!(Some(7.0), pq.key_of(&4));
+
+    // nodes already in the queue only
+    pq.extend([(1, 0.5), (4, 10.0)]);
+    assert_eq!(5, pq.len());
+
+    let popped: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+    assert_eq!(
+        vec![(1, 0.5), (0, 1.0), (3, 2.0), (4, 7.0), (2, 8.0)],
+        popped
+    );
+    assert!(pq.is_empty());
+}
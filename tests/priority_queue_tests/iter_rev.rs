@@ -0,0 +1,31 @@
+use orx_priority_queue::{NodeKeyRef, PriorityQueue};
+use rand::prelude::*;
+
+pub fn test_iter_rev<P>(mut pq: P)
+where
+    P: PriorityQueue<usize, f64>,
+{
+    pq.clear();
+    assert_eq!(0, pq.iter().rev().count());
+
+    let mut rng = rand::thread_rng();
+    for node in 0..100 {
+        pq.push(node, rng.gen());
+    }
+    for _ in 0..30 {
+        pq.pop();
+    }
+
+    let forward: Vec<_> = pq.iter().map(|x| (*x.node(), *x.key())).collect();
+    let mut backward: Vec<_> = pq.iter().rev().map(|x| (*x.node(), *x.key())).collect();
+    assert_eq!(70, backward.len());
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    let mut iter = pq.iter();
+    let first = iter.next().map(|x| *x.node());
+    let last = iter.next_back().map(|x| *x.node());
+    assert_eq!(68, iter.count());
+    assert_eq!(first, forward.first().map(|x| x.0));
+    assert_eq!(last, forward.last().map(|x| x.0));
+}
@@ -1,16 +1,27 @@
+mod arity;
 mod as_slice;
 mod clear;
+mod extend;
 mod is_empty;
+mod iter_rev;
 mod len;
+mod nodes_keys;
+mod or_default;
 mod peek;
 mod push_pop;
 mod push_then_pop;
 
+pub use arity::test_arity;
 #[allow(unused_imports)]
 pub use as_slice::test_as_slice;
 pub use clear::test_clear;
+#[allow(unused_imports)]
+pub use extend::{test_extend, test_extend_duplicates};
 pub use is_empty::test_is_empty;
+pub use iter_rev::test_iter_rev;
 pub use len::test_len;
+pub use nodes_keys::test_nodes_keys;
+pub use or_default::test_or_default;
 pub use peek::test_peek;
 pub use push_pop::{test_push_pop, test_push_pop_randomized};
 pub use push_then_pop::{test_push_then_pop, test_push_then_pop_randomized};
@@ -0,0 +1,26 @@
+use orx_priority_queue::{NodeKeyRef, PriorityQueue};
+use rand::prelude::*;
+
+pub fn test_nodes_keys<P>(mut pq: P)
+where
+    P: PriorityQueue<usize, f64>,
+{
+    pq.clear();
+    assert_eq!(0, pq.nodes().count());
+    assert_eq!(0, pq.keys().count());
+
+    let mut rng = rand::thread_rng();
+    for node in 0..100 {
+        pq.push(node, rng.gen());
+    }
+    for _ in 0..30 {
+        pq.pop();
+    }
+
+    let nodes: Vec<_> = pq.nodes().copied().collect();
+    let keys: Vec<_> = pq.keys().copied().collect();
+    let pairs: Vec<_> = pq.iter().map(|x| (*x.node(), *x.key())).collect();
+
+    assert_eq!(70, nodes.len());
+    assert_eq!(pairs, nodes.into_iter().zip(keys).collect::<Vec<_>>());
+}
@@ -0,0 +1,27 @@
+use orx_priority_queue::PriorityQueue;
+
+pub fn test_or_default<P>(mut pq: P)
+where
+    P: PriorityQueue<usize, f64>,
+{
+    pq.clear();
+    let default_key = f64::INFINITY;
+    assert_eq!(&default_key, pq.peek_key_or(&default_key));
+    assert_eq!((0, default_key), pq.pop_or((0, default_key)));
+    assert!(pq.is_empty());
+
+    pq.push(1, 2.0);
+    pq.push(2, 3.0);
+    pq.push(3, 1.0);
+    assert_eq!(&1.0, pq.peek_key_or(&default_key));
+    assert_eq!(3, pq.len());
+
+    assert_eq!((3, 1.0), pq.pop_or((0, default_key)));
+    assert_eq!(&2.0, pq.peek_key_or(&default_key));
+    assert_eq!((1, 2.0), pq.pop_or((0, default_key)));
+    assert_eq!((2, 3.0), pq.pop_or((0, default_key)));
+
+    assert_eq!(&default_key, pq.peek_key_or(&default_key));
+    assert_eq!((42, -1.0), pq.pop_or((42, -1.0)));
+    assert!(pq.is_empty());
+}
@@ -6,6 +6,8 @@ where
 {
     pq.clear();
     assert!(pq.peek().is_none());
+    assert!(pq.peek_node().is_none());
+    assert!(pq.peek_key().is_none());
 
     pq.push(1, 2.0);
     assert_eq!(Some(&1), pq.peek().map(|nk| nk.node()));
@@ -18,6 +20,9 @@ where
     pq.push(3, 1.0);
     assert_eq!(Some(&3), pq.peek().map(|nk| nk.node()));
     assert_eq!(Some(&1.0), pq.peek().map(|nk| nk.key()));
+    assert_eq!(Some(&3), pq.peek_node());
+    assert_eq!(Some(&1.0), pq.peek_key());
+    assert_eq!(Some((&3, &1.0)), pq.peek().map(|nk| nk.as_tuple()));
 
     pq.pop();
     assert_eq!(Some(&1), pq.peek().map(|nk| nk.node()));
@@ -29,4 +34,6 @@ where
 
     pq.pop();
     assert!(pq.peek().is_none());
+    assert!(pq.peek_node().is_none());
+    assert!(pq.peek_key().is_none());
 }
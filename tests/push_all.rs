@@ -0,0 +1,86 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 300;
+
+type PushAll<P> = fn(&mut P, Vec<(usize, f64)>);
+
+fn test_push_all<P>(
+    mut pq: P,
+    len_existing: usize,
+    len_batch: usize,
+    push_all: PushAll<P>,
+    validate: fn(&P) -> Result<(), HeapError>,
+) where
+    P: PriorityQueue<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+    let mut nodes: Vec<_> = (0..N).collect();
+    nodes.shuffle(&mut rng);
+    let mut nodes = nodes.into_iter();
+
+    let mut expected = vec![];
+    for node in nodes.by_ref().take(len_existing) {
+        let key = rng.gen();
+        pq.push(node, key);
+        expected.push((node, key));
+    }
+
+    let batch: Vec<_> = nodes.take(len_batch).map(|n| (n, rng.gen())).collect();
+    expected.extend(batch.iter().copied());
+    push_all(&mut pq, batch);
+
+    assert_eq!(Ok(()), validate(&pq));
+    assert_eq!(expected.len(), pq.len());
+
+    expected.sort_by(|a, b| a.1.partial_cmp(&b.1).expect("no NaN"));
+    let mut popped = vec![];
+    while let Some(x) = pq.pop() {
+        popped.push(x);
+    }
+    assert_eq!(expected, popped);
+}
+
+fn test_for<const D: usize>() {
+    let lengths = [
+        (0, 0),
+        (0, 50),
+        (50, 0),
+        (100, 5),
+        (5, 100),
+        (80, 80),
+        (80, 81),
+    ];
+    for (len_existing, len_batch) in lengths {
+        test_push_all(
+            DaryHeap::<usize, f64, D>::default(),
+            len_existing,
+            len_batch,
+            |pq, batch| pq.push_all(batch),
+            DaryHeap::validate,
+        );
+        test_push_all(
+            DaryHeapWithMap::<usize, f64, D>::default(),
+            len_existing,
+            len_batch,
+            |pq, batch| pq.push_all(batch),
+            DaryHeapWithMap::validate,
+        );
+        test_push_all(
+            DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+            len_existing,
+            len_batch,
+            |pq, batch| pq.push_all(batch),
+            DaryHeapOfIndices::validate,
+        );
+    }
+}
+
+#[test]
+fn push_all() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
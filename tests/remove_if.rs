@@ -0,0 +1,77 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 200;
+
+type RemoveIf<P> = fn(&mut P, &mut dyn FnMut(&usize, &f64) -> bool) -> Vec<(usize, f64)>;
+
+fn sorted(mut elements: Vec<(usize, f64)>) -> Vec<(usize, f64)> {
+    elements.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    elements
+}
+
+fn test_remove_if<P>(pq: &mut P, remove_if: RemoveIf<P>, validate: fn(&P) -> Result<(), HeapError>)
+where
+    P: PriorityQueue<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+
+    for threshold in [1.0, 0.75, 0.5, 0.25, 0.0] {
+        pq.clear();
+        for node in 0..N {
+            pq.push(node, rng.gen());
+        }
+        let (expected_removed, expected_kept): (Vec<_>, Vec<_>) = pq
+            .iter()
+            .map(|x| (*x.node(), *x.key()))
+            .partition(|x| x.1 >= threshold);
+
+        let removed = remove_if(pq, &mut |_, key| *key >= threshold);
+        assert_eq!(Ok(()), validate(pq));
+        assert_eq!(sorted(expected_removed), sorted(removed));
+
+        let popped: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+        assert_eq!(sorted(expected_kept), popped);
+    }
+
+    // removing none
+    for node in 0..N {
+        pq.push(node, rng.gen());
+    }
+    let removed = remove_if(pq, &mut |_, _| false);
+    assert!(removed.is_empty());
+    assert_eq!(N, pq.len());
+
+    // removing all
+    let removed = remove_if(pq, &mut |_, _| true);
+    assert_eq!(N, removed.len());
+    assert!(pq.is_empty());
+    assert_eq!(Ok(()), validate(pq));
+}
+
+fn test_for<const D: usize>() {
+    test_remove_if(
+        &mut DaryHeap::<usize, f64, D>::default(),
+        |pq, f| pq.remove_if(f),
+        DaryHeap::validate,
+    );
+    test_remove_if(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        |pq, f| pq.remove_if(f),
+        DaryHeapWithMap::validate,
+    );
+    test_remove_if(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        |pq, f| pq.remove_if(f),
+        DaryHeapOfIndices::validate,
+    );
+}
+
+#[test]
+fn remove_if() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
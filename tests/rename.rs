@@ -0,0 +1,95 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+type Rename<P> = fn(&mut P, &usize, usize) -> Result<(), RenameError>;
+
+fn random_ops<P>(pq: &mut P, rename: Rename<P>, as_slice: fn(&P) -> &[(usize, f64)])
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+    let mut model: Vec<Option<f64>> = vec![None; N];
+
+    for _ in 0..1000 {
+        let node = rng.gen_range(0..N);
+        let new = rng.gen_range(0..N);
+
+        match rng.gen_range(0..3) {
+            0 => {
+                let key: f64 = rng.gen();
+                _ = pq.try_decrease_key_or_push(&node, key);
+                model[node] = Some(model[node].map(|x| x.min(key)).unwrap_or(key));
+            }
+            _ => {
+                let slice_before = as_slice(pq).to_vec();
+                let result = rename(pq, &node, new);
+                match (model[node], model[new]) {
+                    (None, _) => {
+                        assert_eq!(Err(RenameError::NodeNotFound), result);
+                        assert_eq!(slice_before, as_slice(pq));
+                    }
+                    (Some(_), Some(_)) => {
+                        assert_eq!(Err(RenameError::NodeAlreadyExists), result);
+                        assert_eq!(slice_before, as_slice(pq));
+                    }
+                    (Some(key), None) => {
+                        assert_eq!(Ok(()), result);
+                        model[node] = None;
+                        model[new] = Some(key);
+
+                        // only the node at the same position is replaced
+                        let slice = as_slice(pq);
+                        for (before, after) in slice_before.iter().zip(slice) {
+                            match before.0 == node {
+                                true => assert_eq!(&(new, before.1), after),
+                                false => assert_eq!(before, after),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        assert_eq!(model[node], pq.key_of(&node));
+        assert_eq!(model[new], pq.key_of(&new));
+        assert_eq!(model.iter().flatten().count(), pq.len());
+    }
+
+    let mut expected: Vec<_> = model
+        .iter()
+        .enumerate()
+        .filter_map(|(node, key)| key.map(|key| (node, key)))
+        .collect();
+    expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let popped: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+    assert_eq!(expected, popped);
+}
+
+fn test_for<const D: usize>() {
+    random_ops(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        DaryHeapWithMap::rename,
+        DaryHeapWithMap::as_slice,
+    );
+    random_ops(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        DaryHeapOfIndices::rename,
+        DaryHeapOfIndices::as_slice,
+    );
+    random_ops(
+        &mut DaryHeapWithSortedVec::<usize, f64, D>::default(),
+        DaryHeapWithSortedVec::rename,
+        DaryHeapWithSortedVec::as_slice,
+    );
+}
+
+#[test]
+fn rename() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,92 @@
+use itertools::Itertools;
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn pop_all<P: PriorityQueue<usize, f64>>(mut pq: P) -> Vec<f64> {
+    let mut keys = vec![];
+    while let Some(key) = pq.pop_key() {
+        keys.push(key);
+    }
+    keys
+}
+
+fn sorted(keys: &[f64]) -> Vec<f64> {
+    keys.iter()
+        .copied()
+        .sorted_by(|a, b| a.partial_cmp(b).expect("no nan"))
+        .collect()
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let len = 100;
+    let keys: Vec<f64> = (0..len).map(|_| rng.gen()).collect_vec();
+
+    let mut dary = DaryHeap::<usize, f64, D>::default();
+    let mut map = DaryHeapWithMap::<usize, f64, D>::default();
+    let mut indices = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(len);
+    for (node, key) in keys.iter().enumerate() {
+        dary.push(node, *key);
+        map.push(node, *key);
+        indices.push(node, *key);
+    }
+
+    // idempotent on a valid heap
+    let slice = dary.as_slice().to_vec();
+    dary.repair();
+    map.repair();
+    indices.repair();
+    assert_eq!(slice, dary.as_slice());
+    assert_eq!(Ok(()), dary.validate());
+    assert_eq!(Ok(()), map.validate());
+    assert_eq!(Ok(()), indices.validate());
+
+    // keys mutated without the guard restoring the heap
+    let mutated: Vec<f64> = keys.iter().map(|key| 1.0 - key).collect();
+    let mut guard = dary.iter_mut();
+    for (node, key) in &mut guard {
+        *key = mutated[*node];
+    }
+    std::mem::forget(guard);
+    let mut guard = map.iter_mut();
+    for (node, key) in &mut guard {
+        *key = mutated[*node];
+    }
+    std::mem::forget(guard);
+    let mut guard = indices.iter_mut();
+    for (node, key) in &mut guard {
+        *key = mutated[*node];
+    }
+    std::mem::forget(guard);
+
+    dary.repair();
+    map.repair();
+    indices.repair();
+    assert_eq!(Ok(()), dary.validate());
+    assert_eq!(Ok(()), map.validate());
+    assert_eq!(Ok(()), indices.validate());
+
+    for (node, key) in mutated.iter().enumerate() {
+        assert_eq!(Some(*key), map.key_of(&node));
+        assert_eq!(Some(*key), indices.key_of(&node));
+    }
+
+    let expected = sorted(&mutated);
+    assert_eq!(expected, pop_all(dary));
+    assert_eq!(expected, pop_all(map));
+    assert_eq!(expected, pop_all(indices));
+
+    let mut empty = DaryHeapWithMap::<usize, f64, D>::default();
+    empty.repair();
+    assert!(empty.is_empty());
+    assert_eq!(Ok(()), empty.validate());
+}
+
+#[test]
+fn repair() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,83 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn test_replace_root<P>(
+    pq: &mut P,
+    replace_root: fn(&mut P, usize, f64) -> Option<(usize, f64)>,
+    validate: fn(&P) -> Result<(), HeapError>,
+) where
+    P: PriorityQueue<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+
+    pq.clear();
+    assert_eq!(None, replace_root(pq, 0, 0.5));
+    assert_eq!(Some((0, 0.5)), pq.pop());
+
+    let mut keys: Vec<Option<f64>> = vec![None; N];
+    for (node, key) in keys.iter_mut().enumerate().skip(N / 2) {
+        let k = rng.gen();
+        pq.push(node, k);
+        *key = Some(k);
+    }
+
+    for _ in 0..300 {
+        let min = keys
+            .iter()
+            .filter_map(|x| *x)
+            .min_by(|a, b| a.total_cmp(b))
+            .expect("not empty");
+
+        // replace with a node which is not in the queue, or with the root itself
+        let (node, key) = match rng.gen_bool(0.8) {
+            true => {
+                let absent = keys.iter().position(|x| x.is_none()).expect("absent node");
+                (absent, rng.gen::<f64>() * 2.0 - 0.5)
+            }
+            false => (*pq.peek().expect("not empty").node(), rng.gen()),
+        };
+
+        let (root_node, root_key) = replace_root(pq, node, key).expect("not empty");
+        assert_eq!(min, root_key);
+        assert_eq!(Some(root_key), keys[root_node]);
+        keys[root_node] = None;
+        keys[node] = Some(key);
+
+        assert_eq!(N / 2, pq.len());
+        assert_eq!(Ok(()), validate(pq));
+    }
+
+    let mut expected: Vec<_> = keys.iter().filter_map(|x| *x).collect();
+    expected.sort_by(|a, b| a.total_cmp(b));
+    let popped: Vec<_> = core::iter::from_fn(|| pq.pop_key()).collect();
+    assert_eq!(expected, popped);
+}
+
+fn test_for<const D: usize>() {
+    test_replace_root(
+        &mut DaryHeap::<usize, f64, D>::default(),
+        DaryHeap::replace_root,
+        DaryHeap::validate,
+    );
+    test_replace_root(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        DaryHeapWithMap::replace_root,
+        DaryHeapWithMap::validate,
+    );
+    test_replace_root(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        DaryHeapOfIndices::replace_root,
+        DaryHeapOfIndices::validate,
+    );
+}
+
+#[test]
+fn replace_root() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,84 @@
+use orx_priority_queue::*;
+
+fn test_reserve<P>(mut pq: P, reserve: fn(&mut P, usize))
+where
+    P: PriorityQueue<usize, u64>,
+{
+    reserve(&mut pq, 50);
+    assert!(pq.capacity() >= 50);
+
+    let capacity = pq.capacity();
+    for node in 0..50 {
+        pq.push(node, node as u64);
+    }
+    assert_eq!(capacity, pq.capacity());
+
+    reserve(&mut pq, 30);
+    assert!(pq.capacity() >= 80);
+
+    let capacity = pq.capacity();
+    for node in 50..80 {
+        pq.push(node, node as u64);
+    }
+    assert_eq!(capacity, pq.capacity());
+
+    for node in 0..80 {
+        assert_eq!(Some(node), pq.pop_node());
+    }
+}
+
+fn test_for<const D: usize>() {
+    test_reserve(DaryHeap::<usize, u64, D>::new(), DaryHeap::reserve);
+    test_reserve(DaryHeap::<usize, u64, D>::new(), DaryHeap::reserve_exact);
+
+    test_reserve(
+        DaryHeapWithMap::<usize, u64, D>::new(),
+        DaryHeapWithMap::reserve,
+    );
+    test_reserve(
+        DaryHeapWithMap::<usize, u64, D>::new(),
+        DaryHeapWithMap::reserve_exact,
+    );
+
+    let new_heap = || DaryHeapOfIndices::<usize, u64, D>::with_index_bound(80);
+    test_reserve(new_heap(), DaryHeapOfIndices::reserve);
+    test_reserve(new_heap(), DaryHeapOfIndices::reserve_exact);
+}
+
+#[test]
+fn reserve() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn reserve_tree_keeps_index_bound() {
+    let mut pq = QuaternaryHeapOfIndices::<usize, u64>::with_index_bound(1000);
+    assert_eq!(0, pq.tree_capacity());
+
+    pq.reserve_tree(50);
+    assert!(pq.tree_capacity() >= 50);
+    assert_eq!(pq.capacity(), pq.tree_capacity());
+    assert_eq!(1000, pq.index_bound());
+
+    let capacity = pq.tree_capacity();
+    for node in (0..50).map(|x| x * 20) {
+        pq.push(node, node as u64);
+    }
+    assert_eq!(capacity, pq.tree_capacity());
+
+    pq.reserve_tree(2000);
+    assert!(pq.tree_capacity() >= 2050);
+    assert_eq!(1000, pq.index_bound());
+
+    pq.grow_index_bound(2000);
+    assert_eq!(2000, pq.index_bound());
+    assert!(pq.tree_capacity() >= 2050);
+
+    for node in (0..50).map(|x| x * 20) {
+        assert_eq!(Some(node), pq.pop_node());
+    }
+}
@@ -0,0 +1,92 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 200;
+
+type Retain<P> = fn(&mut P, &mut dyn FnMut(&usize, &f64) -> bool);
+
+fn test_retain<P>(pq: &mut P, retain: Retain<P>, validate: fn(&P) -> Result<(), HeapError>)
+where
+    P: PriorityQueue<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+
+    for threshold in [1.0, 0.75, 0.5, 0.25, 0.0] {
+        pq.clear();
+        for node in 0..N {
+            pq.push(node, rng.gen());
+        }
+        let mut expected: Vec<_> = pq
+            .iter()
+            .filter(|x| *x.key() < threshold)
+            .map(|x| (*x.node(), *x.key()))
+            .collect();
+        expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        retain(pq, &mut |_, key| *key < threshold);
+        assert_eq!(Ok(()), validate(pq));
+        assert_eq!(expected.len(), pq.len());
+
+        let popped: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+        assert_eq!(expected, popped);
+    }
+
+    // retaining all
+    for node in 0..N {
+        pq.push(node, rng.gen());
+    }
+    let slice_before: Vec<_> = pq.iter().map(|x| (*x.node(), *x.key())).collect();
+    retain(pq, &mut |_, _| true);
+    let slice_after: Vec<_> = pq.iter().map(|x| (*x.node(), *x.key())).collect();
+    assert_eq!(slice_before, slice_after);
+
+    // retaining none
+    retain(pq, &mut |_, _| false);
+    assert!(pq.is_empty());
+    assert_eq!(Ok(()), validate(pq));
+    pq.push(42, 0.5);
+    assert_eq!(Some((42, 0.5)), pq.pop());
+}
+
+fn test_for<const D: usize>() {
+    test_retain(
+        &mut DaryHeap::<usize, f64, D>::default(),
+        |pq, f| pq.retain(f),
+        DaryHeap::validate,
+    );
+    test_retain(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        |pq, f| pq.retain(f),
+        DaryHeapWithMap::validate,
+    );
+    test_retain(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        |pq, f| pq.retain(f),
+        DaryHeapOfIndices::validate,
+    );
+}
+
+#[test]
+fn retain() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn retain_keeps_positions_in_sync() {
+    let mut pq = QuaternaryHeapOfIndices::with_index_bound(N);
+    for node in 0..N {
+        pq.push(node, node as f64);
+    }
+    pq.retain(|node, _| node % 2 == 1);
+
+    for node in 0..N {
+        assert_eq!(node % 2 == 1, pq.contains(&node));
+    }
+    pq.decrease_key(&(N - 1), -1.0);
+    assert_eq!(Some((N - 1, -1.0)), pq.pop());
+    assert_eq!(Some((1, 1.0)), pq.pop());
+}
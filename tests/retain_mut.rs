@@ -0,0 +1,108 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 200;
+
+type RetainMut<P> = fn(&mut P, &mut dyn FnMut(&usize, &mut f64) -> bool);
+
+fn test_retain_mut<P>(
+    pq: &mut P,
+    retain_mut: RetainMut<P>,
+    validate: fn(&P) -> Result<(), HeapError>,
+) where
+    P: PriorityQueue<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+
+    for threshold in [1.0, 0.75, 0.5, 0.25, 0.0] {
+        pq.clear();
+        for node in 0..N {
+            pq.push(node, rng.gen());
+        }
+        // keys below the threshold survive and are reversed within [0, threshold)
+        let mut expected: Vec<_> = pq
+            .iter()
+            .filter(|x| *x.key() < threshold)
+            .map(|x| (*x.node(), threshold - *x.key()))
+            .collect();
+        expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        retain_mut(pq, &mut |_, key| match *key < threshold {
+            true => {
+                *key = threshold - *key;
+                true
+            }
+            false => false,
+        });
+        assert_eq!(Ok(()), validate(pq));
+        assert_eq!(expected.len(), pq.len());
+
+        let popped: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+        assert_eq!(expected, popped);
+    }
+
+    // retaining all while mutating keys heapifies
+    for node in 0..N {
+        pq.push(node, node as f64);
+    }
+    retain_mut(pq, &mut |node, key| {
+        *key = (N - *node) as f64;
+        true
+    });
+    assert_eq!(Ok(()), validate(pq));
+    assert_eq!(Some((N - 1, 1.0)), pq.pop());
+
+    // retaining none
+    retain_mut(pq, &mut |_, _| false);
+    assert!(pq.is_empty());
+    assert_eq!(Ok(()), validate(pq));
+    pq.push(42, 0.5);
+    assert_eq!(Some((42, 0.5)), pq.pop());
+}
+
+fn test_for<const D: usize>() {
+    test_retain_mut(
+        &mut DaryHeap::<usize, f64, D>::default(),
+        |pq, f| pq.retain_mut(f),
+        DaryHeap::validate,
+    );
+    test_retain_mut(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        |pq, f| pq.retain_mut(f),
+        DaryHeapWithMap::validate,
+    );
+    test_retain_mut(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        |pq, f| pq.retain_mut(f),
+        DaryHeapOfIndices::validate,
+    );
+}
+
+#[test]
+fn retain_mut() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn retain_mut_keeps_positions_in_sync() {
+    let mut pq = QuaternaryHeapWithMap::default();
+    for node in 0..N {
+        pq.push(node, node as f64);
+    }
+    pq.retain_mut(|node, key| {
+        *key *= 2.0;
+        node % 2 == 1
+    });
+
+    for node in 0..N {
+        assert_eq!(node % 2 == 1, pq.contains(&node));
+    }
+    assert_eq!(Some(&6.0), pq.key_ref(&3));
+    pq.decrease_key(&(N - 1), -1.0);
+    assert_eq!(Some((N - 1, -1.0)), pq.pop());
+    assert_eq!(Some((1, 2.0)), pq.pop());
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "serde")]
+
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 100;
+
+fn drain<P>(pq: &mut P) -> Vec<(usize, u64)>
+where
+    P: PriorityQueue<usize, u64>,
+{
+    let drained: Vec<_> = core::iter::from_fn(|| pq.pop()).collect();
+    assert!(drained.windows(2).all(|x| x[0].1 <= x[1].1));
+    drained
+}
+
+fn round_trip<P>(mut pq: P)
+where
+    P: PriorityQueue<usize, u64> + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut rng = rand::thread_rng();
+    let mut nodes: Vec<_> = (0..N).collect();
+    nodes.shuffle(&mut rng);
+    for node in nodes.into_iter().take(N / 2) {
+        pq.push(node, rng.gen_range(0..1000));
+    }
+
+    let json = serde_json::to_string(&pq).expect("must serialize");
+    let mut deserialized: P = serde_json::from_str(&json).expect("must deserialize");
+    assert_eq!(pq.len(), deserialized.len());
+    let (mut drained, mut drained_deserialized) = (drain(&mut pq), drain(&mut deserialized));
+    drained.sort_by_key(|x| (x.1, x.0));
+    drained_deserialized.sort_by_key(|x| (x.1, x.0));
+    assert_eq!(drained, drained_deserialized);
+
+    let json = serde_json::to_string(&pq).expect("must serialize");
+    let deserialized: P = serde_json::from_str(&json).expect("must deserialize");
+    assert!(deserialized.is_empty());
+}
+
+fn test_for<const D: usize>() {
+    round_trip(DaryHeap::<usize, u64, D>::default());
+    round_trip(DaryHeapWithMap::<usize, u64, D>::default());
+    round_trip(DaryHeapOfIndices::<usize, u64, D>::with_index_bound(N));
+
+    let json = "[[3,0.5],[1,0.25],[2,0.75]]";
+    let pq: DaryHeapWithMap<usize, f64, D> = serde_json::from_str(json).expect("must deserialize");
+    assert_eq!(Ok(()), pq.validate());
+    assert_eq!(Some(&1), pq.peek_node());
+}
+
+#[test]
+fn serde_round_trip() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn serde_omits_offset() {
+    let mut pq = DaryHeap::<usize, u32, 4>::default();
+    pq.push(0, 7);
+    assert_eq!(
+        "[[0,7]]",
+        serde_json::to_string(&pq).expect("must serialize")
+    );
+}
+
+#[test]
+fn serde_index_bound() {
+    let mut pq = QuaternaryHeapOfIndices::with_index_bound(8);
+    pq.push(3usize, 42u32);
+    let json = serde_json::to_string(&pq).expect("must serialize");
+    assert_eq!(r#"{"index_bound":8,"elements":[[3,42]]}"#, json);
+
+    let deserialized: QuaternaryHeapOfIndices<usize, u32> =
+        serde_json::from_str(&json).expect("must deserialize");
+    assert_eq!(8, deserialized.index_bound());
+    assert!(deserialized.contains(&3));
+
+    let out_of_bound = r#"{"index_bound":2,"elements":[[3,42]]}"#;
+    let result = serde_json::from_str::<QuaternaryHeapOfIndices<usize, u32>>(out_of_bound);
+    assert!(result.is_err());
+}
+
+#[test]
+fn serde_duplicate_nodes() {
+    let json = "[[1,1],[2,2],[1,3]]";
+
+    let result = serde_json::from_str::<QuaternaryHeapWithMap<usize, u32>>(json);
+    assert!(result.is_err());
+
+    let json = r#"{"index_bound":4,"elements":[[1,1],[2,2],[1,3]]}"#;
+    let result = serde_json::from_str::<QuaternaryHeapOfIndices<usize, u32>>(json);
+    assert!(result.is_err());
+
+    let json = "[[1,1],[2,2],[1,3]]";
+    let pq: QuaternaryHeap<usize, u32> = serde_json::from_str(json).expect("must deserialize");
+    assert_eq!(3, pq.len());
+}
@@ -0,0 +1,70 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut pq = DaryHeapOfIndices::<usize, f64, D>::with_index_bound(1000);
+
+    for node in 0..100 {
+        pq.push(node, rng.gen());
+    }
+    let slice_before = pq.as_slice().to_vec();
+
+    // growing is a no-op
+    assert_eq!(Ok(()), pq.shrink_index_bound(2000));
+    assert_eq!(1000, pq.index_bound());
+
+    // nodes beyond the new bound are in the queue
+    for new_bound in [0, 1, 50, 99] {
+        assert_eq!(
+            Err(IndexOutOfBound {
+                index: 99,
+                bound: new_bound
+            }),
+            pq.shrink_index_bound(new_bound)
+        );
+        assert_eq!(1000, pq.index_bound());
+        assert_eq!(slice_before.as_slice(), pq.as_slice());
+    }
+
+    let mut bound = 1000;
+    while bound > 100 {
+        bound = (bound / 2).max(100);
+        assert_eq!(Ok(()), pq.shrink_index_bound(bound));
+        assert_eq!(bound, pq.index_bound());
+        assert_eq!(slice_before.as_slice(), pq.as_slice());
+        assert_eq!(Ok(()), pq.validate());
+    }
+    assert!(pq.try_push(100, 0.5).is_err());
+
+    // pop the high indices and shrink further
+    pq.retain(|node, _| *node < 10);
+    assert_eq!(Ok(()), pq.shrink_index_bound(10));
+    assert_eq!(10, pq.index_bound());
+    assert_eq!(Ok(()), pq.validate());
+    for (node, key) in slice_before.iter().filter(|x| x.0 < 10) {
+        assert_eq!(Some(*key), pq.key_of(node));
+    }
+
+    // growing back is allowed
+    pq.grow_index_bound(20);
+    pq.push(15, -1.0);
+    assert_eq!(Some((15, -1.0)), pq.pop());
+
+    let mut prev = f64::NEG_INFINITY;
+    while let Some((_, key)) = pq.pop() {
+        assert!(prev <= key);
+        prev = key;
+    }
+    assert_eq!(Ok(()), pq.shrink_index_bound(0));
+    assert_eq!(0, pq.index_bound());
+}
+
+#[test]
+fn shrink_index_bound() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
@@ -0,0 +1,171 @@
+use orx_priority_queue::*;
+
+fn test_shrink_to_fit<P>(mut pq: P, shrink_to_fit: fn(&mut P))
+where
+    P: PriorityQueue<usize, u64>,
+{
+    for node in 0..1000 {
+        pq.push(node, node as u64);
+    }
+    for node in 0..990 {
+        assert_eq!(Some(node), pq.pop_node());
+    }
+    assert_eq!(10, pq.len());
+    assert!(pq.capacity() >= 1000);
+
+    shrink_to_fit(&mut pq);
+    assert!(pq.capacity() < 1000);
+    assert!(pq.capacity() >= 10);
+
+    for node in 990..1000 {
+        assert_eq!(Some(node), pq.pop_node());
+    }
+    assert!(pq.is_empty());
+
+    shrink_to_fit(&mut pq);
+    assert_eq!(0, pq.capacity());
+
+    pq.push(0, 0);
+    assert_eq!(Some(0), pq.pop_node());
+}
+
+fn test_for<const D: usize>() {
+    test_shrink_to_fit(DaryHeap::<usize, u64, D>::new(), DaryHeap::shrink_to_fit);
+    test_shrink_to_fit(
+        DaryHeapWithMap::<usize, u64, D>::new(),
+        DaryHeapWithMap::shrink_to_fit,
+    );
+    test_shrink_to_fit(
+        DaryHeapOfIndices::<usize, u64, D>::with_index_bound(1000),
+        DaryHeapOfIndices::shrink_to_fit,
+    );
+}
+
+#[test]
+fn shrink_to_fit() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+fn test_clear_and_shrink<P>(mut pq: P, clear_and_shrink: fn(&mut P))
+where
+    P: PriorityQueueDecKey<usize, u64>,
+{
+    for node in 0..1000 {
+        pq.push(node, node as u64);
+    }
+    assert!(pq.capacity() >= 1000);
+
+    clear_and_shrink(&mut pq);
+    assert!(pq.is_empty());
+    assert_eq!(0, pq.capacity());
+    for node in 0..1000 {
+        assert!(!pq.contains(&node));
+    }
+
+    for node in (0..1000).rev() {
+        pq.push(node, node as u64 + 1);
+    }
+    pq.decrease_key(&500, 0);
+    assert_eq!(Some(500), pq.pop_node());
+    assert_eq!(Some(0), pq.pop_node());
+    assert_eq!(998, pq.len());
+}
+
+fn test_clear_and_shrink_for<const D: usize>() {
+    let mut pq = DaryHeap::<usize, u64, D>::new();
+    (0..1000).for_each(|x| pq.push(x, x as u64));
+    pq.clear_and_shrink();
+    assert!(pq.is_empty());
+    assert_eq!(0, pq.capacity());
+    pq.push(0, 0);
+    assert_eq!(Some(0), pq.pop_node());
+
+    test_clear_and_shrink(
+        DaryHeapWithMap::<usize, u64, D>::new(),
+        DaryHeapWithMap::clear_and_shrink,
+    );
+    test_clear_and_shrink(
+        DaryHeapOfIndices::<usize, u64, D>::with_index_bound(1000),
+        DaryHeapOfIndices::clear_and_shrink,
+    );
+}
+
+#[test]
+fn clear_and_shrink() {
+    test_clear_and_shrink_for::<2>();
+    test_clear_and_shrink_for::<3>();
+    test_clear_and_shrink_for::<4>();
+    test_clear_and_shrink_for::<8>();
+    test_clear_and_shrink_for::<13>();
+}
+
+fn test_clear_to_capacity<P>(mut pq: P, clear_to_capacity: fn(&mut P, usize))
+where
+    P: PriorityQueueDecKey<usize, u64>,
+{
+    for node in 0..1000 {
+        pq.push(node, node as u64);
+    }
+    assert!(pq.capacity() >= 1000);
+
+    clear_to_capacity(&mut pq, 100);
+    assert!(pq.is_empty());
+    assert!(pq.capacity() >= 100);
+    assert!(pq.capacity() < 1000);
+    for node in 0..1000 {
+        assert!(!pq.contains(&node));
+    }
+
+    for node in 0..50 {
+        pq.push(node, node as u64);
+    }
+    let capacity = pq.capacity();
+    clear_to_capacity(&mut pq, 200);
+    assert!(pq.is_empty());
+    assert_eq!(capacity, pq.capacity());
+
+    for node in (0..1000).rev() {
+        pq.push(node, node as u64 + 1);
+    }
+    pq.decrease_key(&500, 0);
+    assert_eq!(Some(500), pq.pop_node());
+    assert_eq!(Some(0), pq.pop_node());
+    assert_eq!(998, pq.len());
+
+    clear_to_capacity(&mut pq, 0);
+    assert!(pq.is_empty());
+    assert_eq!(0, pq.capacity());
+}
+
+fn test_clear_to_capacity_for<const D: usize>() {
+    let mut pq = DaryHeap::<usize, u64, D>::new();
+    (0..1000).for_each(|x| pq.push(x, x as u64));
+    pq.clear_to_capacity(100);
+    assert!(pq.is_empty());
+    assert!(pq.capacity() >= 100);
+    assert!(pq.capacity() < 1000);
+    pq.push(0, 0);
+    assert_eq!(Some(0), pq.pop_node());
+
+    test_clear_to_capacity(
+        DaryHeapWithMap::<usize, u64, D>::new(),
+        DaryHeapWithMap::clear_to_capacity,
+    );
+    test_clear_to_capacity(
+        DaryHeapOfIndices::<usize, u64, D>::with_index_bound(1000),
+        DaryHeapOfIndices::clear_to_capacity,
+    );
+}
+
+#[test]
+fn clear_to_capacity() {
+    test_clear_to_capacity_for::<2>();
+    test_clear_to_capacity_for::<3>();
+    test_clear_to_capacity_for::<4>();
+    test_clear_to_capacity_for::<8>();
+    test_clear_to_capacity_for::<13>();
+}
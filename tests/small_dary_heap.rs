@@ -0,0 +1,101 @@
+#![cfg(feature = "smallvec")]
+
+mod priority_queue_tests;
+
+use orx_priority_queue::{PriorityQueue, SmallDaryHeap};
+use priority_queue_tests::*;
+use rand::prelude::*;
+
+#[test]
+fn test_small_dary_forall() {
+    test_small_dary_for::<2, 4>();
+    test_small_dary_for::<2, 128>();
+    test_small_dary_for::<3, 16>();
+    test_small_dary_for::<4, 16>();
+    test_small_dary_for::<8, 32>();
+    test_small_dary_for::<13, 8>();
+}
+
+fn test_small_dary_for<const D: usize, const INLINE: usize>() {
+    let new_heap = SmallDaryHeap::<usize, f64, D, INLINE>::default;
+
+    test_len(new_heap());
+    test_is_empty(new_heap());
+    test_peek(new_heap());
+    test_or_default(new_heap());
+    test_clear(new_heap());
+    test_push_pop(new_heap());
+    test_push_pop_randomized(new_heap());
+    test_push_then_pop(new_heap());
+    test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
+}
+
+fn offset<const D: usize>() -> usize {
+    match D.is_power_of_two() && D <= 64 {
+        true => D - 1,
+        false => 0,
+    }
+}
+
+fn test_spill_for<const D: usize, const INLINE: usize>() {
+    let mut rng = rand::thread_rng();
+    let num_inline = INLINE - offset::<D>();
+
+    let mut pq = SmallDaryHeap::<usize, u32, D, INLINE>::new();
+    for node in 0..num_inline {
+        pq.push(node, rng.gen_range(0..100));
+        assert!(!pq.spilled());
+        assert_eq!(Ok(()), pq.validate());
+    }
+
+    pq.push(num_inline, rng.gen_range(0..100));
+    assert!(pq.spilled());
+    assert_eq!(Ok(()), pq.validate());
+
+    let mut prev = None;
+    while let Some((_, key)) = pq.pop() {
+        if let Some(prev) = prev {
+            assert!(prev <= key);
+        }
+        prev = Some(key);
+        assert_eq!(Ok(()), pq.validate());
+    }
+
+    pq.shrink_to_fit();
+    assert!(!pq.spilled());
+}
+
+#[test]
+fn small_dary_heap_spill() {
+    test_spill_for::<2, 4>();
+    test_spill_for::<2, 16>();
+    test_spill_for::<3, 16>();
+    test_spill_for::<4, 16>();
+    test_spill_for::<8, 32>();
+    test_spill_for::<13, 8>();
+}
+
+#[test]
+fn small_dary_heap_same_as_dary_heap() {
+    let mut rng = rand::thread_rng();
+    let mut small = SmallDaryHeap::<usize, u32, 4, 16>::new();
+    let mut dary = orx_priority_queue::QuaternaryHeap::new();
+
+    for node in 0..1000 {
+        let key = rng.gen_range(0..100);
+        small.push(node, key);
+        dary.push(node, key);
+        if rng.gen_bool(0.4) {
+            assert_eq!(dary.pop_key(), small.pop_key());
+        }
+    }
+    assert_eq!(dary.len(), small.len());
+    while let Some(key) = dary.pop_key() {
+        assert_eq!(Some(key), small.pop_key());
+    }
+    assert!(small.is_empty());
+}
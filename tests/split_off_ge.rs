@@ -0,0 +1,142 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+const LEN: usize = 200;
+
+fn assert_pops_sorted<P>(mut pq: P, expected: &[(usize, u32)])
+where
+    P: PriorityQueue<usize, u32>,
+{
+    let mut popped = vec![];
+    while let Some(x) = pq.pop() {
+        popped.push(x);
+    }
+    assert!(popped.windows(2).all(|w| w[0].1 <= w[1].1));
+    popped.sort();
+    assert_eq!(expected, popped);
+}
+
+fn test_split_off_ge<P>(
+    mut pq: P,
+    split_off_ge: fn(&mut P, u32) -> P,
+    validate: fn(&P) -> Result<(), HeapError>,
+) where
+    P: PriorityQueue<usize, u32>,
+{
+    let mut rng = ChaCha8Rng::seed_from_u64(7733);
+    let keys: Vec<u32> = (0..LEN).map(|_| rng.gen_range(0..100)).collect();
+    for (node, key) in keys.iter().enumerate() {
+        pq.push(node, *key);
+    }
+
+    let threshold = 40;
+    let ge = split_off_ge(&mut pq, threshold);
+    assert_eq!(Ok(()), validate(&pq));
+    assert_eq!(Ok(()), validate(&ge));
+
+    let expected_lt: Vec<_> = keys
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|x| x.1 < threshold)
+        .collect();
+    let expected_ge: Vec<_> = keys
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|x| x.1 >= threshold)
+        .collect();
+    assert_eq!(expected_lt.len(), pq.len());
+    assert_eq!(expected_ge.len(), ge.len());
+
+    assert_pops_sorted(pq, &expected_lt);
+    assert_pops_sorted(ge, &expected_ge);
+}
+
+fn test_for<const D: usize>() {
+    test_split_off_ge(
+        DaryHeap::<usize, u32, D>::default(),
+        DaryHeap::split_off_ge,
+        DaryHeap::validate,
+    );
+    test_split_off_ge(
+        DaryHeapWithMap::<usize, u32, D>::default(),
+        DaryHeapWithMap::split_off_ge,
+        DaryHeapWithMap::validate,
+    );
+    test_split_off_ge(
+        DaryHeapOfIndices::<usize, u32, D>::with_index_bound(LEN),
+        DaryHeapOfIndices::split_off_ge,
+        DaryHeapOfIndices::validate,
+    );
+}
+
+#[test]
+fn split_off_ge() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn split_off_ge_extremes() {
+    let mut pq = QuaternaryHeapWithMap::default();
+    (0..10).for_each(|x| pq.push(x, x));
+
+    let none = pq.split_off_ge(100);
+    assert!(none.is_empty());
+    assert_eq!(10, pq.len());
+
+    let all = pq.split_off_ge(0);
+    assert!(pq.is_empty());
+    assert_eq!(10, all.len());
+    assert_eq!(Ok(()), pq.validate());
+    assert_eq!(Ok(()), all.validate());
+
+    let mut empty = QuaternaryHeap::<usize, u32>::default();
+    assert!(empty.split_off_ge(0).is_empty());
+}
+
+#[test]
+fn split_off_ge_positions_are_independent() {
+    let mut urgent = BinaryHeapOfIndices::with_index_bound(8);
+    for node in 0..8usize {
+        urgent.push(node, node * 10);
+    }
+
+    let mut later = urgent.split_off_ge(40);
+    assert_eq!(8, later.index_bound());
+    assert!((0..4).all(|node| urgent.contains(&node) && !later.contains(&node)));
+    assert!((4..8).all(|node| !urgent.contains(&node) && later.contains(&node)));
+
+    later.decrease_key(&6, 1);
+    urgent.push(6, 100);
+    urgent.decrease_key(&6, 5);
+    assert_eq!(Some(5), urgent.key_of(&6));
+    assert_eq!(Some((0, 0)), urgent.pop());
+    assert_eq!(Some((6, 5)), urgent.pop());
+    assert_eq!(Some((6, 1)), later.pop());
+    assert_eq!(Ok(()), urgent.validate());
+    assert_eq!(Ok(()), later.validate());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn split_off_ge_keeps_hasher() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+
+    let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+    let mut pq = DaryHeapWithMap::<char, i32, 4, _>::with_hasher(hash_builder);
+    pq.push('a', 1);
+    pq.push('b', 5);
+
+    let mut ge: DaryHeapWithMap<char, i32, 4, BuildHasherDefault<DefaultHasher>> =
+        pq.split_off_ge(3);
+    ge.decrease_key(&'b', 4);
+    assert_eq!(Some(('b', 4)), ge.pop());
+    assert_eq!(Some(('a', 1)), pq.pop());
+}
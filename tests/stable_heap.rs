@@ -0,0 +1,80 @@
+mod priority_queue_tests;
+
+use orx_priority_queue::*;
+use priority_queue_tests::*;
+use rand::prelude::*;
+
+fn pop_all<P: PriorityQueue<usize, u32>>(pq: &mut P) -> Vec<(usize, u32)> {
+    core::iter::from_fn(|| pq.pop()).collect()
+}
+
+/// Expected pop order: sorted by key, and by insertion order, which is the node, in case of ties.
+fn stable_sorted(mut elements: Vec<(usize, u32)>) -> Vec<(usize, u32)> {
+    elements.sort_by_key(|x| x.1);
+    elements
+}
+
+fn test_for<const D: usize>() {
+    let new_heap = StableDaryHeap::<usize, f64, D>::default;
+
+    test_len(new_heap());
+    test_is_empty(new_heap());
+    test_peek(new_heap());
+    test_or_default(new_heap());
+    test_clear(new_heap());
+    test_push_pop(new_heap());
+    test_push_pop_randomized(new_heap());
+    test_push_then_pop(new_heap());
+    test_push_then_pop_randomized(new_heap());
+    test_extend(new_heap());
+    test_arity(new_heap(), Some(D));
+    test_nodes_keys(new_heap());
+    test_iter_rev(new_heap());
+
+    let mut rng = rand::thread_rng();
+    let len = 300;
+
+    // many ties
+    let elements: Vec<(usize, u32)> = (0..len).map(|node| (node, rng.gen_range(0..10))).collect();
+
+    let mut pq = StableDaryHeap::<usize, u32, D>::default();
+    for (node, key) in &elements {
+        pq.push(*node, *key);
+    }
+    assert_eq!(len, pq.len());
+    assert_eq!(len, pq.iter().count());
+    let min = elements.iter().map(|x| x.1).min().expect("not empty");
+    let first = elements.iter().find(|x| x.1 == min).expect("not empty");
+    assert_eq!(Some(&first.0), pq.peek_node());
+    assert_eq!(Some(&first.1), pq.peek_key());
+    assert_eq!(stable_sorted(elements.clone()), pop_all(&mut pq));
+
+    // extend
+    pq.extend(elements.iter().copied());
+    assert_eq!(stable_sorted(elements.clone()), pop_all(&mut pq));
+
+    // push then pop; the popped element is among the pushed elements in any case
+    pq.clear();
+    let (head, tail) = elements.split_at(len / 2);
+    let mut remaining = head.to_vec();
+    for (node, key) in head {
+        pq.push(*node, *key);
+    }
+    for (node, key) in tail {
+        remaining.push((*node, *key));
+        let expected = stable_sorted(remaining.clone())[0];
+        let x = pq.push_then_pop(*node, *key);
+        assert_eq!(expected, x);
+        remaining.retain(|y| y.0 != x.0);
+    }
+    assert_eq!(stable_sorted(remaining), pop_all(&mut pq));
+}
+
+#[test]
+fn stable_heap() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
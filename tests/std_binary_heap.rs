@@ -0,0 +1,23 @@
+use orx_priority_queue::*;
+use std::collections::BinaryHeap;
+
+#[test]
+fn std_binary_heap() {
+    let mut pq = BinaryHeap::<(usize, u64)>::new();
+    assert_eq!(Some(2), pq.arity());
+    assert_eq!(0, pq.nodes().count());
+    assert_eq!(0, PriorityQueue::iter(&pq).rev().count());
+
+    for node in 0..10 {
+        PriorityQueue::push(&mut pq, node, (node * 7 % 10) as u64);
+    }
+
+    let pairs: Vec<_> = PriorityQueue::iter(&pq).copied().collect();
+    let nodes: Vec<_> = pq.nodes().copied().collect();
+    let keys: Vec<_> = pq.keys().copied().collect();
+    assert_eq!(pairs, nodes.into_iter().zip(keys).collect::<Vec<_>>());
+
+    let mut backward: Vec<_> = PriorityQueue::iter(&pq).rev().copied().collect();
+    backward.reverse();
+    assert_eq!(pairs, backward);
+}
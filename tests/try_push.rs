@@ -0,0 +1,55 @@
+use orx_priority_queue::*;
+
+fn test_for<const D: usize>() {
+    const BOUND: usize = 20;
+    let mut pq = DaryHeapOfIndices::<usize, i64, D>::with_index_bound(BOUND);
+
+    for node in 0..(2 * BOUND) {
+        let key = (node as i64 * 7) % 11;
+        let result = pq.try_push(node, key);
+        match node < BOUND {
+            true => assert_eq!(Ok(()), result),
+            false => assert_eq!(
+                Err(IndexOutOfBound {
+                    index: node,
+                    bound: BOUND
+                }),
+                result
+            ),
+        }
+        assert_eq!(node.min(BOUND - 1) + 1, pq.len());
+        assert_eq!(Ok(()), pq.validate());
+    }
+
+    for node in 0..BOUND {
+        assert!(pq.contains(&node));
+    }
+    assert_eq!(BOUND, pq.index_bound());
+
+    let mut prev = None;
+    while let Some((_, key)) = pq.pop() {
+        assert!(prev.map(|prev| prev <= key).unwrap_or(true));
+        prev = Some(key);
+    }
+}
+
+#[test]
+fn try_push() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn index_out_of_bound_display() {
+    let error = IndexOutOfBound {
+        index: 12,
+        bound: 10,
+    };
+    assert_eq!(
+        "index of the node 12 is out of the index bound 10 of the heap",
+        error.to_string()
+    );
+}
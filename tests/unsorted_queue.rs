@@ -0,0 +1,66 @@
+mod priority_queue_deckey_tests;
+mod priority_queue_tests;
+
+use orx_priority_queue::{PriorityQueue, UnsortedQueue};
+use priority_queue_deckey_tests::*;
+use priority_queue_tests::*;
+
+#[test]
+fn test_unsorted_queue() {
+    let new_queue = UnsortedQueue::<usize, f64>::default;
+
+    let change_key = [
+        ChangeKeyMethod::Decrease,
+        ChangeKeyMethod::Update,
+        ChangeKeyMethod::TryDecrease,
+    ];
+
+    test_len(new_queue());
+    test_is_empty(new_queue());
+    test_peek(new_queue());
+    test_or_default(new_queue());
+    test_clear(new_queue());
+    test_push_pop(new_queue());
+    test_push_pop_randomized(new_queue());
+    test_push_then_pop(new_queue());
+    test_push_then_pop_randomized(new_queue());
+    test_extend(new_queue());
+    test_arity(new_queue(), None);
+    test_nodes_keys(new_queue());
+    test_iter_rev(new_queue());
+
+    test_contains(new_queue());
+    test_key_of(new_queue());
+    test_entries(new_queue());
+    change_key
+        .iter()
+        .for_each(|change_key_method| test_change_key(new_queue(), *change_key_method));
+    test_decrease_key_all(new_queue());
+    test_increase_key(new_queue());
+    test_remove(new_queue());
+    test_mixed(new_queue());
+    test_decrease_key_ref(UnsortedQueue::default());
+    test_decrease_key_by(new_queue());
+    test_update_key_if(new_queue());
+    test_push_if_lower(new_queue());
+    test_take(new_queue());
+
+    change_key
+        .iter()
+        .for_each(|change_key_method| test_change_key_or_push(new_queue(), *change_key_method));
+}
+
+#[test]
+fn test_unsorted_queue_from_vec() {
+    let mut queue = UnsortedQueue::from(vec![('a', 3), ('b', 1), ('c', 2)]);
+    assert_eq!(3, queue.len());
+    assert_eq!(Some(&('b', 1)), queue.peek());
+
+    assert_eq!(('d', 0), queue.push_then_pop('d', 0));
+    assert_eq!(('b', 1), queue.push_then_pop('e', 4));
+    assert_eq!(Some(('c', 2)), queue.pop());
+
+    let mut remaining = queue.into_inner();
+    remaining.sort();
+    assert_eq!(vec![('a', 3), ('e', 4)], remaining);
+}
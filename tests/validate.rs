@@ -0,0 +1,72 @@
+use orx_priority_queue::*;
+use rand::prelude::*;
+
+const N: usize = 200;
+
+fn random_ops<P>(pq: &mut P, validate: fn(&P) -> Result<(), HeapError>)
+where
+    P: PriorityQueueDecKey<usize, f64>,
+{
+    let mut rng = rand::thread_rng();
+    assert_eq!(Ok(()), validate(pq));
+
+    for _ in 0..1000 {
+        let node = rng.gen_range(0..N);
+        let key: f64 = rng.gen();
+        match rng.gen_range(0..4) {
+            0 => _ = pq.pop(),
+            1 => _ = pq.try_decrease_key_or_push(&node, key),
+            2 => _ = pq.update_key_or_push(&node, key),
+            _ => {
+                if pq.contains(&node) {
+                    pq.remove(&node);
+                }
+            }
+        }
+        assert_eq!(Ok(()), validate(pq));
+    }
+}
+
+fn test_for<const D: usize>() {
+    let mut rng = rand::thread_rng();
+
+    let mut dary = DaryHeap::<usize, f64, D>::default();
+    assert_eq!(Ok(()), dary.validate());
+    for node in 0..N {
+        dary.push(node, rng.gen());
+        assert_eq!(Ok(()), dary.validate());
+    }
+    while dary.pop().is_some() {
+        assert_eq!(Ok(()), dary.validate());
+    }
+
+    random_ops(
+        &mut DaryHeapWithMap::<usize, f64, D>::default(),
+        DaryHeapWithMap::validate,
+    );
+    random_ops(
+        &mut DaryHeapOfIndices::<usize, f64, D>::with_index_bound(N),
+        DaryHeapOfIndices::validate,
+    );
+}
+
+#[test]
+fn validate() {
+    test_for::<2>();
+    test_for::<3>();
+    test_for::<4>();
+    test_for::<8>();
+    test_for::<13>();
+}
+
+#[test]
+fn heap_error_display() {
+    let error = HeapError::KeyOrder {
+        parent: 0,
+        child: 3,
+    };
+    assert_eq!(
+        "key of the element at position 3 is less than the key of its parent at position 0",
+        error.to_string()
+    );
+}